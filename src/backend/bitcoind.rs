@@ -1,16 +1,28 @@
+use std::cmp;
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
 use bitcoincore_rpc as rpc;
 use bitcoincore_rpc::GetTransaction;
-use bitcoincore_rpc::GetScript;
 use bitcoincore_rpc::Queryable;
 use clap;
+use hex;
+use jsonrpc;
 use rbitcoin::blockdata::opcodes;
-use rbitcoin::blockdata::script::Builder;
-use rbitcoin::consensus::encode::serialize;
-use rbitcoin::util::hash::BitcoinHash;
+use rbitcoin::blockdata::script::{Builder, Script};
+use rbitcoin::consensus::encode::{deserialize, serialize};
+use rbitcoin::util::hash::{BitcoinHash, Sha256dHash};
 use rbitcoin::util::psbt;
 use rbitcoin::{Block, OutPoint, Transaction, TxOut};
+use serde_json::Value;
 
+use backend::cache::{ChainCache, SpendStatus};
+use backend::chain;
 use bitcoin::*;
+use bitcoin;
 
 pub fn args<'a>() -> Vec<clap::Arg<'a, 'a>> {
 	vec![
@@ -21,47 +33,345 @@ pub fn args<'a>() -> Vec<clap::Arg<'a, 'a>> {
 		clap::Arg::with_name("bitcoind-user")
 			.long("bitcoind-user")
 			.help("the RPC user for bitcoind")
-			.takes_value(true),
+			.takes_value(true)
+			.env("BITCOIND_USER"),
 		clap::Arg::with_name("bitcoind-pass")
 			.long("bitcoind-pass")
-			.help("the RPC pass for bitcoind")
+			.help("the RPC pass for bitcoind (prefer the environment variable)")
+			.takes_value(true)
+			.env("BITCOIND_PASS")
+			.hide_env_values(true),
+		clap::Arg::with_name("bitcoind-cookie")
+			.long("bitcoind-cookie")
+			.help("the .cookie file to authenticate with bitcoind")
+			.takes_value(true)
+			.env("BITCOIND_COOKIE"),
+		clap::Arg::with_name("bitcoind-conf")
+			.long("bitcoind-conf")
+			.help("the bitcoin.conf file to read the RPC credentials from")
 			.takes_value(true),
+		clap::Arg::with_name("bitcoind-wallet")
+			.long("bitcoind-wallet")
+			.help("the name of the bitcoind wallet to use, can be given multiple times")
+			.takes_value(true)
+			.multiple(true)
+			.number_of_values(1),
+		clap::Arg::with_name("cache-dir")
+			.long("cache-dir")
+			.help("the directory to cache chain data in, to speed up repeated verification")
+			.takes_value(true)
+			.env("RESERVES_CACHE_DIR"),
+		clap::Arg::with_name("rpc-workers")
+			.long("rpc-workers")
+			.help("the number of concurrent connections to bitcoind for fetching chain data")
+			.takes_value(true)
+			.default_value("4"),
+		clap::Arg::with_name("rpc-batch-size")
+			.long("rpc-batch-size")
+			.help("the number of requests to send to bitcoind in a single batch")
+			.takes_value(true)
+			.default_value("100"),
 	]
 }
 
-pub struct Backend(rpc::Client);
+/// Read the user and password from a bitcoind cookie file.
+fn read_cookie(path: &Path) -> (String, String) {
+	let cookie = fs::read_to_string(path)
+		.expect(&format!("error reading cookie file at '{}'", path.display()));
+	let mut parts = cookie.trim().splitn(2, ':');
+	match (parts.next(), parts.next()) {
+		(Some(user), Some(pass)) => (user.to_owned(), pass.to_owned()),
+		_ => panic!("invalid cookie file at '{}'", path.display()),
+	}
+}
+
+/// Read the RPC credentials from a bitcoin.conf file.  Either the rpcuser and rpcpassword
+/// options are used, or the cookie file in the rpccookiefile option or in the datadir.
+///
+/// The options in the `[main]`, `[test]`, `[signet]` or `[regtest]` section of the configured
+/// chain take precedence, and the cookie file is looked up in the data directory of that chain.
+fn read_conf(path: &Path) -> Option<(String, String)> {
+	let conf =
+		fs::read_to_string(path).expect(&format!("error reading file at '{}'", path.display()));
+	let mut sections: HashMap<String, HashMap<String, String>> = HashMap::new();
+	let mut section = String::new();
+	for line in conf.lines() {
+		let line = line.split('#').next().unwrap().trim();
+		if line.starts_with('[') && line.ends_with(']') {
+			section = line[1..line.len() - 1].trim().to_owned();
+			continue;
+		}
+		let mut parts = line.splitn(2, '=');
+		if let (Some(key), Some(value)) = (parts.next(), parts.next()) {
+			let options = sections.entry(section.clone()).or_insert_with(HashMap::new);
+			options.entry(key.trim().to_owned()).or_insert(value.trim().to_owned());
+		}
+	}
+
+	let global = sections.remove("").unwrap_or_default();
+	let enabled = |key: &str| global.get(key).map(|v| v == "1").unwrap_or(false);
+	let chain = match global.get("chain") {
+		Some(chain) => chain.clone(),
+		None if enabled("regtest") => "regtest".to_owned(),
+		None if enabled("signet") => "signet".to_owned(),
+		None if enabled("testnet") => "test".to_owned(),
+		None => "main".to_owned(),
+	};
+	let chain_options = sections.remove(&chain).unwrap_or_default();
+	let option = |key: &str| chain_options.get(key).or(global.get(key));
+
+	if let (Some(user), Some(pass)) = (option("rpcuser"), option("rpcpassword")) {
+		return Some((user.clone(), pass.clone()));
+	}
+	let datadir = match option("datadir") {
+		Some(datadir) => PathBuf::from(datadir),
+		None => path.parent()?.to_path_buf(),
+	};
+	let chain_dir = match chain.as_str() {
+		"test" => datadir.join("testnet3"),
+		"signet" => datadir.join("signet"),
+		"regtest" => datadir.join("regtest"),
+		_ => datadir,
+	};
+	// A relative cookie file path is relative to the data directory of the chain.
+	let cookie = match option("rpccookiefile") {
+		Some(file) => chain_dir.join(file),
+		None => chain_dir.join(".cookie"),
+	};
+	if cookie.exists() {
+		Some(read_cookie(&cookie))
+	} else {
+		None
+	}
+}
+
+/// Find the RPC credentials in the arguments, in order of precedence: the user and password,
+/// the cookie file and the bitcoin.conf file.
+fn credentials(matches: &clap::ArgMatches) -> Option<(String, String)> {
+	if let Some(user) = matches.value_of("bitcoind-user") {
+		let pass = matches.value_of("bitcoind-pass").unwrap_or("");
+		return Some((user.to_owned(), pass.to_owned()));
+	}
+	if let Some(cookie) = matches.value_of("bitcoind-cookie") {
+		return Some(read_cookie(Path::new(cookie)));
+	}
+	if let Some(conf) = matches.value_of("bitcoind-conf") {
+		return read_conf(Path::new(conf));
+	}
+	None
+}
+
+/// The wallet metadata of a UTXO.
+#[derive(Debug, Clone, Default)]
+pub struct WalletInfo {
+	pub address: Option<String>,
+	pub label: Option<String>,
+	pub spendable: bool,
+}
+
+/// An output found by scanning the UTXO set.
+#[derive(Debug, Clone)]
+pub struct ScannedOutput {
+	pub point: OutPoint,
+	pub txout: TxOut,
+	pub height: u32,
+}
+
+/// Send the calls to the given method as a single JSON-RPC batch request.
+fn send_batch(
+	client: &jsonrpc::client::Client,
+	method: &str,
+	batch: &[(usize, Vec<Value>)],
+) -> Vec<Result<Value, String>> {
+	let requests: Vec<_> =
+		batch.iter().map(|&(_, ref params)| client.build_request(method, params)).collect();
+	match client.send_batch(&requests) {
+		Ok(responses) => responses
+			.into_iter()
+			.map(|r| match r {
+				Some(r) => r.into_result().map_err(|e| format!("{:?}", e)),
+				None => Err("no response from bitcoind".to_owned()),
+			}).collect(),
+		Err(e) => vec![Err(format!("{:?}", e)); batch.len()],
+	}
+}
+
+/// The connection details for bitcoind, to make batched calls over multiple connections.
+struct RpcPool {
+	endpoint: String,
+	user: Option<String>,
+	pass: Option<String>,
+	workers: usize,
+	batch_size: usize,
+}
+
+impl RpcPool {
+	/// Call the method once for each of the given parameters.  The calls are sent in batches of
+	/// the given size, which are spread over the workers.  The results are in the order of the
+	/// parameters.
+	fn call(
+		&self,
+		method: &str,
+		params: Vec<Vec<Value>>,
+		batch_size: usize,
+	) -> Vec<Result<Value, String>> {
+		let nb_calls = params.len();
+		let mut batches = Vec::new();
+		let mut params = params.into_iter().enumerate().peekable();
+		while params.peek().is_some() {
+			batches.push(params.by_ref().take(batch_size).collect::<Vec<_>>());
+		}
+		let nb_workers = cmp::min(self.workers, batches.len());
+		let queue = Arc::new(Mutex::new(batches));
+		let results = Arc::new(Mutex::new(vec![None; nb_calls]));
+
+		let workers: Vec<_> = (0..nb_workers)
+			.map(|_| {
+				let (queue, results) = (queue.clone(), results.clone());
+				let (endpoint, user, pass) =
+					(self.endpoint.clone(), self.user.clone(), self.pass.clone());
+				let method = method.to_owned();
+				thread::spawn(move || {
+					let client = jsonrpc::client::Client::new(endpoint, user, pass);
+					loop {
+						let batch = match queue.lock().unwrap().pop() {
+							Some(batch) => batch,
+							None => break,
+						};
+						let responses = send_batch(&client, &method, &batch);
+						let mut results = results.lock().unwrap();
+						for (&(idx, _), response) in batch.iter().zip(responses.into_iter()) {
+							results[idx] = Some(response);
+						}
+					}
+				})
+			}).collect();
+		for worker in workers.into_iter() {
+			worker.join().expect("RPC worker failed");
+		}
+
+		let mut results = results.lock().unwrap();
+		results
+			.drain(..)
+			.map(|r| r.unwrap_or_else(|| Err("no response from bitcoind".to_owned())))
+			.collect()
+	}
+}
+
+pub struct Backend(rpc::Client, Option<String>, Option<ChainCache>, RpcPool);
 
 impl Backend {
+	/// Load the backend from the arguments.  If wallets are given, the first one is used.
 	pub fn load<'a>(matches: &'a clap::ArgMatches) -> Option<Self> {
-		match matches.value_of("bitcoind") {
-			None => None,
-			Some(endpoint) => Some(Backend(rpc::Client::new(
-				endpoint.to_string(),
-				match matches.value_of("bitcoind-user") {
-					Some(v) => Some(v.to_string()),
-					None => None,
-				},
-				match matches.value_of("bitcoind-pass") {
-					Some(v) => Some(v.to_string()),
-					None => None,
-				},
-			))),
+		let wallet = matches.values_of("bitcoind-wallet").and_then(|mut w| w.next());
+		let mut backend = Backend::load_wallet(matches, wallet)?;
+		if let Some(dir) = matches.value_of("cache-dir") {
+			backend.open_cache(dir);
 		}
+		Some(backend)
 	}
 
-	/// Fetch unspent outputs from the node's wallet.
-	pub fn fetch_utxos(&mut self) -> Vec<UTXO> {
+	/// Load a backend for every wallet given in the arguments, or a single backend for the
+	/// default wallet if none are given.
+	pub fn load_wallets<'a>(matches: &'a clap::ArgMatches) -> Vec<Self> {
+		match matches.values_of("bitcoind-wallet") {
+			None => Backend::load(matches).into_iter().collect(),
+			Some(wallets) => {
+				wallets.filter_map(|w| Backend::load_wallet(matches, Some(w))).collect()
+			}
+		}
+	}
+
+	fn load_wallet<'a>(matches: &'a clap::ArgMatches, wallet: Option<&str>) -> Option<Self> {
+		let endpoint = matches.value_of("bitcoind")?;
+		let endpoint = match wallet {
+			Some(w) => format!("{}/wallet/{}", endpoint.trim_right_matches('/'), w),
+			None => endpoint.to_owned(),
+		};
+		let (user, pass) = match credentials(matches) {
+			Some((user, pass)) => (Some(user), Some(pass)),
+			None => (None, None),
+		};
+		let workers = matches.value_of("rpc-workers").unwrap_or("4");
+		let workers: usize = workers.parse().expect("invalid number of RPC workers");
+		let batch_size = matches.value_of("rpc-batch-size").unwrap_or("100");
+		let batch_size: usize = batch_size.parse().expect("invalid RPC batch size");
+		if workers == 0 || batch_size == 0 {
+			panic!("The number of RPC workers and the RPC batch size must be positive.");
+		}
+
+		let pool = RpcPool {
+			endpoint: endpoint.clone(),
+			user: user.clone(),
+			pass: pass.clone(),
+			workers: workers,
+			batch_size: batch_size,
+		};
+		let client = rpc::Client::new(endpoint, user, pass);
+		Some(Backend(client, wallet.map(|w| w.to_owned()), None, pool))
+	}
+
+	/// Open the chain cache and drop the data of blocks that are no longer in the active chain.
+	/// When bitcoind can't be reached, the cache is used as it is.
+	fn open_cache(&mut self, dir: &str) {
+		let mut cache = ChainCache::open(dir);
+		let tip: Result<Value, _> = self.0.call("getblockcount", &[]);
+		match tip {
+			Ok(tip) => {
+				let tip = tip.as_u64().expect("invalid getblockcount result") as u32;
+				let client = &mut self.0;
+				let mut hash_at = |height: u32| {
+					if height > tip {
+						return None;
+					}
+					let hash: Value = client.call("getblockhash", &[height.into()]).ok()?;
+					hash.as_str().map(|h| h.to_owned())
+				};
+				cache.check_chain(&mut hash_at);
+				let tip_hash = hash_at(tip).expect("error calling getblockhash");
+				cache.put_block_hash(tip, tip_hash);
+			}
+			Err(e) => {
+				println!("Warning: can't reach bitcoind, using the chain cache as it is: {:?}", e)
+			}
+		}
+		self.2 = Some(cache);
+	}
+
+	/// Store a confirmed transaction in the chain cache, if there is one.
+	fn cache_tx(&mut self, tx: &Transaction, block_hash: String, height: u32) {
+		if let Some(ref mut cache) = self.2 {
+			cache.put_tx(tx);
+			cache.put_block_hash(height, block_hash.clone());
+			cache.put_tx_block(tx.bitcoin_hash().to_string(), block_hash);
+		}
+	}
+
+	/// The output spent by the outpoint and the height of its block, from the chain cache.
+	fn cached_prevout(&self, point: &OutPoint) -> Option<(TxOut, u32)> {
+		let cache = self.2.as_ref()?;
+		let txid = point.txid.to_string();
+		let height = cache.block_height(cache.tx_block(&txid)?)?;
+		let tx = cache.tx(&txid)?;
+		tx.output.get(point.vout as usize).map(|o| (o.clone(), height))
+	}
+
+	/// The name of the wallet this backend uses, if any.
+	pub fn wallet(&self) -> Option<&str> {
+		self.1.as_ref().map(|w| w.as_str())
+	}
+
+	/// Fetch the confirmed unspent outputs from the node's wallet, with their wallet metadata.
+	pub fn fetch_utxos(&mut self) -> Vec<(UTXO, WalletInfo)> {
 		let mut utxos = Vec::new();
 
+		// Take all confirmed outputs: the confirmations are filtered at the proof block, so that
+		// the outputs with too few confirmations are reported.
 		let unspents = self
 			.0
-			.list_unspent(Some(6), None, None, None, None)
+			.list_unspent(Some(1), None, None, None, None)
 			.expect("failed to fetch utxos from bitcoind");
 		for unspent in unspents.into_iter() {
-			if !unspent.spendable {
-				continue;
-			}
-
 			// Fetch tx and block info.
 			let tx_info = self
 				.0
@@ -80,7 +390,7 @@ impl Backend {
 			psbt_input.witness_utxo = Some(txout.clone());
 			psbt_input.redeem_script = unspent.redeem_script;
 
-			utxos.push(UTXO {
+			let utxo = UTXO {
 				point: OutPoint {
 					txid: unspent.txid,
 					vout: unspent.vout,
@@ -88,11 +398,138 @@ impl Backend {
 				psbt_input: psbt_input,
 				block_number: block_info.height as u32,
 				block_hash: Some(tx_info.blockhash),
-			});
+				wallet: self.1.clone(),
+			};
+			let info = WalletInfo {
+				address: unspent.address.map(|a| a.to_string()),
+				label: unspent.label,
+				spendable: unspent.spendable,
+			};
+			utxos.push((utxo, info));
 		}
 		utxos
 	}
 
+	/// Validate a UTXO against the chain and fill in the missing transaction data and block
+	/// metadata.
+	///
+	/// The UTXO has to be confirmed at or before the proof block and the transaction data in the
+	/// PSBT input has to match the chain.
+	pub fn validate_utxo(
+		&mut self,
+		utxo: &mut UTXO,
+		proof_block_number: u32,
+	) -> Result<(), String> {
+		let txid = utxo.point.txid;
+		// Without a txindex, bitcoind can only find the tx when it's told the block.
+		let block_hash: Option<Sha256dHash> = match utxo.block_hash {
+			Some(hash) => Some(hash),
+			None if utxo.block_number != 0 => Some(
+				self.block_hash_at(utxo.block_number).parse().expect("invalid block hash"),
+			),
+			None => None,
+		};
+		let tx_info = match block_hash {
+			Some(ref hash) => self.0.get_raw_transaction_verbose(&txid, Some(hash)).map_err(|e| {
+				format!("transaction {} not found in block {}: {:?}", txid, hash, e)
+			})?,
+			None => self.0.get_raw_transaction_verbose(&txid, None).map_err(|e| {
+				format!(
+					"confirmed transaction {} not found; give its block or run bitcoind with \
+					 -txindex: {:?}",
+					txid, e
+				)
+			})?,
+		};
+		let tx = tx_info.transaction().map_err(|_| format!("failed to decode tx {}", txid))?;
+		let txout = tx
+			.output
+			.get(utxo.point.vout as usize)
+			.ok_or_else(|| format!("transaction {} has no output {}", txid, utxo.point.vout))?;
+		let height = self
+			.0
+			.get_block_header_verbose(&tx_info.blockhash)
+			.map_err(|e| format!("error loading block header from bitcoind: {:?}", e))?
+			.height as u32;
+
+		if proof_block_number != 0 && height > proof_block_number {
+			return Err(format!(
+				"UTXO was confirmed in block {}, after the proof block {}",
+				height, proof_block_number
+			));
+		}
+		if let Some(ref prev_tx) = utxo.psbt_input.non_witness_utxo {
+			if *prev_tx != tx {
+				return Err("the previous transaction doesn't match the chain".into());
+			}
+		}
+		if let Some(ref prev_out) = utxo.psbt_input.witness_utxo {
+			if prev_out != txout {
+				return Err("the previous output doesn't match the chain".into());
+			}
+		}
+		if utxo.block_number != 0 && utxo.block_number != height {
+			return Err(format!(
+				"UTXO was confirmed in block {}, not {}",
+				height, utxo.block_number
+			));
+		}
+		if let Some(hash) = utxo.block_hash {
+			if hash != tx_info.blockhash {
+				return Err(format!(
+					"UTXO was confirmed in block {}, not {}",
+					tx_info.blockhash, hash
+				));
+			}
+		}
+
+		if utxo.psbt_input.witness_utxo.is_none() {
+			utxo.psbt_input.witness_utxo = Some(txout.clone());
+		}
+		if utxo.psbt_input.non_witness_utxo.is_none() {
+			utxo.psbt_input.non_witness_utxo = Some(tx.clone());
+		}
+		utxo.block_number = height;
+		utxo.block_hash = Some(tx_info.blockhash);
+		Ok(())
+	}
+
+	/// Fetch the transaction with the given id, if bitcoind can find it.
+	pub fn get_tx(&mut self, txid: &Sha256dHash) -> Option<Transaction> {
+		if let Some(tx) = self.2.as_ref().and_then(|c| c.tx(&txid.to_string())) {
+			return Some(tx);
+		}
+		let tx = self.0.get_raw_transaction(txid, None).ok()?;
+		if let Some(ref mut cache) = self.2 {
+			cache.put_tx(&tx);
+		}
+		Some(tx)
+	}
+
+	/// Check whether the given output is currently unspent.
+	pub fn is_unspent(&mut self, point: &OutPoint) -> bool {
+		match self.2.as_ref().and_then(|c| c.spend(point)) {
+			Some(SpendStatus::SpentBy(_)) | Some(SpendStatus::SpentIn(_)) => return false,
+			_ => {}
+		}
+		let unspent = self
+			.0
+			.get_tx_out(&point.txid, point.vout, Some(false))
+			.expect(&format!("error fetching txout {}", point))
+			.is_some();
+		if let Some(ref mut cache) = self.2 {
+			if let Some(tip) = cache.tip_height() {
+				let status = if unspent {
+					SpendStatus::Unspent(tip)
+				} else {
+					SpendStatus::SpentBy(tip)
+				};
+				cache.put_spend(point, status);
+			}
+		}
+		unspent
+	}
+
 	/// Ask bitcoind to sign the given tx.
 	pub fn sign_tx(&mut self, tx: Transaction) -> Transaction {
 		// Encode tx to pass to bitcoind.
@@ -125,97 +562,521 @@ impl Backend {
 		signed_res.transaction().expect("failed to parse signed transaction from bitcoind")
 	}
 
-	/// Fetch the previous outpoints for the inputs of the proof tx.  We do this to verify they
-	/// all existed at the given block number.
-	pub fn fetch_proof_prevouts(&mut self, proof: &Proof, proof_block_number: u32) -> Vec<TxOut> {
-		let mut prevouts = Vec::new();
-		for (idx, input) in proof.proof_tx.as_ref().unwrap().input.iter().enumerate() {
-			// Skip the challenge input.
-			if idx == 0 {
-				continue;
+	/// Fetch the previous outpoints for the inputs of the proof txs of all chunks of the proof.
+	/// We do this to verify they all existed at the given block number.
+	pub fn fetch_proof_prevouts(
+		&mut self,
+		proof: &Proof,
+		proof_block_number: u32,
+	) -> Vec<Vec<TxOut>> {
+		let txs = proof.proof_txs();
+		// Skip the challenge inputs.
+		let points: Vec<_> =
+			txs.iter().flat_map(|tx| tx.input.iter().skip(1).map(|i| i.previous_output)).collect();
+		let prevouts = self.fetch_prevouts(proof, &points);
+
+		txs.into_iter()
+			.map(|tx| {
+				tx.input
+					.iter()
+					.enumerate()
+					.skip(1)
+					.map(|(idx, input)| {
+						let prevout = prevouts.get(&input.previous_output);
+						let &(ref txout, block_number) = match prevout {
+							Some(prevout) => prevout,
+							//TODO(stevenroose) implement searching for the tx from the proof
+							// blocknumber backwards
+							None => panic!(
+								"Cannot find output for input #{} of proof tx '{}'!",
+								idx, proof.id
+							),
+						};
+						if block_number > proof_block_number {
+							panic!(
+								"Input {} of proof '{}' was not valid at block {} (bestblock {})",
+								input.previous_output, proof.id, proof_block_number, block_number
+							);
+						}
+						txout.clone()
+					}).collect()
+			}).collect()
+	}
+
+	/// Fetch the outputs spent by the given outpoints, with the heights of their blocks.
+	/// Outpoints that can't be found in the active chain are left out.
+	///
+	/// The transactions are first fetched directly, which only finds confirmed transactions when
+	/// bitcoind runs with -txindex.  Otherwise they are looked up in the blocks of the UTXOs in the
+	/// proof, so that every block is fetched only once.  All calls are batched and spread over the
+	/// RPC workers.
+	fn fetch_prevouts(
+		&mut self,
+		proof: &Proof,
+		points: &[OutPoint],
+	) -> HashMap<OutPoint, (TxOut, u32)> {
+		let mut prevouts = HashMap::new();
+		let mut missing = Vec::new();
+		for point in points.iter() {
+			match self.cached_prevout(point) {
+				Some(prevout) => {
+					prevouts.insert(*point, prevout);
+				}
+				None => missing.push(*point),
+			}
+		}
+		if missing.is_empty() {
+			return prevouts;
+		}
+
+		// The transactions with the hashes of their blocks.
+		let mut txs: HashMap<Sha256dHash, (Transaction, String)> = HashMap::new();
+		let txids: HashSet<_> = missing.iter().map(|p| p.txid).collect();
+		let txids: Vec<_> = txids.into_iter().collect();
+		let params = txids.iter().map(|txid| vec![txid.to_string().into(), true.into()]).collect();
+		let results = self.3.call("getrawtransaction", params, self.3.batch_size);
+		for (txid, result) in txids.into_iter().zip(results.into_iter()) {
+			// Without a txindex only mempool transactions are found, and those have no block hash.
+			let info = match result {
+				Ok(info) => info,
+				Err(_) => continue,
+			};
+			if let (Some(raw), Some(hash)) = (info["hex"].as_str(), info["blockhash"].as_str()) {
+				let tx = hex::decode(raw).ok().and_then(|raw| deserialize(&raw).ok());
+				let tx = tx.expect(&format!("invalid transaction {} from bitcoind", txid));
+				txs.insert(txid, (tx, hash.to_owned()));
 			}
+		}
 
-			// We have two possible scenarios here:
-			// 1. the output is currently unspent: we can easily fetch it
-			// 2. the output is no longer unspent: we have to find the output in the blockchain
+		// Group the remaining transactions by the blocks of their UTXOs in the proof.
+		let utxos: HashMap<_, _> = proof.utxos.iter().map(|u| (u.point, u)).collect();
+		let mut by_block = HashMap::new();
+		let mut by_height = HashMap::new();
+		for point in missing.iter().filter(|p| !txs.contains_key(&p.txid)) {
+			let utxo = match utxos.get(point) {
+				Some(utxo) => utxo,
+				None => continue,
+			};
+			if let Some(hash) = utxo.block_hash {
+				by_block.entry(hash.to_string()).or_insert_with(HashSet::new).insert(point.txid);
+			} else if utxo.block_number != 0 {
+				by_height.entry(utxo.block_number).or_insert_with(HashSet::new).insert(point.txid);
+			}
+		}
+		let heights: Vec<u32> = by_height.keys().cloned().collect();
+		let hashes = self.block_hashes_at(&heights);
+		for (height, hash) in heights.iter().zip(hashes.into_iter()) {
+			let txids = by_height.remove(height).unwrap();
+			by_block.entry(hash).or_insert_with(HashSet::new).extend(txids);
+		}
 
-			// Check if the output is unspent.
-			let unspent = self
-				.0
-				.get_tx_out(&input.previous_output.txid, input.previous_output.vout, Some(false))
-				.expect(&format!(
-					"fetch txout {} (input #{} of proof '{}')",
-					input.previous_output, idx, proof.id,
-				));
-			if let Some(unspent) = unspent {
-				// Verify the block number.
-				let block_hash = self
-					.0
-					.get_raw_transaction_verbose(&input.previous_output.txid, None)
-					.expect(&format!(
-						"error fetching transaction details for txid {} for proof '{}'",
-						input.previous_output.txid, proof.id
-					)).blockhash;
-				let block_number = self
-					.0
-					.get_block_header_verbose(&block_hash)
-					.expect(&format!("fetching block header for {}", unspent.bestblock))
-					.height as u32;
-				if block_number > proof_block_number {
-					panic!(
-						"Input {} of proof '{}' was not valid at block {} (bestblock {})",
-						input.previous_output, proof.id, proof_block_number, block_number
-					);
+		let hashes: Vec<String> = by_block.keys().cloned().collect();
+		for (hash, block) in hashes.iter().zip(self.fetch_blocks(&hashes).into_iter()) {
+			let wanted = &by_block[hash];
+			for tx in block.txdata.into_iter() {
+				let txid = tx.bitcoin_hash();
+				if wanted.contains(&txid) {
+					txs.insert(txid, (tx, hash.clone()));
 				}
+			}
+		}
 
-				prevouts.push(TxOut {
-					value: unspent.value.into_inner() as u64,
-					script_pubkey: unspent
-						.script_pub_key
-						.script()
-						.expect("corrupt script from RPC"),
-				});
-				continue;
+		// Only blocks in the active chain count.
+		let hashes: Vec<_> =
+			txs.values().map(|t| t.1.clone()).collect::<HashSet<_>>().into_iter().collect();
+		let heights = self.active_block_heights(&hashes);
+		for point in missing.iter() {
+			let (tx, height) = match txs.get(&point.txid) {
+				Some(&(ref tx, ref hash)) => match heights.get(hash) {
+					Some(height) => (tx, *height),
+					None => continue,
+				},
+				None => continue,
 			};
+			if let Some(out) = tx.output.get(point.vout as usize) {
+				prevouts.insert(*point, (out.clone(), height));
+			}
+		}
+		for (_, (tx, hash)) in txs.into_iter() {
+			if let Some(height) = heights.get(&hash).cloned() {
+				self.cache_tx(&tx, hash, height);
+			}
+		}
+		prevouts
+	}
+
+	/// The outpoints spent by the proof transactions of the proof with their values.
+	/// The values are always fetched from bitcoind, never taken from the proof file, looking the
+	/// transactions up in the blocks of the UTXOs when bitcoind has no txindex.
+	pub fn proven_coins(&mut self, proof: &Proof) -> Vec<(OutPoint, u64)> {
+		let points: Vec<_> = proof.spending_utxos().into_iter().collect();
+		let prevouts = self.fetch_prevouts(proof, &points);
+		points
+			.into_iter()
+			.map(|point| match prevouts.get(&point) {
+				Some(&(ref txout, _)) => (point, txout.value),
+				None => panic!("Cannot find output {} of proof '{}'!", point, proof.id),
+			}).collect()
+	}
 
-			// The output is no longer unspent. We have to verify if it was valid before the block
-			// number in the proof file.
-
-			// If the block number is provided in the proof file
-			let existing = proof.utxos.iter().find(|u| u.point == input.previous_output);
-			if let Some(existing) = existing {
-				let mut block_hash = existing.block_hash;
-				// Get block hash from number.
-				if existing.block_hash.is_none() && existing.block_number != 0 {
-					block_hash = Some(self.0.get_block_hash(existing.block_number.into()).expect(
-						&format!("fetching block hash for block number {}", existing.block_number),
-					));
+	/// Find the blocks in the given range of heights that spend any of the given outpoints.
+	/// Returns the height of the block and the spent outpoint for every spend.
+	/// The outpoints have to exist at the start of the range.
+	pub fn find_spends(
+		&mut self,
+		points: &HashSet<OutPoint>,
+		start: u32,
+		end: u32,
+	) -> Vec<(u32, OutPoint)> {
+		// Only the blocks for the outpoints without a known status have to be scanned.
+		let mut spends = Vec::new();
+		let mut unknown = HashSet::new();
+		for point in points.iter() {
+			match self.2.as_ref().and_then(|c| c.spend(point)) {
+				Some(SpendStatus::SpentIn(h)) => {
+					if h >= start && h <= end {
+						spends.push((h, *point));
+					}
+				}
+				Some(SpendStatus::Unspent(h)) if h >= end => {}
+				_ => {
+					unknown.insert(*point);
 				}
+			}
+		}
 
-				if let Some(block_hash) = block_hash {
-					let block = Block::query(&mut self.0, &block_hash)
-						.expect(&format!("fetching block number {}", existing.block_number));
-					let found = block
-						.txdata
-						.into_iter()
-						.find(|tx| tx.bitcoin_hash() == input.previous_output.txid);
-					if let Some(tx) = found {
-						let out =
-							tx.output.get(input.previous_output.vout as usize).expect(&format!(
-								"outpoint of tx #{} from proof tx '{}' contains non-existent vout {}",
-								idx,
-								proof.id,
-								input.previous_output.vout,
-							));
-						prevouts.push(out.clone());
-						continue;
+		// The blocks are fetched concurrently, as many at a time as there are RPC workers.
+		let mut nb_found = 0;
+		let heights: Vec<u32> = (start..end + 1).collect();
+		for window in heights.chunks(self.3.workers) {
+			if nb_found == unknown.len() {
+				break;
+			}
+			let blocks = self.blocks_at(window);
+			for (height, block) in window.iter().cloned().zip(blocks.iter()) {
+				for tx in block.txdata.iter() {
+					for input in tx.input.iter() {
+						if unknown.contains(&input.previous_output) {
+							spends.push((height, input.previous_output));
+							nb_found += 1;
+							if let Some(ref mut cache) = self.2 {
+								let status = SpendStatus::SpentIn(height);
+								cache.put_spend(&input.previous_output, status);
+							}
+						}
 					}
 				}
 			}
+		}
+		if let Some(ref mut cache) = self.2 {
+			for point in unknown.iter().filter(|p| !spends.iter().any(|s| s.1 == **p)) {
+				cache.put_spend(point, SpendStatus::Unspent(end));
+			}
+		}
+
+		spends.sort_by_key(|s| s.0);
+		spends
+	}
 
-			//TODO(stevenroose) implement searching for the tx from the proof blocknumber backwards
-			panic!("Cannot find output for input #{} of proof tx '{}'!", idx, proof.id);
+	/// The height of the current chain tip.
+	pub fn tip_height(&mut self) -> u32 {
+		chain::tip_height(&mut self.0)
+	}
+
+	/// The hash (hex) of the block at the given height in the active chain.
+	pub fn block_hash_at(&mut self, height: u32) -> String {
+		if let Some(hash) = self.2.as_ref().and_then(|c| c.block_hash_at(height)) {
+			return hash.to_owned();
+		}
+		let hash = chain::block_hash_at(&mut self.0, height);
+		if let Some(ref mut cache) = self.2 {
+			cache.put_block_hash(height, hash.clone());
+		}
+		hash
+	}
+
+	/// Fetch the block at the given height in the active chain.
+	pub fn block_at(&mut self, height: u32) -> Block {
+		let hash = self.block_hash_at(height).parse().expect("invalid block hash from bitcoind");
+		Block::query(&mut self.0, &hash).expect(&format!("fetching block number {}", height))
+	}
+
+	/// The hashes (hex) of the blocks at the given heights in the active chain.
+	fn block_hashes_at(&mut self, heights: &[u32]) -> Vec<String> {
+		let mut hashes: Vec<_> = heights
+			.iter()
+			.map(|h| self.2.as_ref().and_then(|c| c.block_hash_at(*h)).map(|h| h.to_owned()))
+			.collect();
+		let missing: Vec<u32> = heights
+			.iter()
+			.zip(hashes.iter())
+			.filter(|&(_, hash)| hash.is_none())
+			.map(|(height, _)| *height)
+			.collect();
+		let params = missing.iter().map(|h| vec![(*h).into()]).collect();
+		let results = self.3.call("getblockhash", params, self.3.batch_size);
+		let mut fetched = HashMap::new();
+		for (height, result) in missing.into_iter().zip(results.into_iter()) {
+			let hash = result.expect(&format!("error calling getblockhash for block {}", height));
+			let hash = hash.as_str().expect("invalid getblockhash result").to_owned();
+			if let Some(ref mut cache) = self.2 {
+				cache.put_block_hash(height, hash.clone());
+			}
+			fetched.insert(height, hash);
+		}
+		for (height, hash) in heights.iter().zip(hashes.iter_mut()) {
+			if hash.is_none() {
+				*hash = fetched.get(height).cloned();
+			}
+		}
+		hashes.into_iter().map(|h| h.unwrap()).collect()
+	}
+
+	/// Fetch the blocks with the given hashes.
+	/// Blocks are large, so every block is fetched in a request of its own.
+	fn fetch_blocks(&self, hashes: &[String]) -> Vec<Block> {
+		let params = hashes.iter().map(|h| vec![h.as_str().into(), 0.into()]).collect();
+		let results = self.3.call("getblock", params, 1);
+		hashes
+			.iter()
+			.zip(results.into_iter())
+			.map(|(hash, result)| {
+				let raw = result.expect(&format!("error fetching block {}", hash));
+				let raw = raw.as_str().and_then(|r| hex::decode(r).ok());
+				raw.and_then(|r| deserialize(&r).ok())
+					.expect(&format!("invalid block {} from bitcoind", hash))
+			}).collect()
+	}
+
+	/// Fetch the blocks at the given heights in the active chain.
+	pub fn blocks_at(&mut self, heights: &[u32]) -> Vec<Block> {
+		let hashes = self.block_hashes_at(heights);
+		self.fetch_blocks(&hashes)
+	}
+
+	/// The heights of the blocks with the given hashes that are in the active chain.
+	fn active_block_heights(&mut self, hashes: &[String]) -> HashMap<String, u32> {
+		let mut heights = HashMap::new();
+		let mut missing = Vec::new();
+		for hash in hashes.iter() {
+			match self.2.as_ref().and_then(|c| c.block_height(hash)) {
+				Some(height) => {
+					heights.insert(hash.clone(), height);
+				}
+				None => missing.push(hash.clone()),
+			}
+		}
+		let params = missing.iter().map(|h| vec![h.as_str().into()]).collect();
+		let results = self.3.call("getblockheader", params, self.3.batch_size);
+		for (hash, result) in missing.into_iter().zip(results.into_iter()) {
+			let header = match result {
+				Ok(header) => header,
+				Err(_) => continue,
+			};
+			// Blocks that are not in the active chain have -1 confirmations.
+			if header["confirmations"].as_i64().unwrap_or(-1) < 0 {
+				continue;
+			}
+			if let Some(height) = header["height"].as_u64().map(|h| h as u32) {
+				if let Some(ref mut cache) = self.2 {
+					cache.put_block_hash(height, hash.clone());
+				}
+				heights.insert(hash, height);
+			}
+		}
+		heights
+	}
+
+	/// The height of the block with the given hash, if it is in the active chain.
+	pub fn active_block_height(&mut self, block_hash: &str) -> Option<u32> {
+		if let Some(height) = self.2.as_ref().and_then(|c| c.block_height(block_hash)) {
+			return Some(height);
+		}
+		let height = chain::active_block_height(&mut self.0, block_hash);
+		if let (Some(height), Some(cache)) = (height, self.2.as_mut()) {
+			cache.put_block_hash(height, block_hash.to_owned());
+		}
+		height
+	}
+
+	/// Fetch the balances of the given public keys at the given block.
+	///
+	/// The current UTXO set is scanned, and the outputs that existed at the block but have been
+	/// spent since are found using the block filters, which requires Bitcoin Core 25 or later
+	/// running with `-blockfilterindex`.
+	pub fn fetch_pubkey_balances(&mut self, pubkeys: &[Vec<u8>], block_number: u32) -> Vec<u64> {
+		let mut script_keys = HashMap::new();
+		for (idx, pubkey) in pubkeys.iter().enumerate() {
+			for script in bitcoin::pubkey_scripts(pubkey).into_iter() {
+				script_keys.insert(script, idx);
+			}
+		}
+
+		let descriptors: Vec<Value> =
+			pubkeys.iter().map(|pk| format!("combo({})", hex::encode(pk)).into()).collect();
+		let mut outputs = self.scan_utxo_set(descriptors.clone());
+		if self.tip_height() > block_number {
+			let scripts = script_keys.keys().cloned().collect();
+			outputs.extend(self.spent_outputs(descriptors, &scripts, block_number + 1));
+		}
+
+		let mut balances = vec![0; pubkeys.len()];
+		for output in outputs.into_iter() {
+			if output.height > block_number {
+				continue;
+			}
+			if let Some(idx) = script_keys.get(&output.txout.script_pubkey) {
+				balances[*idx] += output.txout.value;
+			}
+		}
+		balances
+	}
+
+	/// Scan the UTXO set for the outputs matching the given output descriptors.
+	/// Ranged descriptors are given as objects with the descriptor and the range.
+	pub fn scan_utxo_set(&mut self, descriptors: Vec<Value>) -> Vec<ScannedOutput> {
+		let res: Value = self
+			.0
+			.call("scantxoutset", &["start".into(), descriptors.into()])
+			.expect("error scanning the UTXO set with bitcoind");
+		if res["success"] != Value::Bool(true) {
+			panic!("bitcoind failed to scan the UTXO set");
+		}
+
+		let invalid = "invalid scantxoutset response";
+		let unspents = res["unspents"].as_array().expect(invalid);
+		unspents
+			.iter()
+			.map(|unspent| {
+				let txid = unspent["txid"].as_str().expect(invalid);
+				let vout = unspent["vout"].as_u64().expect(invalid);
+				let script = unspent["scriptPubKey"].as_str().expect(invalid);
+				let amount = unspent["amount"].as_f64().expect(invalid);
+				ScannedOutput {
+					point: format!("{}:{}", txid, vout).parse().expect(invalid),
+					txout: TxOut {
+						value: (amount * 100_000_000.0).round() as u64,
+						script_pubkey: hex::decode(script).expect(invalid).into(),
+					},
+					height: unspent["height"].as_u64().expect(invalid) as u32,
+				}
+			}).collect()
+	}
+
+	/// The hashes of the blocks from the given height up to the tip that may create or spend
+	/// outputs of the given output descriptors, according to the block filters.  This needs
+	/// Bitcoin Core 25 or later running with `-blockfilterindex`.
+	fn relevant_blocks(&mut self, descriptors: Vec<Value>, start: u32) -> Vec<String> {
+		let res: Value = self
+			.0
+			.call("scanblocks", &["start".into(), descriptors.into(), start.into()])
+			.expect("error scanning the block filters with bitcoind (is -blockfilterindex on?)");
+		let invalid = "invalid scanblocks response";
+		res["relevant_blocks"]
+			.as_array()
+			.expect(invalid)
+			.iter()
+			.map(|h| h.as_str().expect(invalid).to_owned())
+			.collect()
+	}
+
+	/// Find which of the given scripts ever received coins in the active chain.
+	/// The scripts are those of the given output descriptors, whose blocks are found using the
+	/// block filters, which requires bitcoind to run with `-blockfilterindex`.
+	pub fn used_scripts(
+		&mut self,
+		descriptors: Vec<Value>,
+		scripts: &HashSet<Script>,
+	) -> HashSet<Script> {
+		let hashes = self.relevant_blocks(descriptors, 0);
+
+		// Block filters can have false positives, so check the outputs in the blocks.
+		let mut used = HashSet::new();
+		for window in hashes.chunks(self.3.workers) {
+			for block in self.fetch_blocks(window).into_iter() {
+				for tx in block.txdata.into_iter() {
+					for output in tx.output.into_iter() {
+						if scripts.contains(&output.script_pubkey) {
+							used.insert(output.script_pubkey);
+						}
+					}
+				}
+			}
+		}
+		used
+	}
+
+	/// Find the outputs with the given scripts that were created before the block at `start`
+	/// and spent in that block or later.  Together with the current UTXO set, these are the
+	/// outputs that existed at the block before `start`.
+	///
+	/// The blocks are found using the block filters of the given output descriptors and are
+	/// fetched with their prevouts, which requires Bitcoin Core 25 or later running with
+	/// `-blockfilterindex`.
+	pub fn spent_outputs(
+		&mut self,
+		descriptors: Vec<Value>,
+		scripts: &HashSet<Script>,
+		start: u32,
+	) -> Vec<ScannedOutput> {
+		let hashes = self.relevant_blocks(descriptors, start);
+
+		let invalid = "invalid getblock response";
+		let mut spent = Vec::new();
+		for window in hashes.chunks(self.3.workers) {
+			let params = window.iter().map(|h| vec![h.as_str().into(), 3.into()]).collect();
+			let results = self.3.call("getblock", params, 1);
+			for (hash, result) in window.iter().zip(results.into_iter()) {
+				let block = result.expect(&format!("error fetching block {}", hash));
+				for tx in block["tx"].as_array().expect(invalid).iter() {
+					for input in tx["vin"].as_array().expect(invalid).iter() {
+						// Coinbase inputs have no prevout.
+						let prevout = &input["prevout"];
+						if prevout.is_null() {
+							continue;
+						}
+						let height = prevout["height"].as_u64().expect(invalid) as u32;
+						let script = prevout["scriptPubKey"]["hex"].as_str().expect(invalid);
+						let script: Script = hex::decode(script).expect(invalid).into();
+						if height >= start || !scripts.contains(&script) {
+							continue;
+						}
+						let txid = input["txid"].as_str().expect(invalid);
+						let vout = input["vout"].as_u64().expect(invalid);
+						let amount = prevout["value"].as_f64().expect(invalid);
+						spent.push(ScannedOutput {
+							point: format!("{}:{}", txid, vout).parse().expect(invalid),
+							txout: TxOut {
+								value: (amount * 100_000_000.0).round() as u64,
+								script_pubkey: script,
+							},
+							height: height,
+						});
+					}
+				}
+			}
+		}
+		spent
+	}
+
+	/// Create a UTXO for an output found by scanning the UTXO set.
+	pub fn scanned_utxo(&mut self, output: ScannedOutput) -> UTXO {
+		let block_hash =
+			self.block_hash_at(output.height).parse().expect("invalid block hash from bitcoind");
+		let tx = self
+			.0
+			.get_raw_transaction(&output.point.txid, Some(&block_hash))
+			.expect(&format!("error retrieving tx {} from bitcoind", output.point.txid));
+
+		let mut psbt_input: psbt::Input = Default::default();
+		psbt_input.non_witness_utxo = Some(tx);
+		psbt_input.witness_utxo = Some(output.txout);
+		UTXO {
+			point: output.point,
+			psbt_input: psbt_input,
+			block_number: output.height,
+			block_hash: Some(block_hash),
+			wallet: None,
 		}
-		prevouts
 	}
 }
@@ -0,0 +1,349 @@
+//! A persistent local cache of chain data for the bitcoind backend.
+//!
+//! Transactions are stored by txid in separate files.  The index file holds the data that
+//! depends on the active chain: the block hashes of the known heights, the blocks that confirm
+//! the cached transactions and the known spend status of outpoints.  When the cache is opened,
+//! the cached chain is compared with the node's chain and the data of blocks that were
+//! reorganized out is dropped.
+
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::PathBuf;
+
+use rbitcoin::consensus::encode::{deserialize, serialize};
+use rbitcoin::util::hash::BitcoinHash;
+use rbitcoin::{OutPoint, Transaction};
+use serde_json::{self, Value};
+
+/// The name of the index file in the cache directory.
+const INDEX_FILE: &'static str = "index.json";
+
+/// What is known about whether an outpoint is spent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpendStatus {
+	/// The outpoint was unspent at the given height.
+	Unspent(u32),
+	/// The outpoint was spent at or before the given height.
+	SpentBy(u32),
+	/// The outpoint was spent in the block at the given height.
+	SpentIn(u32),
+}
+
+impl SpendStatus {
+	/// The height the status is based on.
+	fn height(&self) -> u32 {
+		match *self {
+			SpendStatus::Unspent(h) | SpendStatus::SpentBy(h) | SpendStatus::SpentIn(h) => h,
+		}
+	}
+
+	/// Whether this status holds more information than the other one.
+	fn supersedes(&self, other: &SpendStatus) -> bool {
+		match (*self, *other) {
+			(SpendStatus::SpentIn(_), SpendStatus::SpentIn(_)) => false,
+			(SpendStatus::SpentIn(_), _) => true,
+			(SpendStatus::SpentBy(_), SpendStatus::SpentIn(_)) => false,
+			(SpendStatus::SpentBy(h), SpendStatus::SpentBy(o)) => h < o,
+			(SpendStatus::SpentBy(_), SpendStatus::Unspent(_)) => true,
+			(SpendStatus::Unspent(h), SpendStatus::Unspent(o)) => h > o,
+			(SpendStatus::Unspent(_), _) => false,
+		}
+	}
+
+	fn to_json(&self) -> Value {
+		match *self {
+			SpendStatus::Unspent(h) => json!({ "unspent": h }),
+			SpendStatus::SpentBy(h) => json!({ "spent_by": h }),
+			SpendStatus::SpentIn(h) => json!({ "spent_in": h }),
+		}
+	}
+
+	fn from_json(v: &Value) -> Option<SpendStatus> {
+		let height = |key: &str| v[key].as_u64().map(|h| h as u32);
+		height("spent_in")
+			.map(SpendStatus::SpentIn)
+			.or_else(|| height("spent_by").map(SpendStatus::SpentBy))
+			.or_else(|| height("unspent").map(SpendStatus::Unspent))
+	}
+}
+
+/// The chain data cache in a directory.  Changes are written to disk when the cache is dropped.
+pub struct ChainCache {
+	dir: PathBuf,
+	/// The block hashes of the known heights in the active chain.
+	chain: BTreeMap<u32, String>,
+	/// The heights of the blocks in `chain`.
+	heights: HashMap<String, u32>,
+	/// The hash of the block that confirms a transaction, by txid.
+	tx_blocks: HashMap<String, String>,
+	spends: HashMap<String, SpendStatus>,
+	dirty: bool,
+}
+
+impl ChainCache {
+	/// Open the cache in the given directory, creating it if needed.
+	pub fn open(dir: &str) -> ChainCache {
+		let dir = PathBuf::from(dir);
+		fs::create_dir_all(dir.join("txs"))
+			.expect(&format!("error creating cache directory '{}'", dir.display()));
+
+		let mut cache = ChainCache {
+			dir: dir,
+			chain: BTreeMap::new(),
+			heights: HashMap::new(),
+			tx_blocks: HashMap::new(),
+			spends: HashMap::new(),
+			dirty: false,
+		};
+		let index = cache.dir.join(INDEX_FILE);
+		if !index.exists() {
+			return cache;
+		}
+
+		let contents = fs::read_to_string(&index).expect("error reading the cache index");
+		let json: Value = match serde_json::from_str(&contents) {
+			Ok(json) => json,
+			Err(e) => {
+				println!("Warning: ignoring the corrupt chain cache index: {}", e);
+				return cache;
+			}
+		};
+		if let Some(chain) = json["chain"].as_object() {
+			for (height, hash) in chain.iter() {
+				if let (Ok(height), Some(hash)) = (height.parse(), hash.as_str()) {
+					cache.chain.insert(height, hash.to_owned());
+					cache.heights.insert(hash.to_owned(), height);
+				}
+			}
+		}
+		if let Some(tx_blocks) = json["tx_blocks"].as_object() {
+			for (txid, hash) in tx_blocks.iter() {
+				if let Some(hash) = hash.as_str() {
+					cache.tx_blocks.insert(txid.clone(), hash.to_owned());
+				}
+			}
+		}
+		if let Some(spends) = json["spends"].as_object() {
+			for (point, status) in spends.iter() {
+				if let Some(status) = SpendStatus::from_json(status) {
+					cache.spends.insert(point.clone(), status);
+				}
+			}
+		}
+		cache
+	}
+
+	/// Compare the cached chain with the active chain from the top down and drop the data of
+	/// the blocks that are no longer in the active chain.  The given function returns the hash
+	/// of the block at a height in the active chain, or None above the tip.
+	pub fn check_chain<F: FnMut(u32) -> Option<String>>(&mut self, mut hash_at: F) {
+		// Find the highest cached block that is still in the active chain.
+		let top = match self.chain.keys().next_back() {
+			Some(top) => *top,
+			None => return,
+		};
+		let mut valid_height = None;
+		let heights: Vec<u32> = self.chain.keys().rev().cloned().collect();
+		for height in heights.into_iter() {
+			if hash_at(height).as_ref() == self.chain.get(&height) {
+				valid_height = Some(height);
+				break;
+			}
+		}
+		if valid_height == Some(top) {
+			return;
+		}
+
+		// All data about the blocks above the last valid block is stale.
+		let fork_height = valid_height.map(|h| h + 1).unwrap_or(0);
+		println!("Chain reorganization detected from block {}, updating cache.", fork_height);
+		let stale: Vec<String> =
+			self.chain.split_off(&fork_height).into_iter().map(|(_, h)| h).collect();
+		for hash in stale.iter() {
+			self.heights.remove(hash);
+		}
+		self.tx_blocks.retain(|_, hash| !stale.contains(hash));
+		self.spends.retain(|_, status| status.height() < fork_height);
+		self.dirty = true;
+	}
+
+	/// The height of the highest known block, which is the tip when the cache was opened.
+	pub fn tip_height(&self) -> Option<u32> {
+		self.chain.keys().next_back().cloned()
+	}
+
+	/// The hash of the block at the given height in the active chain.
+	pub fn block_hash_at(&self, height: u32) -> Option<&str> {
+		self.chain.get(&height).map(|h| h.as_str())
+	}
+
+	/// The height of the block with the given hash in the active chain.
+	pub fn block_height(&self, hash: &str) -> Option<u32> {
+		self.heights.get(hash).cloned()
+	}
+
+	/// Record the hash of the block at the given height in the active chain.
+	pub fn put_block_hash(&mut self, height: u32, hash: String) {
+		if let Some(old) = self.chain.insert(height, hash.clone()) {
+			self.heights.remove(&old);
+		}
+		self.heights.insert(hash, height);
+		self.dirty = true;
+	}
+
+	fn tx_path(&self, txid: &str) -> PathBuf {
+		self.dir.join("txs").join(txid)
+	}
+
+	/// Get a cached transaction.
+	pub fn tx(&self, txid: &str) -> Option<Transaction> {
+		let raw = fs::read(self.tx_path(txid)).ok()?;
+		deserialize(&raw).ok()
+	}
+
+	/// Store a transaction in the cache.
+	pub fn put_tx(&mut self, tx: &Transaction) {
+		let path = self.tx_path(&tx.bitcoin_hash().to_string());
+		if !path.exists() {
+			fs::write(&path, serialize(tx)).expect("error writing to the chain cache");
+		}
+	}
+
+	/// The hash of the block that confirms the transaction.
+	pub fn tx_block(&self, txid: &str) -> Option<&str> {
+		self.tx_blocks.get(txid).map(|h| h.as_str())
+	}
+
+	/// Record the hash of the block that confirms the transaction.
+	pub fn put_tx_block(&mut self, txid: String, block_hash: String) {
+		self.tx_blocks.insert(txid, block_hash);
+		self.dirty = true;
+	}
+
+	/// The known spend status of the outpoint.
+	pub fn spend(&self, point: &OutPoint) -> Option<SpendStatus> {
+		self.spends.get(&point.to_string()).cloned()
+	}
+
+	/// Record the spend status of the outpoint, if it's more informative than the known status.
+	pub fn put_spend(&mut self, point: &OutPoint, status: SpendStatus) {
+		let key = point.to_string();
+		let update = self.spends.get(&key).map(|old| status.supersedes(old)).unwrap_or(true);
+		if update {
+			self.spends.insert(key, status);
+			self.dirty = true;
+		}
+	}
+
+	fn save(&self) {
+		let chain: BTreeMap<String, &String> =
+			self.chain.iter().map(|(h, hash)| (h.to_string(), hash)).collect();
+		let spends: BTreeMap<&String, Value> =
+			self.spends.iter().map(|(p, s)| (p, s.to_json())).collect();
+		let index = json!({
+			"chain": chain,
+			"tx_blocks": self.tx_blocks,
+			"spends": spends,
+		});
+		// This is called when dropping, so don't panic.
+		let path = self.dir.join(INDEX_FILE);
+		if let Err(e) = fs::write(&path, index.to_string()) {
+			println!("Warning: error writing the chain cache index: {}", e);
+		}
+	}
+}
+
+impl Drop for ChainCache {
+	fn drop(&mut self) {
+		if self.dirty {
+			self.save();
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use std::env;
+
+	/// A cache with blocks at heights 100, 102 and 104, a transaction confirmed in each of them
+	/// and spends at several heights.
+	fn cache(name: &str) -> ChainCache {
+		let dir = env::temp_dir().join(format!("reserves-cache-test-{}", name));
+		let _ = fs::remove_dir_all(&dir);
+		let mut cache = ChainCache::open(dir.to_str().unwrap());
+		for height in [100, 102, 104].iter() {
+			cache.put_block_hash(*height, format!("block{}", height));
+			cache.put_tx_block(format!("tx{}", height), format!("block{}", height));
+		}
+		let spends = [
+			("a:0", SpendStatus::SpentIn(101)),
+			("b:0", SpendStatus::SpentBy(102)),
+			("c:0", SpendStatus::SpentIn(103)),
+			("d:0", SpendStatus::Unspent(104)),
+		];
+		for &(point, status) in spends.iter() {
+			cache.spends.insert(point.to_owned(), status);
+		}
+		cache
+	}
+
+	#[test]
+	fn no_reorg() {
+		let mut cache = cache("no-reorg");
+		let mut queried = Vec::new();
+		cache.check_chain(|h| {
+			queried.push(h);
+			Some(format!("block{}", h))
+		});
+		assert_eq!(queried, vec![104]);
+		assert_eq!(cache.chain.len(), 3);
+		assert_eq!(cache.tx_blocks.len(), 3);
+		assert_eq!(cache.spends.len(), 4);
+	}
+
+	#[test]
+	fn reorg() {
+		let mut cache = cache("reorg");
+		cache.check_chain(|h| match h {
+			104 => Some("other104".to_owned()),
+			h => Some(format!("block{}", h)),
+		});
+		assert_eq!(cache.tip_height(), Some(102));
+		assert_eq!(cache.block_height("block104"), None);
+		assert_eq!(cache.block_height("block102"), Some(102));
+		assert_eq!(cache.tx_block("tx104"), None);
+		assert_eq!(cache.tx_block("tx102"), Some("block102"));
+		assert_eq!(cache.spends.get("a:0"), Some(&SpendStatus::SpentIn(101)));
+		assert_eq!(cache.spends.get("b:0"), Some(&SpendStatus::SpentBy(102)));
+		assert_eq!(cache.spends.get("c:0"), None);
+		assert_eq!(cache.spends.get("d:0"), None);
+	}
+
+	#[test]
+	fn full_mismatch() {
+		let mut cache = cache("full-mismatch");
+		cache.check_chain(|h| Some(format!("other{}", h)));
+		assert!(cache.chain.is_empty());
+		assert!(cache.heights.is_empty());
+		assert!(cache.tx_blocks.is_empty());
+		assert!(cache.spends.is_empty());
+	}
+
+	#[test]
+	fn supersedes() {
+		use self::SpendStatus::*;
+
+		assert!(SpentIn(5).supersedes(&SpentBy(5)));
+		assert!(SpentIn(5).supersedes(&Unspent(4)));
+		assert!(!SpentIn(5).supersedes(&SpentIn(6)));
+		assert!(SpentBy(5).supersedes(&Unspent(4)));
+		assert!(SpentBy(5).supersedes(&SpentBy(6)));
+		assert!(!SpentBy(6).supersedes(&SpentBy(5)));
+		assert!(!SpentBy(5).supersedes(&SpentIn(5)));
+		assert!(Unspent(5).supersedes(&Unspent(4)));
+		assert!(!Unspent(4).supersedes(&Unspent(5)));
+		assert!(!Unspent(5).supersedes(&SpentBy(6)));
+		assert!(!Unspent(5).supersedes(&SpentIn(6)));
+	}
+}
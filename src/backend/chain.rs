@@ -0,0 +1,30 @@
+//! Chain queries shared by the bitcoind and elementsd backends.
+
+use bitcoincore_rpc as rpc;
+use serde_json::Value;
+
+/// The height of the tip of the active chain.
+pub fn tip_height(client: &mut rpc::Client) -> u32 {
+	let count: Value = client.call("getblockcount", &[]).expect("error calling getblockcount");
+	count.as_u64().expect("invalid getblockcount result") as u32
+}
+
+/// The hash (hex) of the block at the given height in the active chain.
+pub fn block_hash_at(client: &mut rpc::Client, height: u32) -> String {
+	let hash: Value =
+		client.call("getblockhash", &[height.into()]).expect("error calling getblockhash");
+	hash.as_str().expect("invalid getblockhash result").to_owned()
+}
+
+/// The height of the block with the given hash, if it is in the active chain.
+pub fn active_block_height(client: &mut rpc::Client, block_hash: &str) -> Option<u32> {
+	let header: Value = match client.call("getblockheader", &[block_hash.into()]) {
+		Ok(header) => header,
+		Err(_) => return None,
+	};
+	// Blocks that are not in the active chain have -1 confirmations.
+	if header["confirmations"].as_i64().unwrap_or(-1) < 0 {
+		return None;
+	}
+	header["height"].as_u64().map(|h| h as u32)
+}
@@ -0,0 +1,335 @@
+use bitcoincore_rpc as rpc;
+use clap;
+use elements::encode::deserialize;
+use elements::hashes::hex::FromHex;
+use elements::pset;
+use elements::confidential::{AssetBlindingFactor, ValueBlindingFactor};
+use elements::{
+	AssetId, Block, BlockHash, OutPoint, Script, Transaction, TxOut, TxOutSecrets, Txid,
+};
+use hex;
+use serde_json::Value;
+
+use backend::chain;
+use liquid::*;
+
+pub fn args<'a>() -> Vec<clap::Arg<'a, 'a>> {
+	vec![
+		clap::Arg::with_name("elementsd")
+			.long("elementsd")
+			.help("the RPC endpoint for elementsd")
+			.takes_value(true),
+		clap::Arg::with_name("elementsd-user")
+			.long("elementsd-user")
+			.help("the RPC user for elementsd")
+			.takes_value(true),
+		clap::Arg::with_name("elementsd-pass")
+			.long("elementsd-pass")
+			.help("the RPC pass for elementsd")
+			.takes_value(true),
+	]
+}
+
+/// The elementsd backend.
+///
+/// The RPC client library only knows about Bitcoin types, so we make the raw calls ourselves and
+/// decode the Elements data that is returned.
+pub struct Backend(rpc::Client);
+
+impl Backend {
+	pub fn load<'a>(matches: &'a clap::ArgMatches) -> Option<Self> {
+		match matches.value_of("elementsd") {
+			None => None,
+			Some(endpoint) => Some(Backend(rpc::Client::new(
+				endpoint.to_string(),
+				matches.value_of("elementsd-user").map(|v| v.to_string()),
+				matches.value_of("elementsd-pass").map(|v| v.to_string()),
+			))),
+		}
+	}
+
+	fn call(&mut self, cmd: &str, args: &[Value]) -> Value {
+		self.0.call(cmd, args).expect(&format!("error calling {} on elementsd", cmd))
+	}
+
+	/// Fetch and decode the transaction with the given txid.
+	fn get_transaction(&mut self, txid: &Txid) -> Transaction {
+		let res = self.call("getrawtransaction", &[txid.to_string().into()]);
+		let raw = hex::decode(res.as_str().expect("invalid getrawtransaction result"))
+			.expect("invalid tx hex from elementsd");
+		deserialize(&raw).expect(&format!("failed to decode tx {}", txid))
+	}
+
+	/// Find the hash and height of the block that contains the given tx.
+	fn get_tx_block(&mut self, txid: &Txid) -> (BlockHash, u32) {
+		let res = self.call("getrawtransaction", &[txid.to_string().into(), true.into()]);
+		let block_hash = BlockHash::from_hex(
+			res["blockhash"].as_str().expect(&format!("tx {} is not confirmed", txid)),
+		).expect("invalid block hash from elementsd");
+		let header = self.call("getblockheader", &[block_hash.to_string().into()]);
+		let height = header["height"].as_u64().expect("invalid getblockheader result") as u32;
+		(block_hash, height)
+	}
+
+	/// The height of the current chain tip.
+	pub fn tip_height(&mut self) -> u32 {
+		chain::tip_height(&mut self.0)
+	}
+
+	/// The hash (hex) of the block at the given height in the active chain.
+	pub fn block_hash_at(&mut self, height: u32) -> String {
+		chain::block_hash_at(&mut self.0, height)
+	}
+
+	/// The height of the block with the given hash, if it is in the active chain.
+	pub fn active_block_height(&mut self, block_hash: &str) -> Option<u32> {
+		chain::active_block_height(&mut self.0, block_hash)
+	}
+
+	/// Fetch unspent outputs from the node's wallet.
+	///
+	/// For confidential outputs, the unblinding data known to the wallet is added.
+	pub fn fetch_utxos(&mut self) -> Vec<UTXO> {
+		let mut utxos = Vec::new();
+
+		let unspents = self.call("listunspent", &[6.into()]);
+		for unspent in unspents.as_array().expect("invalid listunspent result").iter() {
+			if !unspent["spendable"].as_bool().unwrap_or(false) {
+				continue;
+			}
+
+			let txid = Txid::from_hex(unspent["txid"].as_str().expect("missing txid"))
+				.expect("invalid txid from elementsd");
+			let vout = unspent["vout"].as_u64().expect("missing vout") as u32;
+			let point = OutPoint {
+				txid: txid,
+				vout: vout,
+			};
+
+			// Fetch tx and block info.
+			let tx = self.get_transaction(&txid);
+			let (block_hash, block_number) = self.get_tx_block(&txid);
+			let txout = tx.output.get(vout as usize).expect("unspent vout doesn't exist").clone();
+			let unblinding = if txout.asset.is_explicit() && txout.value.is_explicit() {
+				None
+			} else {
+				let secrets = unblinding_from_listunspent(unspent)
+					.and_then(|s| verify_unblinding(&txout, &s).map(|_| s));
+				match secrets {
+					Ok(secrets) => Some(secrets),
+					Err(e) => {
+						println!(
+							"No unblinding data for confidential UTXO {} ({}), add it with \
+							 unblind-utxo.",
+							point, e
+						);
+						None
+					}
+				}
+			};
+
+			let mut pset_input = pset::Input::from_prevout(point);
+			pset_input.non_witness_utxo = Some(tx.clone());
+			pset_input.witness_utxo = Some(txout);
+			if let Some(redeem) = unspent["redeemScript"].as_str() {
+				pset_input.redeem_script =
+					Some(Script::from(hex::decode(redeem).expect("invalid redeem script hex")));
+			}
+
+			utxos.push(UTXO {
+				point: point,
+				pset_input: pset_input,
+				unblinding: unblinding,
+				block_number: block_number,
+				block_hash: Some(block_hash),
+			});
+		}
+		utxos
+	}
+
+	/// Ask elementsd to sign the given PSET and return the final tx.
+	pub fn sign_pset(&mut self, pset: &pset::PartiallySignedTransaction) -> Transaction {
+		let processed = self.call("walletprocesspsbt", &[pset.to_string().into(), true.into()]);
+		let finalized = self.call("finalizepsbt", &[processed["psbt"].clone(), false.into()]);
+		if !finalized["complete"].as_bool().unwrap_or(false) {
+			panic!("elementsd was unable to sign all inputs of the proof tx");
+		}
+		let signed: pset::PartiallySignedTransaction = finalized["psbt"]
+			.as_str()
+			.expect("invalid finalizepsbt result")
+			.parse()
+			.expect("failed to parse signed PSET from elementsd");
+
+		let mut tx = signed.extract_tx().expect("failed to extract signed proof tx");
+		// Make sure the challenge input is left untouched.
+		tx.input[0].script_sig = Script::new();
+		tx.input[0].witness = Default::default();
+		tx
+	}
+
+	/// Fetch the previous outpoints for the inputs of the proof tx.  We do this to verify they
+	/// all existed at the given block number.
+	pub fn fetch_proof_prevouts(&mut self, proof: &Proof, proof_block_number: u32) -> Vec<TxOut> {
+		let mut prevouts = Vec::new();
+		for (idx, input) in proof.proof_tx.as_ref().unwrap().input.iter().enumerate() {
+			// Skip the challenge input.
+			if idx == 0 {
+				continue;
+			}
+			let point = input.previous_output;
+
+			// If the output is still unspent, we can take it from the tx.
+			let unspent = self.call(
+				"gettxout",
+				&[point.txid.to_string().into(), point.vout.into(), false.into()],
+			);
+			if !unspent.is_null() {
+				let (_, block_number) = self.get_tx_block(&point.txid);
+				if block_number > proof_block_number {
+					panic!(
+						"Input {} of proof '{}' was not valid at block {} (included in block {})",
+						point, proof.id, proof_block_number, block_number
+					);
+				}
+				let tx = self.get_transaction(&point.txid);
+				prevouts.push(tx.output[point.vout as usize].clone());
+				continue;
+			}
+
+			// The output is no longer unspent. Find it in the block given in the proof file.
+			let existing = proof.utxos.iter().find(|u| u.point == point);
+			if let Some(existing) = existing {
+				let mut block_hash = existing.block_hash;
+				if block_hash.is_none() && existing.block_number != 0 {
+					let res = self.call("getblockhash", &[existing.block_number.into()]);
+					block_hash = Some(
+						BlockHash::from_hex(res.as_str().expect("invalid getblockhash result"))
+							.expect("invalid block hash from elementsd"),
+					);
+				}
+
+				if let Some(block_hash) = block_hash {
+					let header = self.call("getblockheader", &[block_hash.to_string().into()]);
+					let height = header["height"].as_u64().expect("invalid block header") as u32;
+					if height > proof_block_number {
+						panic!(
+							"Input {} of proof '{}' was not valid at block {} (included in block {})",
+							point, proof.id, proof_block_number, height
+						);
+					}
+
+					let res = self.call("getblock", &[block_hash.to_string().into(), 0.into()]);
+					let raw = hex::decode(res.as_str().expect("invalid getblock result"))
+						.expect("invalid block hex from elementsd");
+					let block: Block = deserialize(&raw).expect("failed to decode block");
+					let found = block.txdata.into_iter().find(|tx| tx.txid() == point.txid);
+					if let Some(tx) = found {
+						let out = tx.output.get(point.vout as usize).expect(&format!(
+							"outpoint of tx #{} from proof tx '{}' contains non-existent vout {}",
+							idx, proof.id, point.vout,
+						));
+						prevouts.push(out.clone());
+						continue;
+					}
+				}
+			}
+
+			panic!("Cannot find output for input #{} of proof tx '{}'!", idx, proof.id);
+		}
+		prevouts
+	}
+}
+
+/// Parse an amount in BTC as returned by the RPC into satoshis, without going through floating
+/// point arithmetic.
+fn parse_amount(amount: &Value) -> Result<u64, String> {
+	let amount = match *amount {
+		Value::Number(ref n) => n.to_string(),
+		Value::String(ref s) => s.clone(),
+		_ => return Err("the amount is not a number".into()),
+	};
+	let (mantissa, exp) = match amount.find(|c| c == 'e' || c == 'E') {
+		Some(idx) => {
+			let exp: i32 = amount[idx + 1..].parse().map_err(|_| "invalid amount exponent")?;
+			(&amount[..idx], exp)
+		}
+		None => (&amount[..], 0),
+	};
+	let (int, frac) = match mantissa.find('.') {
+		Some(idx) => (&mantissa[..idx], &mantissa[idx + 1..]),
+		None => (mantissa, ""),
+	};
+	let digits = format!("{}{}", int, frac);
+	if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit()) {
+		return Err(format!("invalid amount {}", amount));
+	}
+
+	// The amount in satoshis is the digits shifted by this many places.
+	let shift = 8 + exp - frac.len() as i32;
+	let sats = if shift >= 0 {
+		format!("{}{}", digits, "0".repeat(shift as usize))
+	} else {
+		let (sats, rest) = digits.split_at(digits.len().saturating_sub(-shift as usize));
+		if rest.chars().any(|c| c != '0') {
+			return Err(format!("amount {} has more than 8 decimals", amount));
+		}
+		sats.to_owned()
+	};
+	match sats.is_empty() {
+		true => Ok(0),
+		false => sats.parse().map_err(|_| format!("amount {} out of range", amount)),
+	}
+}
+
+/// Construct the unblinding data for a confidential output from a listunspent entry.
+fn unblinding_from_listunspent(unspent: &Value) -> Result<TxOutSecrets, String> {
+	let field = |name: &str| -> Result<&str, String> {
+		unspent[name].as_str().ok_or(format!("missing {} in listunspent result", name))
+	};
+	Ok(TxOutSecrets {
+		asset: AssetId::from_hex(field("asset")?).map_err(|_| "invalid asset from elementsd")?,
+		asset_bf: AssetBlindingFactor::from_hex(field("assetblinder")?)
+			.map_err(|_| "invalid asset blinder from elementsd")?,
+		value: parse_amount(&unspent["amount"])?,
+		value_bf: ValueBlindingFactor::from_hex(field("amountblinder")?)
+			.map_err(|_| "invalid amount blinder from elementsd")?,
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn amount(s: &str) -> Result<u64, String> {
+		parse_amount(&::serde_json::from_str(s).unwrap())
+	}
+
+	#[test]
+	fn parse_amounts() {
+		assert_eq!(amount("0"), Ok(0));
+		assert_eq!(amount("21000000"), Ok(2_100_000_000_000_000));
+		assert_eq!(amount("0.1"), Ok(10_000_000));
+		assert_eq!(amount("0.29"), Ok(29_000_000));
+		assert_eq!(amount("20999999.99999999"), Ok(2_099_999_999_999_999));
+		assert_eq!(amount("\"1.5\""), Ok(150_000_000));
+		assert_eq!(amount("\"1.\""), Ok(100_000_000));
+	}
+
+	#[test]
+	fn parse_exponents() {
+		assert_eq!(amount("1e-8"), Ok(1));
+		assert_eq!(amount("1.5e-7"), Ok(15));
+		assert_eq!(amount("1E3"), Ok(100_000_000_000));
+		assert!(amount("1e-9").is_err());
+	}
+
+	#[test]
+	fn parse_invalid_amounts() {
+		assert!(amount("0.123456789").is_err());
+		assert!(amount("-1").is_err());
+		assert!(amount("\"\"").is_err());
+		assert!(amount("\"1.2.3\"").is_err());
+		assert!(amount("true").is_err());
+		assert!(amount("1e20").is_err());
+	}
+}
@@ -1,3 +1,6 @@
 //! Backend modules for the reserve binary.
 pub mod bitcoind;
+pub mod cache;
+pub mod chain;
+pub mod elementsd;
 pub mod trezor;
@@ -5,18 +5,20 @@ use rbitcoin::blockdata::script::Script;
 use rbitcoin::blockdata::transaction::Transaction;
 use rbitcoin::consensus::encode::deserialize as bitcoin_deserialize;
 use rbitcoin::network::constants::Network as BitcoinNetwork;
+use rbitcoin::util::bip32::{ChildNumber, ExtendedPubKey};
 use rbitcoin::util::psbt;
 use rpassword;
 use trezor::{self, SignTxProgress, Trezor, TrezorMessage, TrezorResponse};
 
 use bitcoin;
 use context::Ctx;
+use hd;
 
 pub fn args<'a>() -> Vec<clap::Arg<'a, 'a>> {
 	vec![
 		clap::Arg::with_name("trezor")
 			.long("trezor")
-			.help("use a Trezor hardware wallet to sign")
+			.help("use a Trezor hardware wallet")
 			.takes_value(false),
 	]
 }
@@ -114,6 +116,57 @@ impl Backend {
 		Some(Backend(trezor))
 	}
 
+	/// Ask Trezor for the extended public key at the given path.
+	pub fn get_xpub(
+		&mut self,
+		path: Vec<ChildNumber>,
+		script_type: hd::KeyScriptType,
+		network: BitcoinNetwork,
+	) -> ExtendedPubKey {
+		let input_type = match script_type {
+			hd::KeyScriptType::P2pkh => trezor::protos::InputScriptType::SPENDADDRESS,
+			hd::KeyScriptType::P2shP2wpkh => trezor::protos::InputScriptType::SPENDP2SHWITNESS,
+			hd::KeyScriptType::P2wpkh => trezor::protos::InputScriptType::SPENDWITNESS,
+		};
+		let resp = self
+			.0
+			.get_public_key(path, input_type, network, false)
+			.expect("Trezor error getting public key");
+		handle_interaction(resp)
+	}
+
+	/// Ask Trezor for the xpub of the given BIP44-style account, with its key origin.
+	/// The purpose follows the script type: BIP44 for P2PKH, BIP49 for P2SH-P2WPKH and BIP84
+	/// for P2WPKH.
+	pub fn account_xpub(
+		&mut self,
+		script_type: hd::KeyScriptType,
+		network: BitcoinNetwork,
+		account: u32,
+	) -> hd::OriginXpub {
+		let purpose = match script_type {
+			hd::KeyScriptType::P2pkh => 44,
+			hd::KeyScriptType::P2shP2wpkh => 49,
+			hd::KeyScriptType::P2wpkh => 84,
+		};
+		let coin_type = match network {
+			BitcoinNetwork::Bitcoin => 0,
+			_ => 1,
+		};
+		let path = hd::parse_path(&format!("{}'/{}'/{}'", purpose, coin_type, account)).unwrap();
+
+		// The parent of the purpose key is the master key, which Trezor doesn't export.
+		let purpose_xpub = self.get_xpub(path[..1].to_vec(), script_type, network);
+		let xpub = self.get_xpub(path.clone(), script_type, network);
+		hd::OriginXpub {
+			origin: hd::KeyOrigin {
+				fingerprint: purpose_xpub.parent_fingerprint,
+				path: path,
+			},
+			xpub: xpub,
+		}
+	}
+
 	/// Ask Trezor to sign the given tx.
 	pub fn sign_tx(
 		&mut self,
@@ -1,9 +1,11 @@
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt;
+use std::str::FromStr;
 
 use bitcoin_amount::Amount;
 use bitcoinconsensus;
 use rbitcoin::blockdata::opcodes;
-use rbitcoin::blockdata::script::Builder;
+use rbitcoin::blockdata::script::{Builder, Instruction, Script};
 use rbitcoin::blockdata::transaction::{OutPoint, Transaction, TxIn, TxOut};
 use rbitcoin::consensus::encode::{deserialize, serialize};
 use rbitcoin::network::constants::Network as BitcoinNetwork;
@@ -11,6 +13,7 @@ use rbitcoin::util::address;
 use rbitcoin::util::hash::{Hash160, Sha256dHash};
 use rbitcoin::util::psbt;
 
+use challenge::Challenge;
 use common::*;
 use protos;
 use utils;
@@ -27,6 +30,63 @@ pub fn network(network: protos::Network) -> BitcoinNetwork {
 	}
 }
 
+/// The standard types of output scripts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ScriptType {
+	P2pk,
+	P2pkh,
+	P2sh,
+	P2wpkh,
+	P2wsh,
+	Unknown,
+}
+
+impl ScriptType {
+	pub fn from_script(script: &Script) -> ScriptType {
+		if script.is_p2pk() {
+			ScriptType::P2pk
+		} else if script.is_p2pkh() {
+			ScriptType::P2pkh
+		} else if script.is_p2sh() {
+			ScriptType::P2sh
+		} else if script.is_v0_p2wpkh() {
+			ScriptType::P2wpkh
+		} else if script.is_v0_p2wsh() {
+			ScriptType::P2wsh
+		} else {
+			ScriptType::Unknown
+		}
+	}
+}
+
+impl FromStr for ScriptType {
+	type Err = String;
+
+	fn from_str(s: &str) -> Result<ScriptType, String> {
+		match s.to_lowercase().as_str() {
+			"p2pk" => Ok(ScriptType::P2pk),
+			"p2pkh" => Ok(ScriptType::P2pkh),
+			"p2sh" => Ok(ScriptType::P2sh),
+			"p2wpkh" => Ok(ScriptType::P2wpkh),
+			"p2wsh" => Ok(ScriptType::P2wsh),
+			_ => Err(format!("unknown script type: {}", s)),
+		}
+	}
+}
+
+impl fmt::Display for ScriptType {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.write_str(match *self {
+			ScriptType::P2pk => "P2PK",
+			ScriptType::P2pkh => "P2PKH",
+			ScriptType::P2sh => "P2SH",
+			ScriptType::P2wpkh => "P2WPKH",
+			ScriptType::P2wsh => "P2WSH",
+			ScriptType::Unknown => "unknown",
+		})
+	}
+}
+
 /// An internal type used to represent a transaction output with extra metadata.
 #[derive(Debug)]
 pub struct UTXO {
@@ -37,6 +97,7 @@ pub struct UTXO {
 	// meta-information: This information is not critical for proof verification.
 	pub block_number: u32,
 	pub block_hash: Option<Sha256dHash>,
+	pub wallet: Option<String>,
 }
 
 impl UTXO {
@@ -61,6 +122,110 @@ impl UTXO {
 		let ref script = self.txout().script_pubkey;
 		script.is_v0_p2wsh() || script.is_v0_p2wpkh()
 	}
+
+	/// Returns whether the transaction output is known.
+	pub fn has_txout(&self) -> bool {
+		self.psbt_input.witness_utxo.is_some() || self.psbt_input.non_witness_utxo.is_some()
+	}
+
+	/// The type of the output script and, for P2SH outputs with a known redeem script, the
+	/// type of the redeem script.
+	pub fn script_type(&self) -> (ScriptType, Option<ScriptType>) {
+		let tp = ScriptType::from_script(&self.txout().script_pubkey);
+		let redeem_tp = match (tp, &self.psbt_input.redeem_script) {
+			(ScriptType::P2sh, &Some(ref redeem)) => Some(ScriptType::from_script(redeem)),
+			_ => None,
+		};
+		(tp, redeem_tp)
+	}
+
+	/// Check that the redeem and witness scripts match the output script.
+	pub fn check_scripts(&self) -> Result<(), String> {
+		let spk = &self.txout().script_pubkey;
+		let mut program = spk;
+		if let Some(ref redeem) = self.psbt_input.redeem_script {
+			if !spk.is_p2sh() {
+				return Err("a redeem script is given for a non-P2SH output".into());
+			}
+			if spk.as_bytes()[2..22] != Hash160::from_data(redeem.as_bytes())[..] {
+				return Err("the redeem script doesn't match the output script".into());
+			}
+			program = redeem;
+		}
+		if let Some(ref witness_script) = self.psbt_input.witness_script {
+			if !program.is_v0_p2wsh() {
+				return Err("a witness script is given for a non-P2WSH output".into());
+			}
+			if program.as_bytes()[2..34] != utils::sha256(witness_script.as_bytes())[..] {
+				return Err("the witness script doesn't match the output script".into());
+			}
+		}
+		Ok(())
+	}
+
+	/// Whether the given public key can be used to sign for this UTXO: either the output script
+	/// is a standard script for the key, or the key is used in the redeem or witness script.
+	pub fn is_signable_by(&self, pubkey: &[u8]) -> bool {
+		if pubkey_scripts(pubkey).contains(&self.txout().script_pubkey) {
+			return true;
+		}
+		let has_key = |script: &Script| {
+			script.iter(false).any(|i| match i {
+				Instruction::PushBytes(data) => data == pubkey,
+				_ => false,
+			})
+		};
+		self.psbt_input.redeem_script.as_ref().map(&has_key).unwrap_or(false)
+			|| self.psbt_input.witness_script.as_ref().map(&has_key).unwrap_or(false)
+	}
+
+	/// The data that still has to be provided to be able to sign for this UTXO.
+	pub fn missing_signing_data(&self) -> Vec<&'static str> {
+		let mut missing = Vec::new();
+		let (tp, redeem_tp) = self.script_type();
+		if tp == ScriptType::P2sh && redeem_tp.is_none() {
+			missing.push("the redeem script (--redeem-script)");
+		}
+		let is_p2wsh = tp == ScriptType::P2wsh || redeem_tp == Some(ScriptType::P2wsh);
+		if is_p2wsh && self.psbt_input.witness_script.is_none() {
+			missing.push("the witness script (--witness-script)");
+		}
+		if self.psbt_input.hd_keypaths.is_empty() {
+			missing.push("the xpub and HD keypath (--xpub, --hd-keypath), for hardware wallets");
+		}
+		missing
+	}
+
+	/// Estimate the weight of the signed input spending this UTXO.
+	pub fn estimated_input_weight(&self) -> usize {
+		// The outpoint, sequence and script length.
+		let base = 4 * 41;
+		let nb_keys = |script: &Script| {
+			script.iter(false).filter(|i| match *i {
+				Instruction::PushBytes(data) => data.len() == 33 || data.len() == 65,
+				_ => false,
+			}).count()
+		};
+		match self.script_type() {
+			(ScriptType::P2pk, _) => base + 4 * SIG_WEIGHT,
+			(ScriptType::P2pkh, _) => base + 4 * (SIG_WEIGHT + 34),
+			(ScriptType::P2wpkh, _) => base + 1 + SIG_WEIGHT + 34,
+			(ScriptType::P2sh, Some(ScriptType::P2wpkh)) => base + 4 * 23 + 1 + SIG_WEIGHT + 34,
+			(tp, redeem_tp) => {
+				let witness_script = self.psbt_input.witness_script.as_ref();
+				let redeem_script = self.psbt_input.redeem_script.as_ref();
+				if let Some(ws) = witness_script {
+					let wrapped = if redeem_tp.is_some() { 4 * 35 } else { 0 };
+					base + wrapped + 2 + ws.len() + nb_keys(ws) * SIG_WEIGHT
+				} else if let (ScriptType::P2sh, Some(rs)) = (tp, redeem_script) {
+					base + 4 * (3 + rs.len() + nb_keys(rs) * SIG_WEIGHT)
+				} else {
+					// Unknown scripts: assume the maximum standard script sig size.
+					base + 4 * 1650
+				}
+			}
+		}
+	}
 }
 
 impl From<protos::UTXO> for UTXO {
@@ -78,6 +243,11 @@ impl From<protos::UTXO> for UTXO {
 			} else {
 				None
 			},
+			wallet: if o.get_wallet().is_empty() {
+				None
+			} else {
+				Some(o.get_wallet().to_owned())
+			},
 		}
 	}
 }
@@ -92,18 +262,22 @@ impl Into<protos::UTXO> for UTXO {
 		if let Some(hash) = self.block_hash {
 			p.set_block_hash(hash.as_bytes()[..].into());
 		}
+		if let Some(wallet) = self.wallet {
+			p.set_wallet(wallet);
+		}
 		p
 	}
 }
 
-/// Generate the challenge input based on the challenge string.
-/// The input is created by using the SHA-256 hash of the challenge as the prevout hash.
-pub fn challenge_txin(challenge: &str) -> TxIn {
-	let challenge_hash = utils::sha256(challenge.as_bytes());
+/// Generate the challenge input for the given chunk based on the challenge.
+/// The input is created by using the hash of the challenge as the prevout hash and the index of
+/// the chunk as the prevout index.
+pub fn challenge_txin(challenge: &Challenge, chunk_idx: u32) -> TxIn {
+	let challenge_hash = challenge.hash();
 	TxIn {
 		previous_output: OutPoint {
 			txid: challenge_hash[..].into(),
-			vout: 0,
+			vout: chunk_idx,
 		},
 		sequence: 0xFFFFFFFF,
 		script_sig: Builder::new().into_script(),
@@ -111,26 +285,243 @@ pub fn challenge_txin(challenge: &str) -> TxIn {
 	}
 }
 
+/// The P2PKH output script for the given public key.
+pub fn p2pkh_script(pubkey: &[u8]) -> Script {
+	Builder::new()
+		.push_opcode(opcodes::All::OP_DUP)
+		.push_opcode(opcodes::All::OP_HASH160)
+		.push_slice(&Hash160::from_data(pubkey)[..])
+		.push_opcode(opcodes::All::OP_EQUALVERIFY)
+		.push_opcode(opcodes::All::OP_CHECKSIG)
+		.into_script()
+}
+
+/// The P2WPKH output script for the given public key.
+pub fn p2wpkh_script(pubkey: &[u8]) -> Script {
+	Builder::new().push_int(0).push_slice(&Hash160::from_data(pubkey)[..]).into_script()
+}
+
+/// The P2SH output script for the given redeem script.
+pub fn p2sh_script(redeem_script: &Script) -> Script {
+	Builder::new()
+		.push_opcode(opcodes::All::OP_HASH160)
+		.push_slice(&Hash160::from_data(&redeem_script[..])[..])
+		.push_opcode(opcodes::All::OP_EQUAL)
+		.into_script()
+}
+
+/// The standard output scripts that are spendable by the given public key: P2PK, P2PKH and
+/// for compressed keys also P2WPKH and P2SH-P2WPKH.
+pub fn pubkey_scripts(pubkey: &[u8]) -> Vec<Script> {
+	let mut scripts = vec![
+		Builder::new().push_slice(pubkey).push_opcode(opcodes::All::OP_CHECKSIG).into_script(),
+		p2pkh_script(pubkey),
+	];
+	if pubkey.len() == 33 {
+		let p2wpkh = p2wpkh_script(pubkey);
+		scripts.push(p2sh_script(&p2wpkh));
+		scripts.push(p2wpkh);
+	}
+	scripts
+}
+
+/// The default maximum weight of a proof transaction, the standardness limit.
+pub const DEFAULT_MAX_TX_WEIGHT: usize = 400_000;
+
+/// The estimated weight of a proof transaction without the UTXO inputs: the version, the lock
+/// time, the input and output counts, the segwit marker, the challenge input and the output.
+const TX_OVERHEAD_WEIGHT: usize = 4 * (4 + 4 + 3 + 3 + 41 + 34) + 2;
+
+/// The estimated weight of a signature with its push opcode.
+const SIG_WEIGHT: usize = 73;
+
+/// The limits for the proof transactions of a proof.  Proofs with more UTXOs are split into
+/// multiple chunks.
+#[derive(Debug, Clone, Copy)]
+pub struct ChunkLimits {
+	pub max_inputs: Option<usize>,
+	pub max_weight: usize,
+}
+
+impl Default for ChunkLimits {
+	fn default() -> ChunkLimits {
+		ChunkLimits {
+			max_inputs: None,
+			max_weight: DEFAULT_MAX_TX_WEIGHT,
+		}
+	}
+}
+
+/// A proof transaction of a proof.  Large proofs are split into multiple chunks that each have
+/// their own proof transaction.  The challenge input of a chunk spends the output of the
+/// challenge "transaction" with the index of the chunk.  The indices of the chunks of a proof
+/// are distinct, but not necessarily consecutive.
+#[derive(Debug, Default)]
+pub struct Chunk {
+	pub proof_tx: Option<Transaction>,
+	pub psbt: Option<psbt::PartiallySignedTransaction>,
+}
+
+impl Chunk {
+	/// The unsigned proof transaction of the chunk.
+	pub fn unsigned_tx(&self) -> &Transaction {
+		&self.psbt.as_ref().expect("chunk without PSBT").global.unsigned_tx
+	}
+
+	/// The index of the chunk, which is the prevout index of its challenge input.
+	pub fn index(&self) -> u32 {
+		let tx = match self.proof_tx {
+			Some(ref tx) => tx,
+			None => self.unsigned_tx(),
+		};
+		tx.input[0].previous_output.vout
+	}
+
+	/// The number of signatures collected for this chunk.
+	pub fn nb_signatures(&self) -> usize {
+		match (&self.proof_tx, &self.psbt) {
+			(&Some(ref tx), _) => tx.input.len() - 1,
+			(&None, &Some(ref psbt)) => psbt.inputs.iter().map(|i| i.partial_sigs.len()).sum(),
+			(&None, &None) => 0,
+		}
+	}
+}
+
+impl From<protos::ProofChunk> for Chunk {
+	fn from(c: protos::ProofChunk) -> Self {
+		Chunk {
+			proof_tx: if c.proof_tx.len() > 0 {
+				Some(deserialize(&c.proof_tx).expect("corrupt proof tx"))
+			} else {
+				None
+			},
+			psbt: if c.psbt.len() > 0 {
+				Some(deserialize(&c.psbt).expect("corrupt PSBT in proof"))
+			} else {
+				None
+			},
+		}
+	}
+}
+
+impl Into<protos::ProofChunk> for Chunk {
+	fn into(self) -> protos::ProofChunk {
+		let mut c = protos::ProofChunk::new();
+		if let Some(proof_tx) = self.proof_tx {
+			c.set_proof_tx(serialize(&proof_tx));
+		}
+		if let Some(psbt) = self.psbt {
+			c.set_psbt(serialize(&psbt));
+		}
+		c
+	}
+}
+
+/// Split the UTXOs into groups that fit in a proof transaction within the limits.
+fn split_utxos<'a>(utxos: Vec<&'a UTXO>, limits: &ChunkLimits) -> Vec<Vec<&'a UTXO>> {
+	let mut groups: Vec<Vec<&UTXO>> = vec![vec![]];
+	let mut weight = TX_OVERHEAD_WEIGHT;
+	for utxo in utxos.into_iter() {
+		let input_weight = utxo.estimated_input_weight();
+		let nb_inputs = groups.last().unwrap().len();
+		let full = limits.max_inputs.map(|max| nb_inputs >= max).unwrap_or(false)
+			|| weight + input_weight > limits.max_weight;
+		if full && nb_inputs > 0 {
+			groups.push(vec![]);
+			weight = TX_OVERHEAD_WEIGHT;
+		}
+		groups.last_mut().unwrap().push(utxo);
+		weight += input_weight;
+	}
+	groups
+}
+
+/// Construct the unsigned proof transaction of the chunk with the given index.
+fn build_chunk(challenge: &Challenge, chunk_idx: u32, utxos: &[&UTXO]) -> Chunk {
+	let mut tx_inputs = Vec::new();
+	let mut psbt_inputs = Vec::new();
+
+	// Add the challenge txin.
+	let challenge_txin = challenge_txin(challenge, chunk_idx);
+	tx_inputs.push(challenge_txin);
+	psbt_inputs.push(psbt::Input {
+		witness_utxo: Some(TxOut {
+			value: 0,
+			script_pubkey: Builder::new().push_opcode(opcodes::OP_TRUE).into_script(),
+		}),
+		witness_script: Some(Builder::new().into_script()),
+		final_script_sig: Some(Builder::new().into_script()),
+		..Default::default()
+	});
+
+	// Then add all proof UTXOs of the chunk as inputs.
+	let mut total_amount = 0;
+	for utxo in utxos.iter() {
+		tx_inputs.push(TxIn {
+			previous_output: utxo.point,
+			sequence: 0xFFFFFFFF,
+			script_sig: Builder::new().into_script(),
+			witness: Vec::new(),
+		});
+		psbt_inputs.push(utxo.psbt_input.clone());
+		total_amount += utxo.value().into_inner();
+	}
+
+	// Construct an unspentable output script.
+	let out_script = address::Address {
+		payload: address::Payload::PubkeyHash(Hash160::from_data(&[0])),
+		network: BitcoinNetwork::Testnet,
+	}.script_pubkey();
+
+	// Construct the tx and psbt tx.
+	let tx = Transaction {
+		version: 1,
+		lock_time: 0xffffffff, // Max time in the future. 2106-02-07 06:28:15
+		input: tx_inputs,
+		output: vec![TxOut {
+			value: total_amount as u64,
+			script_pubkey: out_script,
+		}],
+	};
+	let mut psbt = psbt::PartiallySignedTransaction::from_unsigned_tx(tx)
+		.expect("error constructing PSBT from unsigned tx");
+	psbt.inputs = psbt_inputs;
+	// We can leave the one psbt output empty.
+
+	Chunk {
+		proof_tx: None,
+		psbt: Some(psbt),
+	}
+}
+
 #[derive(Debug)]
 pub struct Proof {
 	pub id: String,
 	pub status: Proof_Status,
 
-	pub proof_tx: Option<Transaction>,
 	pub utxos: Vec<UTXO>,
-	pub psbt: Option<psbt::PartiallySignedTransaction>,
+	/// The proof transactions, empty while gathering UTXOs.
+	pub chunks: Vec<Chunk>,
+	/// The limits the chunks are built with.
+	pub chunk_limits: ChunkLimits,
 }
 
 impl From<protos::Proof> for Proof {
-	fn from(p: protos::Proof) -> Self {
+	fn from(mut p: protos::Proof) -> Self {
+		// Proofs with a single chunk use the proof tx and PSBT fields of the proof.
+		let chunks = if p.chunks.len() > 0 {
+			p.take_chunks().into_vec().into_iter().map(|c| c.into()).collect()
+		} else if p.proof_tx.len() > 0 || p.psbt.len() > 0 {
+			let mut chunk = protos::ProofChunk::new();
+			chunk.set_proof_tx(p.take_proof_tx());
+			chunk.set_psbt(p.take_psbt());
+			vec![chunk.into()]
+		} else {
+			vec![]
+		};
 		Proof {
 			id: p.id.into(),
 			status: p.status,
-			proof_tx: if p.proof_tx.len() > 0 {
-				Some(deserialize(&p.proof_tx).expect("corrupt proof tx"))
-			} else {
-				None
-			},
 			utxos: p
 				.utxos
 				.into_vec()
@@ -139,10 +530,18 @@ impl From<protos::Proof> for Proof {
 					let i: UTXO = u.into();
 					i
 				}).collect(),
-			psbt: if p.psbt.len() > 0 {
-				Some(deserialize(&p.psbt).expect("corrupt PSBT in proof"))
-			} else {
-				None
+			chunks: chunks,
+			chunk_limits: ChunkLimits {
+				max_inputs: if p.chunk_max_inputs != 0 {
+					Some(p.chunk_max_inputs as usize)
+				} else {
+					None
+				},
+				max_weight: if p.chunk_max_weight != 0 {
+					p.chunk_max_weight as usize
+				} else {
+					DEFAULT_MAX_TX_WEIGHT
+				},
 			},
 		}
 	}
@@ -153,9 +552,6 @@ impl Into<protos::Proof> for Proof {
 		let mut p = protos::Proof::new();
 		p.set_id(self.id.into());
 		p.set_status(self.status);
-		if let Some(proof_tx) = self.proof_tx {
-			p.set_proof_tx(serialize(&proof_tx));
-		}
 		p.set_utxos(
 			self.utxos
 				.into_iter()
@@ -164,8 +560,20 @@ impl Into<protos::Proof> for Proof {
 					i
 				}).collect(),
 		);
-		if let Some(psbt) = self.psbt {
-			p.set_psbt(serialize(&psbt));
+		let mut chunks: Vec<protos::ProofChunk> =
+			self.chunks.into_iter().map(|c| c.into()).collect();
+		if chunks.len() == 1 {
+			let mut chunk = chunks.pop().unwrap();
+			p.set_proof_tx(chunk.take_proof_tx());
+			p.set_psbt(chunk.take_psbt());
+		} else {
+			p.set_chunks(chunks.into_iter().collect());
+		}
+		if let Some(max) = self.chunk_limits.max_inputs {
+			p.set_chunk_max_inputs(max as u32);
+		}
+		if self.chunk_limits.max_weight != DEFAULT_MAX_TX_WEIGHT {
+			p.set_chunk_max_weight(self.chunk_limits.max_weight as u32);
 		}
 		p
 	}
@@ -176,82 +584,169 @@ impl Proof {
 		Proof {
 			id: id,
 			status: status,
-			proof_tx: None,
 			utxos: vec![],
-			psbt: None,
-		}
-	}
-
-	/// Advance the proof to the SIGNING state by constructing a PSBT transaction to be signed.
-	pub fn start_signing(&mut self, challenge: &str) {
-		let mut tx_inputs = Vec::new();
-		let mut psbt_inputs = Vec::new();
-
-		// Add the challenge txin.
-		let challenge_txin = challenge_txin(challenge);
-		tx_inputs.push(challenge_txin);
-		psbt_inputs.push(psbt::Input {
-			witness_utxo: Some(TxOut {
-				value: 0,
-				script_pubkey: Builder::new().push_opcode(opcodes::OP_TRUE).into_script(),
-			}),
-			witness_script: Some(Builder::new().into_script()),
-			final_script_sig: Some(Builder::new().into_script()),
-			..Default::default()
-		});
+			chunks: vec![],
+			chunk_limits: ChunkLimits::default(),
+		}
+	}
 
-		// Then add all proof UTXOs as inputs.
-		let mut total_amount = 0;
-		for utxo in self.utxos.iter() {
-			tx_inputs.push(TxIn {
-				previous_output: utxo.point,
-				sequence: 0xFFFFFFFF,
-				script_sig: Builder::new().into_script(),
-				witness: Vec::new(),
-			});
-			psbt_inputs.push(utxo.psbt_input.clone());
-			total_amount += utxo.value().into_inner();
-		}
-
-		// Construct an unspentable output script.
-		let out_script = address::Address {
-			payload: address::Payload::PubkeyHash(Hash160::from_data(&[0])),
-			network: BitcoinNetwork::Testnet,
-		}.script_pubkey();
-
-		// Construct the tx and psbt tx.
-		let tx = Transaction {
-			version: 1,
-			lock_time: 0xffffffff, // Max time in the future. 2106-02-07 06:28:15
-			input: tx_inputs,
-			output: vec![TxOut {
-				value: total_amount as u64,
-				script_pubkey: out_script,
-			}],
-		};
-		let mut psbt = psbt::PartiallySignedTransaction::from_unsigned_tx(tx)
-			.expect("error constructing PSBT from unsigned tx");
-		psbt.inputs = psbt_inputs;
-		// We can leave the one psbt output empty.
+	/// Create a final proof from the signed proof transactions of its chunks.
+	pub fn from_proof_txs(id: String, txs: Vec<Transaction>) -> Proof {
+		let mut proof = Proof::new(id, Proof_Status::FINAL);
+		proof.chunks = txs
+			.into_iter()
+			.map(|tx| Chunk {
+				proof_tx: Some(tx),
+				psbt: None,
+			}).collect();
+		proof
+	}
 
-		self.psbt = Some(psbt);
+	/// Advance the proof to the SIGNING state by constructing the PSBT transactions to be signed.
+	/// If the UTXOs don't fit in a single transaction within the limits, the proof is split into
+	/// multiple chunks.
+	pub fn start_signing(&mut self, challenge: &Challenge, limits: &ChunkLimits) {
+		self.chunks = split_utxos(self.utxos.iter().collect(), limits)
+			.iter()
+			.enumerate()
+			.map(|(idx, utxos)| build_chunk(challenge, idx as u32, utxos))
+			.collect();
+		self.chunk_limits = *limits;
 		self.status = Proof_Status::SIGNING;
 	}
 
+	/// Discard the proof transactions and return to gathering UTXOs.
+	/// Returns the number of signatures that were discarded.
+	pub fn reopen(&mut self) -> usize {
+		let nb_sigs = self.chunks.iter().map(|c| c.nb_signatures()).sum();
+		self.chunks.clear();
+		self.status = Proof_Status::GATHERING_UTXOS;
+		nb_sigs
+	}
+
+	/// Rebuild the proof transactions after the UTXOs of a proof in the SIGNING state changed.
+	/// Existing chunks keep their index and their remaining UTXOs, and new UTXOs are put in the
+	/// chunks that lost all their UTXOs or in new chunks, so that the signatures of unchanged
+	/// chunks stay valid.  New chunks are built with the limits the proof was split with.
+	/// Returns the number of signatures that were invalidated.
+	pub fn rebuild_signing(&mut self, challenge: &Challenge) -> usize {
+		let mut old_chunks: BTreeMap<u32, Chunk> =
+			self.chunks.drain(..).map(|c| (c.index(), c)).collect();
+
+		let mut chunks = Vec::new();
+		{
+			let by_point: HashMap<OutPoint, &UTXO> =
+				self.utxos.iter().map(|u| (u.point, u)).collect();
+			let mut placed = HashSet::new();
+			let mut groups = BTreeMap::new();
+			let mut free = Vec::new();
+			for (idx, chunk) in old_chunks.iter() {
+				let inputs = chunk.unsigned_tx().input.iter().skip(1);
+				let group: Vec<&UTXO> =
+					inputs.filter_map(|i| by_point.get(&i.previous_output).cloned()).collect();
+				if group.is_empty() {
+					free.push(*idx);
+				} else {
+					placed.extend(group.iter().map(|u| u.point));
+					groups.insert(*idx, group);
+				}
+			}
+
+			let new_utxos = self.utxos.iter().filter(|u| !placed.contains(&u.point)).collect();
+			let mut free = free.into_iter();
+			let mut next_idx = old_chunks.keys().next_back().map(|i| i + 1).unwrap_or(0);
+			for group in split_utxos(new_utxos, &self.chunk_limits).into_iter() {
+				if group.is_empty() {
+					continue;
+				}
+				let idx = match free.next() {
+					Some(idx) => idx,
+					None => {
+						next_idx += 1;
+						next_idx - 1
+					}
+				};
+				groups.insert(idx, group);
+			}
+
+			for (idx, group) in groups.iter() {
+				let chunk = build_chunk(challenge, *idx, group);
+				let unchanged = match old_chunks.get(idx) {
+					Some(old) => old.unsigned_tx() == chunk.unsigned_tx(),
+					None => false,
+				};
+				if unchanged {
+					chunks.push(old_chunks.remove(idx).unwrap());
+				} else {
+					chunks.push(chunk);
+				}
+			}
+		}
+		self.chunks = chunks;
+
+		self.status = if self.chunks.is_empty() {
+			Proof_Status::GATHERING_UTXOS
+		} else if self.nb_signed_chunks() == self.chunks.len() {
+			Proof_Status::FINAL
+		} else {
+			Proof_Status::SIGNING
+		};
+		old_chunks.values().map(|c| c.nb_signatures()).sum()
+	}
+
+	/// The number of chunks that are signed.
+	pub fn nb_signed_chunks(&self) -> usize {
+		self.chunks.iter().filter(|c| c.proof_tx.is_some()).count()
+	}
+
+	/// The proof transactions of all chunks.
+	pub fn proof_txs(&self) -> Vec<&Transaction> {
+		self.chunks.iter().map(|c| c.proof_tx.as_ref().expect("proof in wrong state")).collect()
+	}
+
+	/// The total amount of the proof transactions.
+	pub fn amount(&self) -> u64 {
+		self.proof_txs().iter().flat_map(|tx| tx.output.iter()).fold(0, |a, o| a + o.value)
+	}
+
 	/// Return all the outpoins this proof is spending.
 	pub fn spending_utxos(&self) -> HashSet<OutPoint> {
 		let mut set = HashSet::new();
-		// Skip the challenge UTXO.
-		for input in self.proof_tx.as_ref().unwrap().input.iter().skip(1) {
-			if !set.insert(input.previous_output) {
-				panic!("Proof '{}' is spending UTXO {} twice!", self.id, input.previous_output);
+		for tx in self.proof_txs().into_iter() {
+			// Skip the challenge UTXO.
+			for input in tx.input.iter().skip(1) {
+				if !set.insert(input.previous_output) {
+					panic!("Proof '{}' is spending UTXO {} twice!", self.id, input.previous_output);
+				}
 			}
 		}
 		set
 	}
 
-	pub fn verify(&self, challenge: &str, prevouts: Vec<TxOut>) -> Amount {
-		let tx = self.proof_tx.as_ref().expect("proof in wrong state");
+	/// Verify the proof transactions of all chunks, given the prevouts of their inputs.
+	pub fn verify(&self, challenge: &Challenge, prevouts: Vec<Vec<TxOut>>) -> Amount {
+		let txs = self.proof_txs();
+		if txs.is_empty() {
+			panic!("Proof '{}' has no proof transactions!", self.id);
+		}
+		if prevouts.len() != txs.len() {
+			panic!("Wrong amount of prevouts provided");
+		}
+
+		let mut total_amount = Amount::from_sat(0);
+		let mut indices = HashSet::new();
+		for (tx, prevouts) in txs.into_iter().zip(prevouts.into_iter()) {
+			total_amount = total_amount + self.verify_tx(challenge, tx, prevouts);
+			let chunk_idx = tx.input[0].previous_output.vout;
+			if !indices.insert(chunk_idx) {
+				panic!("Proof '{}' has multiple chunks with index {}!", self.id, chunk_idx);
+			}
+		}
+		total_amount
+	}
+
+	/// Verify the proof transaction of a single chunk.
+	fn verify_tx(&self, challenge: &Challenge, tx: &Transaction, prevouts: Vec<TxOut>) -> Amount {
 		// Proof tx must have exactly 1 output and more than 1 inputs.
 		if tx.output.len() != 1 {
 			panic!(
@@ -271,8 +766,9 @@ impl Proof {
 			panic!("Wrong amount of prevouts provided");
 		}
 
-		// First check the challenge input.
-		let challenge_txin = challenge_txin(challenge);
+		// First check the challenge input, which spends the output with the index of the chunk.
+		let chunk_idx = tx.input[0].previous_output.vout;
+		let challenge_txin = challenge_txin(challenge, chunk_idx);
 		if tx.input[0].previous_output != challenge_txin.previous_output {
 			panic!("Challenge for proof '{}' is incorrect", self.id);
 		}
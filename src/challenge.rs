@@ -0,0 +1,362 @@
+//! Structured challenges.
+//!
+//! Next to the plain challenge message, a challenge can commit to a liabilities root, a recent
+//! block hash, an auditor nonce and the end of a claimed block range.  The challenge hash is
+//! then a tagged hash over a canonical serialization of the fields.  Challenges with only a
+//! message use the SHA-256 hash of the message, so that existing proofs remain valid.
+//!
+//! Challenges issued by an auditor also contain the auditor's identity, the date and the target
+//! block, and are signed by the auditor using Bitcoin's legacy message signing.
+
+use std::fmt;
+use std::str::FromStr;
+
+use hex;
+use k256::ecdsa::{RecoveryId, Signature, SigningKey, VerifyingKey};
+use k256::elliptic_curve::sec1::ToEncodedPoint;
+use rbitcoin::util::address::{Address, Payload};
+use rbitcoin::util::hash::{Hash160, Sha256dHash};
+
+use protos;
+use utils;
+
+/// The tag used for the challenge hash.
+const CHALLENGE_TAG: &'static [u8] = b"reserves/challenge/v1";
+
+// The field types in the canonical serialization.
+const FIELD_MESSAGE: u8 = 1;
+const FIELD_LIABILITIES_ROOT: u8 = 2;
+const FIELD_BLOCK_HASH: u8 = 3;
+const FIELD_NONCE: u8 = 4;
+const FIELD_AUDITOR: u8 = 5;
+const FIELD_DATE: u8 = 6;
+const FIELD_TARGET_BLOCK: u8 = 7;
+const FIELD_END_BLOCK: u8 = 8;
+
+/// The prefix used by Bitcoin's legacy message signing.
+const SIGNED_MESSAGE_PREFIX: &'static [u8] = b"Bitcoin Signed Message:\n";
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Challenge {
+	pub message: String,
+	pub liabilities_root: Option<[u8; 32]>,
+	/// The block hash in internal byte order.
+	pub block_hash: Option<[u8; 32]>,
+	pub nonce: Option<Vec<u8>>,
+	pub auditor: Option<String>,
+	pub date: Option<String>,
+	pub target_block: Option<u32>,
+	pub end_block: Option<u32>,
+
+	/// The auditor's signature in the 65-byte legacy message signing format.
+	pub auditor_signature: Option<Vec<u8>>,
+}
+
+fn hash_from_slice(b: &[u8]) -> [u8; 32] {
+	if b.len() != 32 {
+		panic!("corrupt challenge hash field");
+	}
+	let mut hash = [0; 32];
+	hash.copy_from_slice(b);
+	hash
+}
+
+impl Challenge {
+	/// Create a challenge with only a message.
+	pub fn new(message: String) -> Challenge {
+		Challenge {
+			message: message,
+			..Default::default()
+		}
+	}
+
+	/// Whether the challenge has any fields other than the message.
+	pub fn is_structured(&self) -> bool {
+		self.liabilities_root.is_some()
+			|| self.block_hash.is_some()
+			|| self.nonce.is_some()
+			|| self.auditor.is_some()
+			|| self.date.is_some()
+			|| self.target_block.is_some()
+			|| self.end_block.is_some()
+	}
+
+	/// The canonical serialization: all present fields ordered by type, each serialized as
+	/// the type byte, the length as a 4-byte little-endian integer and the value.
+	pub fn serialize(&self) -> Vec<u8> {
+		fn field(buf: &mut Vec<u8>, tp: u8, value: &[u8]) {
+			buf.push(tp);
+			buf.extend_from_slice(&(value.len() as u32).to_le_bytes());
+			buf.extend_from_slice(value);
+		}
+
+		let mut buf = Vec::new();
+		field(&mut buf, FIELD_MESSAGE, self.message.as_bytes());
+		if let Some(ref root) = self.liabilities_root {
+			field(&mut buf, FIELD_LIABILITIES_ROOT, root);
+		}
+		if let Some(ref hash) = self.block_hash {
+			field(&mut buf, FIELD_BLOCK_HASH, hash);
+		}
+		if let Some(ref nonce) = self.nonce {
+			field(&mut buf, FIELD_NONCE, nonce);
+		}
+		if let Some(ref auditor) = self.auditor {
+			field(&mut buf, FIELD_AUDITOR, auditor.as_bytes());
+		}
+		if let Some(ref date) = self.date {
+			field(&mut buf, FIELD_DATE, date.as_bytes());
+		}
+		if let Some(block) = self.target_block {
+			field(&mut buf, FIELD_TARGET_BLOCK, &block.to_le_bytes());
+		}
+		if let Some(block) = self.end_block {
+			field(&mut buf, FIELD_END_BLOCK, &block.to_le_bytes());
+		}
+		buf
+	}
+
+	/// The hash the proofs commit to.
+	pub fn hash(&self) -> [u8; 32] {
+		if !self.is_structured() {
+			return utils::sha256(self.message.as_bytes());
+		}
+
+		let tag = utils::sha256(CHALLENGE_TAG);
+		let mut data = Vec::new();
+		data.extend_from_slice(&tag);
+		data.extend_from_slice(&tag);
+		data.extend(self.serialize());
+		utils::sha256(&data)
+	}
+
+	/// The hash of the message the auditor signs, which is the hex challenge hash.
+	fn auditor_message_hash(&self) -> [u8; 32] {
+		let message = hex::encode(&self.hash());
+		let mut data = vec![SIGNED_MESSAGE_PREFIX.len() as u8];
+		data.extend_from_slice(SIGNED_MESSAGE_PREFIX);
+		data.push(message.len() as u8);
+		data.extend_from_slice(message.as_bytes());
+		let mut hash = [0; 32];
+		hash.copy_from_slice(&Sha256dHash::from_data(&data)[..]);
+		hash
+	}
+
+	/// Sign the challenge with the auditor's private key.
+	pub fn sign(&mut self, secret: &[u8; 32], compressed: bool) -> Result<(), String> {
+		let key = SigningKey::from_slice(secret).map_err(|_| "invalid private key")?;
+		let (sig, recid) = key
+			.sign_prehash_recoverable(&self.auditor_message_hash())
+			.map_err(|e| format!("signing failed: {}", e))?;
+
+		let mut signature = vec![27 + recid.to_byte() + if compressed { 4 } else { 0 }];
+		signature.extend_from_slice(&sig.to_bytes());
+		self.auditor_signature = Some(signature);
+		Ok(())
+	}
+
+	/// Recover the public key of the auditor from the signature.
+	pub fn auditor_key(&self) -> Result<Vec<u8>, String> {
+		let signature = self.auditor_signature.as_ref().ok_or("the challenge is not signed")?;
+		if signature.len() != 65 || signature[0] < 27 || signature[0] > 34 {
+			return Err("invalid auditor signature".into());
+		}
+		let compressed = signature[0] >= 31;
+		let recid = RecoveryId::from_byte((signature[0] - 27) & 3).unwrap();
+		let sig = Signature::from_slice(&signature[1..]).map_err(|_| "invalid auditor signature")?;
+		let key = VerifyingKey::recover_from_prehash(&self.auditor_message_hash(), &sig, recid)
+			.map_err(|_| "invalid auditor signature")?;
+		Ok(key.to_encoded_point(compressed).as_bytes().to_vec())
+	}
+
+	/// Check whether the challenge is signed by the given auditor key.
+	/// The key can be given as a hex public key or as a P2PKH address.
+	pub fn is_signed_by(&self, auditor_key: &str) -> Result<bool, String> {
+		let signer = self.auditor_key()?;
+		if let Ok(pubkey) = hex::decode(auditor_key) {
+			return Ok(pubkey == signer);
+		}
+		let address = Address::from_str(auditor_key)
+			.map_err(|_| "the auditor key is neither a public key nor an address")?;
+		match address.payload {
+			Payload::PubkeyHash(hash) => Ok(hash == Hash160::from_data(&signer)),
+			_ => Err("only P2PKH addresses can be used for message signing".into()),
+		}
+	}
+}
+
+impl fmt::Display for Challenge {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "{}", self.message)
+	}
+}
+
+impl From<protos::Challenge> for Challenge {
+	fn from(c: protos::Challenge) -> Self {
+		let opt = |b: &[u8]| if b.is_empty() { None } else { Some(b.to_vec()) };
+		let opt_str = |s: &str| if s.is_empty() { None } else { Some(s.to_owned()) };
+		Challenge {
+			message: c.get_message().to_owned(),
+			liabilities_root: opt(c.get_liabilities_root()).map(|b| hash_from_slice(&b)),
+			block_hash: opt(c.get_block_hash()).map(|b| hash_from_slice(&b)),
+			nonce: opt(c.get_nonce()),
+			auditor: opt_str(c.get_auditor()),
+			date: opt_str(c.get_date()),
+			target_block: if c.get_target_block() != 0 {
+				Some(c.get_target_block())
+			} else {
+				None
+			},
+			end_block: if c.get_end_block() != 0 {
+				Some(c.get_end_block())
+			} else {
+				None
+			},
+			auditor_signature: opt(c.get_auditor_signature()),
+		}
+	}
+}
+
+impl Into<protos::Challenge> for Challenge {
+	fn into(self) -> protos::Challenge {
+		let mut c = protos::Challenge::new();
+		c.set_message(self.message);
+		if let Some(root) = self.liabilities_root {
+			c.set_liabilities_root(root.to_vec());
+		}
+		if let Some(hash) = self.block_hash {
+			c.set_block_hash(hash.to_vec());
+		}
+		if let Some(nonce) = self.nonce {
+			c.set_nonce(nonce);
+		}
+		if let Some(auditor) = self.auditor {
+			c.set_auditor(auditor);
+		}
+		if let Some(date) = self.date {
+			c.set_date(date);
+		}
+		if let Some(block) = self.target_block {
+			c.set_target_block(block);
+		}
+		if let Some(block) = self.end_block {
+			c.set_end_block(block);
+		}
+		if let Some(signature) = self.auditor_signature {
+			c.set_auditor_signature(signature);
+		}
+		c
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	/// The public key and addresses of private key 1, which is the generator.
+	const PUBKEY: &'static str =
+		"0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798";
+	const ADDRESS: &'static str = "1BgGZ9tcN4rm9KBzDn7KprQz87SZ26SAMH";
+	const ADDRESS_UNCOMPRESSED: &'static str = "1EHNa6Q4Jz2uvNExL497mE43ikXhwF6kZm";
+
+	fn secret(n: u8) -> [u8; 32] {
+		let mut secret = [0; 32];
+		secret[31] = n;
+		secret
+	}
+
+	fn challenge() -> Challenge {
+		Challenge {
+			message: "Proof of Reserves".into(),
+			liabilities_root: Some([1; 32]),
+			block_hash: Some([2; 32]),
+			nonce: Some(vec![3; 16]),
+			auditor: Some("Auditor".into()),
+			date: Some("2019-01-01".into()),
+			target_block: Some(560000),
+			end_block: Some(560100),
+			auditor_signature: None,
+		}
+	}
+
+	#[test]
+	fn message_only_hash() {
+		let c = Challenge::new("Proof of Reserves".into());
+		assert!(!c.is_structured());
+		assert_eq!(c.hash(), utils::sha256(b"Proof of Reserves"));
+		assert_ne!(challenge().hash(), c.hash());
+	}
+
+	#[test]
+	fn sign_verify() {
+		let mut c = challenge();
+		assert!(c.auditor_key().is_err());
+		c.sign(&secret(1), true).unwrap();
+		assert_eq!(hex::encode(&c.auditor_key().unwrap()), PUBKEY);
+		assert_eq!(c.is_signed_by(PUBKEY), Ok(true));
+		assert_eq!(c.is_signed_by(ADDRESS), Ok(true));
+		assert_eq!(c.is_signed_by(ADDRESS_UNCOMPRESSED), Ok(false));
+
+		c.sign(&secret(1), false).unwrap();
+		assert_eq!(c.is_signed_by(ADDRESS_UNCOMPRESSED), Ok(true));
+		assert_eq!(c.is_signed_by(ADDRESS), Ok(false));
+	}
+
+	#[test]
+	fn proto_roundtrip() {
+		let mut c = challenge();
+		c.sign(&secret(1), true).unwrap();
+		let proto: protos::Challenge = c.clone().into();
+		let decoded: Challenge = proto.into();
+		assert_eq!(decoded, c);
+		assert_eq!(decoded.is_signed_by(PUBKEY), Ok(true));
+	}
+
+	#[test]
+	fn wrong_key() {
+		let mut c = challenge();
+		c.sign(&secret(2), true).unwrap();
+		assert_eq!(c.is_signed_by(PUBKEY), Ok(false));
+		assert_eq!(c.is_signed_by(ADDRESS), Ok(false));
+	}
+
+	#[test]
+	fn changed_fields() {
+		let mut signed = challenge();
+		signed.sign(&secret(1), true).unwrap();
+
+		let mut c = signed.clone();
+		c.liabilities_root = Some([4; 32]);
+		assert_ne!(c.is_signed_by(PUBKEY), Ok(true));
+
+		let mut c = signed.clone();
+		c.target_block = Some(560001);
+		assert_ne!(c.is_signed_by(PUBKEY), Ok(true));
+
+		let mut c = signed.clone();
+		c.end_block = None;
+		assert_ne!(c.is_signed_by(PUBKEY), Ok(true));
+
+		let mut c = signed.clone();
+		c.message.push('!');
+		assert_ne!(c.is_signed_by(PUBKEY), Ok(true));
+	}
+
+	#[test]
+	fn invalid_signature() {
+		let mut c = challenge();
+		c.sign(&secret(1), true).unwrap();
+		let signature = c.auditor_signature.clone().unwrap();
+
+		c.auditor_signature = Some(signature[..64].to_vec());
+		assert!(c.is_signed_by(PUBKEY).is_err());
+
+		let mut header = signature.clone();
+		header[0] = 35;
+		c.auditor_signature = Some(header);
+		assert!(c.is_signed_by(PUBKEY).is_err());
+
+		c.auditor_signature = Some(signature);
+		assert!(c.is_signed_by("3J98t1WpEZ73CNmQviecrnyiWrnqRhWNLy").is_err());
+	}
+}
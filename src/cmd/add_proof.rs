@@ -1,4 +1,7 @@
+use std::collections::BTreeMap;
+
 use clap;
+use elements;
 use hex;
 use rbitcoin::consensus::encode::deserialize;
 use rbitcoin::Transaction;
@@ -7,16 +10,18 @@ use bitcoin;
 use cmd::common;
 use common::*;
 use context;
+use liquid;
 
 /// Create the add-proof subcommand.
 pub fn subcommand<'a>() -> clap::App<'a, 'a> {
 	clap::SubCommand::with_name("add-proof")
-		.about("add a raw proof transaction")
+		.about("add raw proof transactions")
 		.arg(common::id_arg())
 		.arg(
 			clap::Arg::with_name("proof-tx")
-				.help("the hexadecimal proof tx")
+				.help("the hexadecimal proof txs, one for every chunk of the proof")
 				.takes_value(true)
+				.multiple(true)
 				.required(true),
 		)
 }
@@ -31,20 +36,58 @@ pub fn execute(ctx: &mut context::Ctx) {
 		panic!("A proof with this ID already exists.");
 	}
 
-	let hex_tx = ctx.command().value_of("proof-tx").expect("no proof tx provided");
-	let raw_tx = hex::decode(hex_tx).expect("proof tx not hex");
-	let tx: Transaction = deserialize(&raw_tx).expect("invalid transaction encoding");
+	let raw_txs: Vec<Vec<u8>> = ctx
+		.command()
+		.values_of("proof-tx")
+		.expect("no proof tx provided")
+		.map(|hex_tx| hex::decode(hex_tx).expect("proof tx not hex"))
+		.collect();
 
-	// Perform some validation of the tx.
-	if tx.input.len() < 2 {
-		panic!("Proof transaction has less than two inputs.");
-	}
-	if tx.input[0] != bitcoin::challenge_txin(&pf.challenge) {
-		panic!("Proof transaction does not commit to the correct challenge.");
-	}
+	let proof = match pf.network {
+		Network::BITCOIN_MAINNET | Network::BITCOIN_TESTNET => {
+			let mut txs = BTreeMap::new();
+			for raw_tx in raw_txs.iter() {
+				let tx: Transaction = deserialize(raw_tx).expect("invalid transaction encoding");
+
+				// Perform some validation of the tx.  The challenge input of every chunk uses
+				// the index of the chunk.
+				if tx.input.len() < 2 {
+					panic!("Proof transaction has less than two inputs.");
+				}
+				let chunk_idx = tx.input[0].previous_output.vout;
+				if tx.input[0] != bitcoin::challenge_txin(&pf.challenge, chunk_idx) {
+					panic!("Proof transaction does not commit to the correct challenge.");
+				}
+				if txs.insert(chunk_idx, tx).is_some() {
+					panic!("Multiple proof transactions for chunk {}.", chunk_idx);
+				}
+			}
+
+			let txs = txs.into_iter().map(|(_, tx)| tx).collect();
+			Proof::Bitcoin(bitcoin::Proof::from_proof_txs(proof_id.to_owned(), txs))
+		}
+		Network::LIQUID => {
+			if raw_txs.len() != 1 {
+				panic!("Liquid proofs have a single proof transaction.");
+			}
+			let raw_tx = &raw_txs[0];
+			let tx: elements::Transaction =
+				elements::encode::deserialize(raw_tx).expect("invalid transaction encoding");
+
+			// Perform some validation of the tx.
+			if tx.input.len() < 2 {
+				panic!("Proof transaction has less than two inputs.");
+			}
+			if tx.input[0].previous_output != liquid::challenge_txin(&pf.challenge).previous_output
+			{
+				panic!("Proof transaction does not commit to the correct challenge.");
+			}
 
-	let mut proof = bitcoin::Proof::new(proof_id.to_owned(), Proof_Status::FINAL);
-	proof.proof_tx = Some(tx);
+			let mut proof = liquid::Proof::new(proof_id.to_owned(), Proof_Status::FINAL);
+			proof.proof_tx = Some(tx);
+			Proof::Liquid(proof)
+		}
+	};
 
 	pf.proofs.insert(0, proof);
 	ctx.save_proof_file(pf);
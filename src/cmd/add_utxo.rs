@@ -1,22 +1,28 @@
-use std::collections::HashMap;
+use std::collections::BTreeSet;
+use std::io::Cursor;
 
 use clap;
 use hex;
-use rbitcoin::consensus::encode::deserialize;
-use rbitcoin::util::bip32;
+use rbitcoin::blockdata::script::Instruction;
+use rbitcoin::consensus::encode::{deserialize, Decodable};
+use rbitcoin::util::bip32::Fingerprint;
 use rbitcoin::util::psbt;
-use secp256k1;
+use secp256k1::PublicKey;
 
+use backend;
 use bitcoin;
 use cmd::common;
 use common::*;
 use context;
+use hd;
 
 /// Create the add-utxo subcommand.
 pub fn subcommand<'a>() -> clap::App<'a, 'a> {
 	clap::SubCommand::with_name("add-utxo")
-		.about("manually add a UTXO to a proof")
+		.about("add a UTXO to a proof, manually or by fetching its data from a backend")
 		.arg(common::id_arg())
+		// When bitcoind is given, the UTXO is validated against the chain.
+		.args(&backend::bitcoind::args())
 		.args(&vec![
 			// Necessary argument.
 			clap::Arg::with_name("outpoint")
@@ -32,10 +38,23 @@ pub fn subcommand<'a>() -> clap::App<'a, 'a> {
 				.long("previous-output")
 				.help("the encoded transaction output of the UTXO")
 				.takes_value(true),
+			clap::Arg::with_name("xpub")
+				.long("xpub")
+				.help("the xpub the UTXO key derives from, optionally as `[fingerprint/path]xpub`")
+				.takes_value(true)
+				.requires("hd-keypath"),
 			clap::Arg::with_name("hd-keypath")
 				.long("hd-keypath")
-				.help("the BIP32 HD wallet keypath this UTXO has to be signed with")
-				.takes_value(true),
+				.help(
+					"the BIP32 keypath of the key to sign this UTXO with, relative to --xpub; \
+					 without --xpub, the key has to be in the UTXO's scripts",
+				).takes_value(true),
+			clap::Arg::with_name("fingerprint")
+				.long("fingerprint")
+				.help("the fingerprint of the master key of --hd-keypath, when no --xpub is given")
+				.takes_value(true)
+				.requires("hd-keypath")
+				.conflicts_with("xpub"),
 			clap::Arg::with_name("redeem-script")
 				.long("redeem-script")
 				.help("the redeem script needed to spend the UTXO")
@@ -44,7 +63,6 @@ pub fn subcommand<'a>() -> clap::App<'a, 'a> {
 				.long("witness-script")
 				.help("the witness script needed to spend the UTXO")
 				.takes_value(true),
-			//TODO(stevenroose) add HD keypaths
 			// Metadata arguments.
 			clap::Arg::with_name("block-number")
 				.long("block-number")
@@ -57,87 +75,211 @@ pub fn subcommand<'a>() -> clap::App<'a, 'a> {
 		])
 }
 
+/// The user-provided data describing a UTXO to add.
+#[derive(Debug, Default)]
+pub struct UtxoFields {
+	pub outpoint: String,
+	pub previous_tx: Option<String>,
+	pub previous_output: Option<String>,
+	pub redeem_script: Option<String>,
+	pub witness_script: Option<String>,
+	pub xpub: Option<String>,
+	pub hd_keypath: Option<String>,
+	pub fingerprint: Option<String>,
+	pub block_number: Option<String>,
+	pub block_hash: Option<String>,
+}
+
+/// The public keys pushed in the scripts of the UTXO: in a P2PK output script or in the
+/// redeem and witness scripts.
+fn script_pubkeys(utxo: &bitcoin::UTXO) -> BTreeSet<Vec<u8>> {
+	let output_script = match utxo.has_txout() {
+		true => Some(&utxo.txout().script_pubkey),
+		false => None,
+	};
+	let scripts = vec![
+		utxo.psbt_input.redeem_script.as_ref(),
+		utxo.psbt_input.witness_script.as_ref(),
+		output_script,
+	];
+	scripts
+		.into_iter()
+		.filter_map(|s| s)
+		.flat_map(|s| s.iter(false))
+		.filter_map(|i| match i {
+			Instruction::PushBytes(data) if data.len() == 33 || data.len() == 65 => {
+				Some(data.to_vec())
+			}
+			_ => None,
+		}).collect()
+}
+
+/// Decode an optional hex-encoded consensus-encoded field.
+fn decode_field<T>(
+	value: &Option<String>,
+	name: &str,
+) -> Result<Option<T>, String>
+where
+	T: for<'a> Decodable<Cursor<&'a [u8]>>,
+{
+	match *value {
+		Some(ref v) => {
+			let bytes = hex::decode(v).map_err(|_| format!("invalid {} hex", name))?;
+			deserialize(&bytes).map(Some).map_err(|_| format!("invalid {} encoding", name))
+		}
+		None => Ok(None),
+	}
+}
+
+impl UtxoFields {
+	/// Take the fields from the add-utxo arguments.
+	fn from_matches(matches: &clap::ArgMatches) -> UtxoFields {
+		let value = |name: &str| matches.value_of(name).map(|v| v.to_owned());
+		UtxoFields {
+			outpoint: value("outpoint").expect("outpoint is required"),
+			previous_tx: value("previous-tx"),
+			previous_output: value("previous-output"),
+			redeem_script: value("redeem-script"),
+			witness_script: value("witness-script"),
+			xpub: value("xpub"),
+			hd_keypath: value("hd-keypath"),
+			fingerprint: value("fingerprint"),
+			block_number: value("block-number"),
+			block_hash: value("block-hash"),
+		}
+	}
+
+	/// Build the UTXO from the fields.  When a backend is given, the UTXO is validated against
+	/// the chain and its missing data is filled in.
+	pub fn build(
+		&self,
+		bitcoind: Option<&mut backend::bitcoind::Backend>,
+		proof_block_number: u32,
+	) -> Result<bitcoin::UTXO, String> {
+		let mut utxo = bitcoin::UTXO {
+			point: self.outpoint.parse().map_err(|_| "failed to parse outpoint")?,
+			psbt_input: psbt::Input {
+				non_witness_utxo: decode_field(&self.previous_tx, "previous tx")?,
+				witness_utxo: decode_field(&self.previous_output, "previous output")?,
+				redeem_script: decode_field(&self.redeem_script, "redeem script")?,
+				witness_script: decode_field(&self.witness_script, "witness script")?,
+				..Default::default()
+			},
+			block_number: match self.block_number {
+				Some(ref n) => n.parse().map_err(|_| "failed to parse block number")?,
+				None => 0,
+			},
+			block_hash: match self.block_hash {
+				Some(ref h) => Some(h.parse().map_err(|_| "failed to parse block hash")?),
+				None => None,
+			},
+			wallet: None,
+		};
+
+		if let Some(bitcoind) = bitcoind {
+			bitcoind.validate_utxo(&mut utxo, proof_block_number)?;
+		}
+
+		match (&self.xpub, &self.hd_keypath) {
+			(&Some(ref xpub), &Some(ref path)) => {
+				let xpub: hd::OriginXpub = xpub.parse()?;
+				let (pubkey, origin) = xpub.derive(&hd::parse_path(path)?)?;
+				if utxo.has_txout() && !utxo.is_signable_by(&pubkey.serialize()) {
+					return Err(format!("the derived key {} can't sign for the UTXO", pubkey));
+				}
+				utxo.psbt_input.hd_keypaths.insert(pubkey, (origin.fingerprint, origin.path));
+			}
+			(&None, &Some(ref path)) => {
+				// Without an xpub, the key can only be taken from the scripts.
+				let fingerprint = match self.fingerprint {
+					Some(ref f) => hex::decode(f).map_err(|_| "invalid hex fingerprint")?,
+					None => return Err("an HD keypath without xpub needs a fingerprint".into()),
+				};
+				if fingerprint.len() != 4 {
+					return Err("the fingerprint must be 4 bytes".into());
+				}
+				let path = hd::parse_path(path)?;
+				let mut keys = script_pubkeys(&utxo).into_iter();
+				let pubkey = match (keys.next(), keys.next()) {
+					(Some(key), None) => PublicKey::from_slice(&key)
+						.map_err(|e| format!("invalid public key in the scripts: {}", e))?,
+					_ => return Err("an xpub is needed to derive the key of the HD keypath".into()),
+				};
+				let fingerprint = Fingerprint::from(&fingerprint[..]);
+				utxo.psbt_input.hd_keypaths.insert(pubkey, (fingerprint, path));
+			}
+			(&None, &None) => {}
+			(&Some(_), &None) => return Err("the xpub needs an HD keypath".into()),
+		}
+
+		if utxo.has_txout() {
+			utxo.check_scripts()?;
+		}
+		Ok(utxo)
+	}
+}
+
 /// Execute the add-utxo command.
 pub fn execute(ctx: &mut context::Ctx) {
 	let mut pf = ctx.load_proof_file();
 	let proof_id = ctx.command().value_of("id").expect("no proof identifier given");
 
-	let outpoint_str = ctx.command().value_of("outpoint").expect("outpoint is required");
-	let outpoint = outpoint_str.parse().expect("failed to parse outpoint");
+	if pf.network == Network::LIQUID {
+		panic!("Manually adding Liquid UTXOs is not supported yet, use fetch-utxos instead.");
+	}
+
+	let fields = UtxoFields::from_matches(ctx.command());
+	let outpoint = fields.outpoint.parse().expect("failed to parse outpoint");
 
-	let mut proof = pf
-		.take_proof(proof_id)
-		.or_else(|| Some(bitcoin::Proof::new(proof_id.to_owned(), Proof_Status::GATHERING_UTXOS)))
-		.unwrap();
-	if let Some(ref utxo) = proof.utxos.iter().find(|u| u.point == outpoint) {
+	let mut proof = common::take_editable_proof(&mut pf, proof_id);
+	if let Some(ref utxo) = proof.bitcoin().utxos.iter().find(|u| u.point == outpoint) {
 		info!("UTXO found with given outpoint: {:?}", utxo);
 		panic!("Proof already has a UTXO with this outpoint.");
 	}
+	if let Some(other) = pf.find_bitcoin_utxo(&outpoint) {
+		panic!("UTXO is already used in proof '{}'.", other);
+	}
 
-	let utxo = bitcoin::UTXO {
-		point: outpoint,
-		psbt_input: psbt::Input {
-			non_witness_utxo: match ctx.command().value_of("previous-tx") {
-				Some(t) => Some(
-					deserialize(&hex::decode(t).expect("invalid previous tx hex"))
-						.expect("invalid previous tx encoding"),
-				),
-				None => None,
-			},
-			witness_utxo: match ctx.command().value_of("previous-output") {
-				Some(t) => Some(
-					deserialize(&hex::decode(t).expect("invalid previous output hex"))
-						.expect("invalid previous output encoding"),
-				),
-				None => None,
-			},
-			hd_keypaths: match ctx.command().value_of("hd-keypath") {
-				Some(p) => {
-					//TODO(stevenroose) what to do with public key and fingerprint?
-					// Trezor doesn't need those
-					let mut map = HashMap::new();
-					let path =
-						bip32::parse_derivation_path(&p).expect("failed to parse HD keypath");
-					let mut bytes = vec![0; secp256k1::constants::SECRET_KEY_SIZE];
-					bytes[0] = 1;
-					let empty_privkey =
-						secp256k1::key::SecretKey::from_slice(&bytes).unwrap();
-					let secp = secp256k1::Secp256k1::signing_only();
-					let pubkey = secp256k1::key::PublicKey::from_secret_key(&secp, &empty_privkey);
-					map.insert(pubkey, (Default::default(), path));
-					map
-				}
-				None => HashMap::new(),
-			},
-			redeem_script: match ctx.command().value_of("redeem-script") {
-				Some(t) => Some(
-					deserialize(&hex::decode(t).expect("invalid redeem script hex"))
-						.expect("invalid redeem script encoding"),
-				),
-				None => None,
-			},
-			witness_script: match ctx.command().value_of("witness-script") {
-				Some(t) => Some(
-					deserialize(&hex::decode(t).expect("invalid witness script hex"))
-						.expect("invalid witness script encoding"),
-				),
-				None => None,
-			},
-			..Default::default()
-		},
-		block_number: match ctx.command().value_of("block-number") {
-			Some(n) => n.parse().expect("failed to parse block number"),
-			None => 0,
-		},
-		block_hash: match ctx.command().value_of("block-hash") {
-			Some(h) => Some(h.parse().expect("failed to parse block hash")),
-			None => None,
-		},
-	};
+	let mut bitcoind = backend::bitcoind::Backend::load(ctx.command());
+	let utxo = fields
+		.build(bitcoind.as_mut(), pf.block_number)
+		.unwrap_or_else(|e| panic!("Invalid UTXO {}: {}", outpoint, e));
+	if let Some(mut bitcoind) = bitcoind {
+		if !bitcoind.is_unspent(&outpoint) {
+			println!("Warning: UTXO {} is currently spent.", outpoint);
+		}
+		println!("Validated the UTXO against the chain.");
+	}
+
+	if utxo.has_txout() {
+		match utxo.script_type() {
+			(bitcoin::ScriptType::Unknown, _) => {
+				println!("Warning: the UTXO has a non-standard output script.")
+			}
+			(tp, Some(redeem_tp)) => println!("Script type: {} wrapping {}", tp, redeem_tp),
+			(tp, None) => println!("Script type: {}", tp),
+		}
+		let missing = utxo.missing_signing_data();
+		if !missing.is_empty() {
+			println!("To sign for this UTXO, you still need to provide:");
+			for m in missing.iter() {
+				println!("  - {}", m);
+			}
+		}
+	} else {
+		if fields.xpub.is_some() {
+			println!("Warning: the derived key can't be checked against the unknown UTXO script.");
+		}
+		println!(
+			"Warning: the transaction output is unknown. Provide --previous-tx or \
+			 --previous-output, or use --bitcoind to fetch it."
+		);
+	}
 
 	debug!("Adding new UTXO to proof: {:?}", utxo);
-	proof.utxos.push(utxo);
+	proof.bitcoin_mut().utxos.push(utxo);
 	println!("Successfully added the UTXO to the proof.");
+	common::finish_utxo_changes(&pf.challenge, &mut proof);
 
 	pf.proofs.insert(0, proof);
 	ctx.save_proof_file(pf);
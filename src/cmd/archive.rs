@@ -0,0 +1,54 @@
+use std::path::Path;
+
+use clap;
+
+use backend;
+use cmd::history::history_dir_arg;
+use cmd::verify;
+use context;
+use history;
+use utils;
+
+/// Create the archive subcommand.
+pub fn subcommand<'a>() -> clap::App<'a, 'a> {
+	clap::SubCommand::with_name("archive")
+		.about("verify the proof file and record it in the attestation history")
+		.args(&backend::bitcoind::args())
+		.args(&backend::elementsd::args())
+		.arg(verify::auditor_key_arg())
+		.arg(history_dir_arg())
+}
+
+/// Execute the archive command.
+pub fn execute(ctx: &mut context::Ctx) {
+	let pf = ctx.load_proof_file();
+	let path = Path::new(ctx.proof_file_path());
+
+	let mut history = history::History::open(ctx.command().value_of("history-dir").unwrap());
+	let sha256 = history::file_sha256(path);
+	if let Some(a) = history.find(&sha256) {
+		panic!("This proof file is already archived as '{}'.", a.file);
+	}
+
+	let reserves = verify::verify_reserves(ctx.command(), &pf);
+	let now = utils::now();
+	let attestation = history::Attestation {
+		file: format!("{}-{}-{}.proof", pf.block_number, now, &sha256[..8]),
+		sha256: sha256,
+		archived_at: now,
+		network: utils::network_name(pf.network),
+		challenge: pf.challenge.to_string(),
+		block_number: pf.block_number,
+		block_hash: pf.block_hash.as_ref().map(utils::block_hash_hex),
+		nb_utxos: reserves.nb_utxos,
+		totals: reserves.totals(pf.network),
+	};
+
+	if ctx.dry_run() {
+		println!("Dry-run: not archiving the proof file.");
+		return;
+	}
+	let file = attestation.file.clone();
+	history.archive(path, attestation);
+	println!("Archived the verified proof file as '{}'.", file);
+}
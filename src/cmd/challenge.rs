@@ -0,0 +1,119 @@
+use clap;
+use hex;
+use rand;
+
+use challenge::Challenge;
+use context;
+use protos;
+use utils;
+
+/// Create the challenge subcommand.
+pub fn subcommand<'a>() -> clap::App<'a, 'a> {
+	clap::SubCommand::with_name("challenge")
+		.about("manage auditor-issued challenges")
+		.setting(clap::AppSettings::SubcommandRequiredElseHelp)
+		.subcommand(
+			clap::SubCommand::with_name("create")
+				.about("create a challenge file signed by the auditor")
+				.args(&[
+					clap::Arg::with_name("message")
+						.long("message")
+						.short("m")
+						.help("the challenge message")
+						.takes_value(true)
+						.required(true),
+					clap::Arg::with_name("auditor")
+						.long("auditor")
+						.help("the identity of the auditor")
+						.takes_value(true)
+						.required(true),
+					clap::Arg::with_name("target-block")
+						.long("target-block")
+						.help("the block number the proofs have to be valid at")
+						.takes_value(true)
+						.required(true),
+					clap::Arg::with_name("end-block")
+						.long("end-block")
+						.help("the last block of the range the UTXOs have to stay unspent over")
+						.takes_value(true),
+					clap::Arg::with_name("date")
+						.long("date")
+						.help("the date of the challenge [default: today]")
+						.takes_value(true),
+					clap::Arg::with_name("nonce")
+						.long("nonce")
+						.help("the nonce (hex) [default: 32 random bytes]")
+						.takes_value(true),
+					clap::Arg::with_name("block-hash")
+						.long("block-hash")
+						.help("a recent block hash to prove freshness")
+						.takes_value(true),
+					clap::Arg::with_name("liabilities-root")
+						.long("liabilities-root")
+						.help("the liabilities Merkle root (hex) to commit to")
+						.takes_value(true),
+					clap::Arg::with_name("auditor-privkey")
+						.long("auditor-privkey")
+						.help("the WIF private key of the auditor to sign the challenge with")
+						.takes_value(true)
+						.required(true),
+					clap::Arg::with_name("output")
+						.long("output")
+						.short("o")
+						.help("the file to write the challenge to")
+						.takes_value(true)
+						.default_value("reserves.challenge"),
+				]),
+		)
+}
+
+fn create(ctx: &mut context::Ctx, matches: &clap::ArgMatches) {
+	let mut c = Challenge::new(matches.value_of("message").unwrap().to_owned());
+	c.auditor = Some(matches.value_of("auditor").unwrap().to_owned());
+	let today = || utils::utc_date(utils::now());
+	c.date = Some(matches.value_of("date").map(|d| d.to_owned()).unwrap_or_else(today));
+	c.target_block =
+		Some(matches.value_of("target-block").unwrap().parse().expect("invalid target block"));
+	if let Some(end) = matches.value_of("end-block") {
+		let end = end.parse().expect("invalid end block");
+		if Some(end) < c.target_block {
+			panic!("The end block of the range is before the target block.");
+		}
+		c.end_block = Some(end);
+	}
+	c.nonce = Some(match matches.value_of("nonce") {
+		Some(nonce) => hex::decode(nonce).expect("invalid hex nonce"),
+		None => rand::random::<[u8; 32]>().to_vec(),
+	});
+	if let Some(hash) = matches.value_of("block-hash") {
+		c.block_hash = Some(utils::parse_block_hash(hash).expect("invalid block hash"));
+	}
+	if let Some(root) = matches.value_of("liabilities-root") {
+		c.liabilities_root = Some(
+			utils::parse_liabilities_root(root)
+				.unwrap_or_else(|e| panic!("Invalid liabilities root: {}", e)),
+		);
+	}
+
+	let (secret, compressed) = utils::parse_wif(matches.value_of("auditor-privkey").unwrap())
+		.expect("invalid WIF private key");
+	c.sign(&secret, compressed).unwrap_or_else(|e| panic!("Failed to sign the challenge: {}", e));
+	println!("Created challenge with hash {}", hex::encode(&c.hash()));
+	println!("Signed by auditor key {}", hex::encode(&c.auditor_key().unwrap()));
+
+	let output = matches.value_of("output").unwrap();
+	if ctx.dry_run() {
+		println!("Dry-run: not writing challenge file to disk.");
+		return;
+	}
+	let proto: protos::Challenge = c.into();
+	utils::write_proto_file(output, &proto);
+}
+
+/// Execute the challenge command.
+pub fn execute(ctx: &mut context::Ctx) {
+	match ctx.subcommand() {
+		("create", matches) => create(ctx, matches),
+		(c, _) => println!("command {} unknown", c),
+	}
+}
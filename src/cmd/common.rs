@@ -1,5 +1,8 @@
 use clap;
 
+use challenge::Challenge;
+use common::*;
+
 /// Create the CLI argument for passing the proof identifier.
 pub fn id_arg<'a>() -> clap::Arg<'a, 'a> {
 	clap::Arg::with_name("id")
@@ -9,3 +12,50 @@ pub fn id_arg<'a>() -> clap::Arg<'a, 'a> {
 		.default_value("(default)")
 		.takes_value(true)
 }
+
+/// Take the proof with the given id from the proof file to change its UTXOs, or create it.
+/// Bitcoin proofs that are being signed can be changed as well, their proof transactions are
+/// rebuilt afterwards by `finish_utxo_changes`.
+pub fn take_editable_proof(pf: &mut ProofFile, proof_id: &str) -> Proof {
+	let proof = pf.take_proof(proof_id).unwrap_or_else(|| {
+		Proof::new(pf.network, proof_id.to_owned(), Proof_Status::GATHERING_UTXOS)
+	});
+	match (proof.status(), &proof) {
+		(Proof_Status::GATHERING_UTXOS, _) => { /* ok */ }
+		(Proof_Status::SIGNING, &Proof::Bitcoin(_)) => { /* ok */ }
+		(Proof_Status::SIGNING, &Proof::Liquid(_)) => {
+			panic!("Proof '{}' is being signed, use reopen first.", proof_id)
+		}
+		(Proof_Status::FINAL, _) => panic!("Proof '{}' is already in FINAL state.", proof_id),
+		(Proof_Status::UNDEFINED, _) => panic!("Corrupt proof file"),
+	}
+	proof
+}
+
+/// Rebuild the proof transactions of a proof that is being signed after its UTXOs changed.
+pub fn finish_utxo_changes(challenge: &Challenge, proof: &mut Proof) {
+	if proof.status() != Proof_Status::SIGNING {
+		return;
+	}
+
+	let proof = proof.bitcoin_mut();
+	let nb_invalidated = proof.rebuild_signing(challenge);
+	if nb_invalidated > 0 {
+		println!(
+			"Warning: the change invalidated {} signatures, the affected chunks have to be \
+			 signed again.",
+			nb_invalidated
+		);
+	}
+	match proof.status {
+		Proof_Status::GATHERING_UTXOS => {
+			println!("The proof has no UTXOs left and is gathering UTXOs again.")
+		}
+		Proof_Status::FINAL => println!("All chunks of the proof are signed."),
+		_ => println!(
+			"Rebuilt the proof transactions, {} of {} chunks are signed.",
+			proof.nb_signed_chunks(),
+			proof.chunks.len()
+		),
+	}
+}
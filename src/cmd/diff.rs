@@ -0,0 +1,472 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use clap;
+use serde_json::Value;
+
+use backend;
+use common::*;
+use context;
+use liquid;
+use utils;
+
+/// The asset name used for Bitcoin amounts.
+const BITCOIN_ASSET: &'static str = "BTC";
+
+/// Create the diff subcommand.
+pub fn subcommand<'a>() -> clap::App<'a, 'a> {
+	clap::SubCommand::with_name("diff")
+		.about("show the changes between two proof files")
+		// When bitcoind is given, it's used to find the transactions that moved coins.
+		.args(&backend::bitcoind::args())
+		.args(&[
+			clap::Arg::with_name("old")
+				.help("the old proof file")
+				.takes_value(true)
+				.required(true),
+			clap::Arg::with_name("new")
+				.help("the new proof file")
+				.takes_value(true)
+				.required(true),
+			clap::Arg::with_name("format")
+				.long("format")
+				.help("the output format")
+				.takes_value(true)
+				.possible_values(&["text", "json"])
+				.default_value("text"),
+		])
+}
+
+/// A UTXO in one of the proof files.
+struct Coin {
+	proof: String,
+	/// The asset and value, unknown for final proofs that don't keep their UTXOs.
+	amount: Option<(String, u64)>,
+	/// The outpoints spent by the transaction that created the UTXO, if known.
+	spent: Option<Vec<String>>,
+}
+
+/// Whether the UTXOs of the proof are only known from its proof txs.
+fn has_pruned_utxos(proof: &Proof) -> bool {
+	let no_utxos = match *proof {
+		Proof::Bitcoin(ref p) => p.utxos.is_empty(),
+		Proof::Liquid(ref p) => p.utxos.is_empty(),
+	};
+	no_utxos && proof.status() == Proof_Status::FINAL
+}
+
+/// Collect the UTXOs of all proofs in the proof file by outpoint.
+/// For final proofs without UTXOs, the inputs of the proof txs are used.
+fn collect_coins(pf: &ProofFile) -> BTreeMap<String, Coin> {
+	let mut coins = BTreeMap::new();
+	for proof in pf.proofs.iter() {
+		if has_pruned_utxos(proof) {
+			for point in proof.outpoints().into_iter() {
+				let coin = Coin {
+					proof: proof.id().to_owned(),
+					amount: None,
+					spent: None,
+				};
+				coins.insert(point, coin);
+			}
+			continue;
+		}
+
+		match *proof {
+			Proof::Bitcoin(ref p) => {
+				for u in p.utxos.iter() {
+					if !u.has_txout() {
+						panic!("UTXO {} of proof '{}' has no output data.", u.point, p.id);
+					}
+					let coin = Coin {
+						proof: p.id.clone(),
+						amount: Some((BITCOIN_ASSET.to_owned(), u.txout().value)),
+						spent: u.psbt_input.non_witness_utxo.as_ref().map(|tx| {
+							tx.input.iter().map(|i| i.previous_output.to_string()).collect()
+						}),
+					};
+					coins.insert(u.point.to_string(), coin);
+				}
+			}
+			Proof::Liquid(ref p) => {
+				for u in p.utxos.iter() {
+					let (asset, value) = u.asset_value();
+					let coin = Coin {
+						proof: p.id.clone(),
+						amount: Some((liquid::asset_name(&asset), value)),
+						spent: u.pset_input.non_witness_utxo.as_ref().map(|tx| {
+							tx.input.iter().map(|i| i.previous_output.to_string()).collect()
+						}),
+					};
+					coins.insert(u.point.to_string(), coin);
+				}
+			}
+		}
+	}
+	coins
+}
+
+/// The amounts per asset of the final proofs without UTXOs, taken from their proof txs.
+fn pruned_amounts(pf: &ProofFile) -> Vec<(String, String, u64)> {
+	let mut amounts = Vec::new();
+	for proof in pf.proofs.iter().filter(|p| has_pruned_utxos(p)) {
+		match *proof {
+			Proof::Bitcoin(ref p) => {
+				amounts.push((p.id.clone(), BITCOIN_ASSET.to_owned(), p.amount()));
+			}
+			Proof::Liquid(ref p) => {
+				for (asset, value) in p.amounts().into_iter() {
+					amounts.push((p.id.clone(), liquid::asset_name(&asset), value));
+				}
+			}
+		}
+	}
+	amounts
+}
+
+/// The total amount per asset of the UTXOs of the given proof, or of all proofs.
+fn totals(
+	pf: &ProofFile,
+	coins: &BTreeMap<String, Coin>,
+	proof: Option<&str>,
+) -> BTreeMap<String, u64> {
+	let selected = |id: &str| proof.map(|p| p == id).unwrap_or(true);
+	let mut totals = BTreeMap::new();
+	for coin in coins.values().filter(|c| selected(&c.proof)) {
+		if let Some((ref asset, value)) = coin.amount {
+			*totals.entry(asset.clone()).or_insert(0) += value;
+		}
+	}
+	for (id, asset, value) in pruned_amounts(pf).into_iter() {
+		if selected(&id) {
+			*totals.entry(asset).or_insert(0) += value;
+		}
+	}
+	totals
+}
+
+/// The change of the total amounts per asset.
+struct TotalChange {
+	asset: String,
+	old: u64,
+	new: u64,
+}
+
+fn total_changes(old: BTreeMap<String, u64>, new: BTreeMap<String, u64>) -> Vec<TotalChange> {
+	let assets: BTreeSet<_> = old.keys().chain(new.keys()).cloned().collect();
+	assets
+		.into_iter()
+		.map(|asset| TotalChange {
+			old: old.get(&asset).cloned().unwrap_or(0),
+			new: new.get(&asset).cloned().unwrap_or(0),
+			asset: asset,
+		}).collect()
+}
+
+/// The changes of a single proof.
+struct ProofChange {
+	id: String,
+	/// The status in the old and the new file, None if the proof is missing in the file.
+	old_status: Option<Proof_Status>,
+	new_status: Option<Proof_Status>,
+	totals: Vec<TotalChange>,
+	added: Vec<String>,
+	removed: Vec<String>,
+}
+
+/// A movement of coins between the two files.
+struct Movement {
+	/// "consolidated" or "moved" for coins spent on chain, "reassigned" for coins that moved to
+	/// another proof.
+	kind: &'static str,
+	/// The transaction that spent the old coins.
+	txid: Option<String>,
+	from: Vec<String>,
+	to: Vec<String>,
+	from_proofs: BTreeSet<String>,
+	to_proofs: BTreeSet<String>,
+}
+
+/// All changes between the two proof files.
+struct Diff {
+	/// The changed fields of the proof file with their old and new value.
+	fields: Vec<(&'static str, String, String)>,
+	proofs: Vec<ProofChange>,
+	totals: Vec<TotalChange>,
+	movements: Vec<Movement>,
+}
+
+fn status_name(status: Option<Proof_Status>) -> String {
+	status.map(|s| format!("{:?}", s)).unwrap_or_else(|| "absent".to_owned())
+}
+
+fn block_hash_name(hash: &Option<[u8; 32]>) -> String {
+	hash.as_ref().map(utils::block_hash_hex).unwrap_or_else(|| "unknown".to_owned())
+}
+
+/// Find the transactions that created the added coins and spent the removed ones.
+fn find_movements(
+	old: &BTreeMap<String, Coin>,
+	new: &BTreeMap<String, Coin>,
+	removed: &BTreeSet<&String>,
+) -> Vec<Movement> {
+	let mut by_tx: BTreeMap<String, Movement> = BTreeMap::new();
+	for (point, coin) in new.iter().filter(|&(p, _)| !old.contains_key(p)) {
+		let spent: Vec<String> = match coin.spent {
+			Some(ref spent) => spent.iter().filter(|p| removed.contains(p)).cloned().collect(),
+			None => continue,
+		};
+		if spent.is_empty() {
+			continue;
+		}
+
+		let txid = point.split(':').next().unwrap().to_owned();
+		let movement = by_tx.entry(txid.clone()).or_insert_with(|| Movement {
+			kind: if spent.len() > 1 { "consolidated" } else { "moved" },
+			txid: Some(txid),
+			from_proofs: spent.iter().map(|p| old[p].proof.clone()).collect(),
+			from: spent,
+			to: vec![],
+			to_proofs: BTreeSet::new(),
+		});
+		movement.to.push(point.clone());
+		movement.to_proofs.insert(coin.proof.clone());
+	}
+
+	let mut movements: Vec<Movement> = by_tx.into_iter().map(|(_, m)| m).collect();
+	for (point, coin) in new.iter() {
+		if let Some(old_coin) = old.get(point) {
+			if old_coin.proof != coin.proof {
+				movements.push(Movement {
+					kind: "reassigned",
+					txid: None,
+					from: vec![point.clone()],
+					to: vec![point.clone()],
+					from_proofs: Some(old_coin.proof.clone()).into_iter().collect(),
+					to_proofs: Some(coin.proof.clone()).into_iter().collect(),
+				});
+			}
+		}
+	}
+	movements
+}
+
+/// The coins of the proof that are not in that proof in the other file.
+fn proof_only_coins(
+	coins: &BTreeMap<String, Coin>,
+	others: &BTreeMap<String, Coin>,
+	id: &str,
+) -> Vec<String> {
+	coins
+		.iter()
+		.filter(|&(p, c)| c.proof == id && others.get(p).map(|o| o.proof != id).unwrap_or(true))
+		.map(|(p, _)| p.clone())
+		.collect()
+}
+
+/// Compute the changes between the two proof files.
+fn diff(
+	old_pf: &ProofFile,
+	new_pf: &ProofFile,
+	old: &BTreeMap<String, Coin>,
+	new: &BTreeMap<String, Coin>,
+) -> Diff {
+	let mut fields = Vec::new();
+	if old_pf.network != new_pf.network {
+		let (o, n) = (utils::network_name(old_pf.network), utils::network_name(new_pf.network));
+		fields.push(("network", o, n));
+	}
+	if old_pf.challenge != new_pf.challenge {
+		let (o, n) = (old_pf.challenge.to_string(), new_pf.challenge.to_string());
+		fields.push(("challenge", o, n));
+	}
+	if old_pf.block_number != new_pf.block_number {
+		let (o, n) = (old_pf.block_number.to_string(), new_pf.block_number.to_string());
+		fields.push(("block number", o, n));
+	}
+	if old_pf.end_block_number != new_pf.end_block_number {
+		let name = |end: Option<u32>| end.map(|e| e.to_string()).unwrap_or_else(|| "none".into());
+		let (o, n) = (name(old_pf.end_block_number), name(new_pf.end_block_number));
+		fields.push(("range end block", o, n));
+	}
+	if old_pf.block_hash != new_pf.block_hash {
+		let (o, n) = (block_hash_name(&old_pf.block_hash), block_hash_name(&new_pf.block_hash));
+		fields.push(("block hash", o, n));
+	}
+
+	let ids: BTreeSet<&str> =
+		old_pf.proofs.iter().chain(new_pf.proofs.iter()).map(|p| p.id()).collect();
+	let proofs = ids
+		.into_iter()
+		.map(|id| {
+			let status = |pf: &ProofFile| {
+				pf.proofs.iter().find(|p| p.id() == id).map(|p| p.status())
+			};
+			ProofChange {
+				id: id.to_owned(),
+				old_status: status(old_pf),
+				new_status: status(new_pf),
+				totals: total_changes(
+					totals(old_pf, old, Some(id)),
+					totals(new_pf, new, Some(id)),
+				),
+				added: proof_only_coins(new, old, id),
+				removed: proof_only_coins(old, new, id),
+			}
+		}).collect();
+
+	let removed: BTreeSet<&String> = old.keys().filter(|p| !new.contains_key(*p)).collect();
+	Diff {
+		fields: fields,
+		proofs: proofs,
+		totals: total_changes(totals(old_pf, old, None), totals(new_pf, new, None)),
+		movements: find_movements(old, new, &removed),
+	}
+}
+
+fn format_total(t: &TotalChange) -> String {
+	format!("{}: {} -> {} ({:+})", t.asset, t.old, t.new, t.new as i64 - t.old as i64)
+}
+
+fn print_text(diff: &Diff) {
+	if diff.fields.is_empty() {
+		println!("The network, challenge and proof block are unchanged.");
+	}
+	for &(name, ref old, ref new) in diff.fields.iter() {
+		println!("{}: {} -> {}", name, old, new);
+	}
+
+	for p in diff.proofs.iter() {
+		let unchanged = p.old_status == p.new_status && p.added.is_empty() && p.removed.is_empty();
+		if unchanged {
+			continue;
+		}
+		println!("");
+		println!(
+			"proof '{}' ({} -> {}):",
+			p.id,
+			status_name(p.old_status),
+			status_name(p.new_status)
+		);
+		for t in p.totals.iter() {
+			println!("  {}", format_total(t));
+		}
+		for point in p.added.iter() {
+			println!("  + {}", point);
+		}
+		for point in p.removed.iter() {
+			println!("  - {}", point);
+		}
+	}
+
+	println!("");
+	println!("total:");
+	for t in diff.totals.iter() {
+		println!("  {}", format_total(t));
+	}
+
+	if !diff.movements.is_empty() {
+		println!("");
+		println!("movements:");
+	}
+	for m in diff.movements.iter() {
+		let proofs = |ids: &BTreeSet<String>| ids.iter().cloned().collect::<Vec<_>>().join(", ");
+		match m.txid {
+			Some(ref txid) => println!(
+				"  {} {} UTXOs of {} into {} UTXOs of {} in tx {}",
+				m.kind,
+				m.from.len(),
+				proofs(&m.from_proofs),
+				m.to.len(),
+				proofs(&m.to_proofs),
+				txid
+			),
+			None => println!(
+				"  {} {} from {} to {}",
+				m.kind,
+				m.from[0],
+				proofs(&m.from_proofs),
+				proofs(&m.to_proofs)
+			),
+		}
+	}
+}
+
+fn totals_json(totals: &[TotalChange]) -> Value {
+	let totals = totals.iter().map(|t| {
+		json!({
+			"asset": t.asset,
+			"old": t.old,
+			"new": t.new,
+			"change": t.new as i64 - t.old as i64,
+		})
+	});
+	Value::Array(totals.collect())
+}
+
+fn print_json(diff: &Diff) {
+	let fields = diff.fields.iter().map(|&(name, ref old, ref new)| {
+		json!({
+			"field": name,
+			"old": old,
+			"new": new,
+		})
+	});
+	let proofs = diff.proofs.iter().map(|p| {
+		json!({
+			"id": p.id,
+			"old_status": p.old_status.map(|s| format!("{:?}", s)),
+			"new_status": p.new_status.map(|s| format!("{:?}", s)),
+			"totals": totals_json(&p.totals),
+			"added": p.added,
+			"removed": p.removed,
+		})
+	});
+	let movements = diff.movements.iter().map(|m| {
+		json!({
+			"kind": m.kind,
+			"txid": m.txid,
+			"from": m.from,
+			"to": m.to,
+			"from_proofs": m.from_proofs,
+			"to_proofs": m.to_proofs,
+		})
+	});
+	let json = json!({
+		"changed_fields": fields.collect::<Vec<_>>(),
+		"proofs": proofs.collect::<Vec<_>>(),
+		"totals": totals_json(&diff.totals),
+		"movements": movements.collect::<Vec<_>>(),
+	});
+	println!("{}", json);
+}
+
+/// Execute the diff command.
+pub fn execute(ctx: &mut context::Ctx) {
+	let old_pf = ctx.load_other_proof_file(ctx.command().value_of("old").unwrap());
+	let new_pf = ctx.load_other_proof_file(ctx.command().value_of("new").unwrap());
+
+	let old = collect_coins(&old_pf);
+	let mut new = collect_coins(&new_pf);
+
+	// Look up the transactions that created the new coins, when they are not in the proof file.
+	if let Some(mut bitcoind) = backend::bitcoind::Backend::load(ctx.command()) {
+		if new_pf.network == Network::LIQUID {
+			panic!("Looking up Liquid transactions is not supported.");
+		}
+		for (point, coin) in new.iter_mut() {
+			if coin.spent.is_some() || old.contains_key(point) {
+				continue;
+			}
+			let txid = point.split(':').next().unwrap().parse().expect("invalid outpoint");
+			coin.spent = bitcoind.get_tx(&txid).map(|tx| {
+				tx.input.iter().map(|i| i.previous_output.to_string()).collect()
+			});
+		}
+	}
+
+	let diff = diff(&old_pf, &new_pf, &old, &new);
+	match ctx.command().value_of("format").unwrap() {
+		"json" => print_json(&diff),
+		_ => print_text(&diff),
+	}
+}
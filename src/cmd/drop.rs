@@ -15,7 +15,7 @@ pub fn execute(ctx: &mut Ctx) {
 	let proof_id = ctx.command().value_of("id").expect("no proof identifier given");
 
 	let nb_proofs = pf.proofs.len();
-	pf.proofs.retain(|p| p.id != proof_id);
+	pf.proofs.retain(|p| p.id() != proof_id);
 
 	if nb_proofs <= pf.proofs.len() {
 		println!("No proofs with id '{}' found.", proof_id);
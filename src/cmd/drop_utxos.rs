@@ -2,6 +2,7 @@ use std::collections::HashSet;
 use std::str::FromStr;
 
 use clap;
+use elements;
 use rbitcoin::blockdata::transaction::OutPoint;
 
 use cmd::common;
@@ -29,29 +30,63 @@ pub fn execute(ctx: &mut context::Ctx) {
 
 	let mut proof = pf.take_proof(proof_id).expect("No proof found with given id");
 
-	match proof.status {
+	match proof.status() {
 		Proof_Status::UNDEFINED => panic!("Corrupt proof file"),
-		Proof_Status::SIGNING => panic!("Proof is already in SIGNING state"),
 		Proof_Status::FINAL => panic!("Proof already in FINAL state"),
-		Proof_Status::GATHERING_UTXOS => { /* ok */ }
+		Proof_Status::SIGNING | Proof_Status::GATHERING_UTXOS => { /* ok */ }
 	}
 
-	let mut drops = HashSet::new();
-	for utxo in ctx.command().values_of("utxo").expect("no UTXOs provided") {
-		drops.insert(OutPoint::from_str(utxo).expect(&format!("failed to parse UTXO: {}", utxo)));
-	}
+	let utxos = ctx.command().values_of("utxo").expect("no UTXOs provided");
+	let dropped = match proof {
+		Proof::Bitcoin(ref mut proof) => {
+			let mut drops = HashSet::new();
+			for utxo in utxos {
+				drops.insert(
+					OutPoint::from_str(utxo).expect(&format!("failed to parse UTXO: {}", utxo)),
+				);
+			}
 
-	let nb_before = proof.utxos.len();
-	proof.utxos.retain(|u| {
-		if drops.contains(&u.point) {
-			debug!("Dropping UTXO: {:?}", u);
-			false
-		} else {
-			true
+			let nb_before = proof.utxos.len();
+			proof.utxos.retain(|u| {
+				if drops.contains(&u.point) {
+					debug!("Dropping UTXO: {:?}", u);
+					false
+				} else {
+					true
+				}
+			});
+			nb_before - proof.utxos.len()
 		}
-	});
+		Proof::Liquid(ref mut proof) => {
+			if proof.status == Proof_Status::SIGNING {
+				panic!("Proof is being signed, use reopen first.");
+			}
+
+			let mut drops = HashSet::new();
+			for utxo in utxos {
+				drops.insert(
+					elements::OutPoint::from_str(utxo)
+						.expect(&format!("failed to parse UTXO: {}", utxo)),
+				);
+			}
 
-	println!("Dropped {} UTXOs.", nb_before - proof.utxos.len());
+			let nb_before = proof.utxos.len();
+			proof.utxos.retain(|u| {
+				if drops.contains(&u.point) {
+					debug!("Dropping UTXO: {:?}", u);
+					false
+				} else {
+					true
+				}
+			});
+			nb_before - proof.utxos.len()
+		}
+	};
+
+	println!("Dropped {} UTXOs.", dropped);
+	if dropped > 0 {
+		common::finish_utxo_changes(&pf.challenge, &mut proof);
+	}
 
 	pf.proofs.insert(0, proof);
 	ctx.save_proof_file(pf);
@@ -1,10 +1,17 @@
+use std::cmp;
+use std::collections::{HashMap, HashSet};
+use std::fmt::{Debug, Display};
+
 use clap;
+use serde_json::Value;
 
 use backend;
 use bitcoin;
 use cmd::common;
 use common::*;
 use context;
+use hd;
+use liquid;
 
 /// Create the fetch-utxos subcommand.
 pub fn subcommand<'a>() -> clap::App<'a, 'a> {
@@ -12,44 +19,480 @@ pub fn subcommand<'a>() -> clap::App<'a, 'a> {
 		.about("fetch UTXOs from a wallet to add to a new or existing proof")
 		.arg(common::id_arg())
 		.args(&backend::bitcoind::args())
+		.args(&backend::elementsd::args())
+		.args(&backend::trezor::args())
+		.args(&filter_args())
+		.args(&[
+			clap::Arg::with_name("xpub")
+				.long("xpub")
+				.help(
+					"scan the UTXO set for the keys of this xpub, optionally with key origin; \
+					 needs Bitcoin Core 25 or later with -blockfilterindex",
+				).takes_value(true)
+				.requires("xpub-script-type")
+				.conflicts_with("trezor"),
+			clap::Arg::with_name("xpub-script-type")
+				.long("xpub-script-type")
+				.help(
+					"the script type the xpub keys are used with: p2pkh, p2sh-p2wpkh or p2wpkh \
+					 [with --trezor, default: all three]",
+				).takes_value(true),
+			clap::Arg::with_name("gap-limit")
+				.long("gap-limit")
+				.help("the number of never used keys after which the xpub scan stops")
+				.takes_value(true)
+				.default_value("20"),
+			clap::Arg::with_name("account")
+				.long("account")
+				.help("with --trezor, only scan this account [default: discover all used accounts]")
+				.takes_value(true),
+		])
+}
+
+/// Create the CLI arguments for the UTXO filters.
+fn filter_args<'a>() -> Vec<clap::Arg<'a, 'a>> {
+	let list = |name: &'a str, help: &'a str| {
+		clap::Arg::with_name(name)
+			.long(name)
+			.help(help)
+			.takes_value(true)
+			.multiple(true)
+			.use_delimiter(true)
+			.number_of_values(1)
+	};
+	vec![
+		clap::Arg::with_name("min-confirmations")
+			.long("min-confirmations")
+			.help("the minimum number of confirmations at the proof block")
+			.takes_value(true)
+			.default_value("6"),
+		clap::Arg::with_name("max-confirmations")
+			.long("max-confirmations")
+			.help("the maximum number of confirmations at the proof block")
+			.takes_value(true),
+		clap::Arg::with_name("min-value")
+			.long("min-value")
+			.help("the minimum value in satoshis, to skip dust")
+			.takes_value(true),
+		list("address", "only take UTXOs with these addresses"),
+		list("exclude-address", "skip UTXOs with these addresses"),
+		list("label", "only take UTXOs with these wallet labels"),
+		list("exclude-label", "skip UTXOs with these wallet labels"),
+		list("script-type", "only take UTXOs of these script types (p2pk, p2pkh, p2sh, ...)"),
+		clap::Arg::with_name("max-inputs")
+			.long("max-inputs")
+			.help("the maximum number of UTXOs in the proof, the largest ones are kept")
+			.takes_value(true),
+	]
+}
+
+/// The default minimum number of confirmations of the UTXOs at the proof block.
+pub const DEFAULT_MIN_CONFIRMATIONS: u32 = 6;
+
+/// The policy deciding which of the fetched UTXOs are added to a proof.
+#[derive(Debug, Default)]
+pub struct UtxoFilter {
+	pub min_confirmations: u32,
+	pub max_confirmations: Option<u32>,
+	pub min_value: u64,
+	pub addresses: Option<HashSet<String>>,
+	pub exclude_addresses: HashSet<String>,
+	pub labels: Option<HashSet<String>>,
+	pub exclude_labels: HashSet<String>,
+	pub script_types: Option<HashSet<bitcoin::ScriptType>>,
+	pub max_inputs: Option<usize>,
+}
+
+impl UtxoFilter {
+	/// Create the filter from the CLI arguments.
+	pub fn from_matches(matches: &clap::ArgMatches) -> UtxoFilter {
+		let number = |name: &str| -> Option<u64> {
+			matches.value_of(name).map(|v| v.parse().expect(&format!("invalid {}", name)))
+		};
+		let set = |name: &str| -> Option<HashSet<String>> {
+			matches.values_of(name).map(|v| v.map(|s| s.to_owned()).collect())
+		};
+		UtxoFilter {
+			min_confirmations: number("min-confirmations")
+				.map(|n| n as u32)
+				.unwrap_or(DEFAULT_MIN_CONFIRMATIONS),
+			max_confirmations: number("max-confirmations").map(|n| n as u32),
+			min_value: number("min-value").unwrap_or(0),
+			addresses: set("address"),
+			exclude_addresses: set("exclude-address").unwrap_or_default(),
+			labels: set("label"),
+			exclude_labels: set("exclude-label").unwrap_or_default(),
+			script_types: matches.values_of("script-type").map(|v| {
+				v.map(|t| t.parse().unwrap_or_else(|e| panic!("{}", e))).collect()
+			}),
+			max_inputs: number("max-inputs").map(|n| n as usize),
+		}
+	}
+
+	/// Check whether the UTXO passes the filter, given the height the confirmations are
+	/// counted at.  Returns the reason for excluding it otherwise.
+	pub fn check(
+		&self,
+		utxo: &bitcoin::UTXO,
+		info: &backend::bitcoind::WalletInfo,
+		height: u32,
+	) -> Result<(), String> {
+		if !info.spendable {
+			return Err("not spendable by the wallet".into());
+		}
+
+		let confirmations = (height + 1).saturating_sub(utxo.block_number);
+		if confirmations < self.min_confirmations {
+			return Err(format!("only {} confirmations", confirmations));
+		}
+		if self.max_confirmations.map(|max| confirmations > max).unwrap_or(false) {
+			return Err(format!("{} confirmations", confirmations));
+		}
+
+		if (utxo.value().into_inner() as u64) < self.min_value {
+			return Err("value below the minimum".into());
+		}
+
+		let address = info.address.as_ref().map(|a| a.as_str()).unwrap_or("");
+		if self.exclude_addresses.contains(address) {
+			return Err(format!("excluded address {}", address));
+		}
+		if self.addresses.as_ref().map(|a| !a.contains(address)).unwrap_or(false) {
+			return Err(format!("address {} not allowed", address));
+		}
+
+		let label = info.label.as_ref().map(|l| l.as_str()).unwrap_or("");
+		if self.exclude_labels.contains(label) {
+			return Err(format!("excluded label '{}'", label));
+		}
+		if self.labels.as_ref().map(|l| !l.contains(label)).unwrap_or(false) {
+			return Err(format!("label '{}' not allowed", label));
+		}
+
+		let (script_type, _) = utxo.script_type();
+		if self.script_types.as_ref().map(|t| !t.contains(&script_type)).unwrap_or(false) {
+			return Err(format!("script type {} not allowed", script_type));
+		}
+		Ok(())
+	}
+
+	/// Whether any filters are set other than the defaults.
+	pub fn is_set(&self) -> bool {
+		self.min_confirmations != DEFAULT_MIN_CONFIRMATIONS
+			|| self.max_confirmations.is_some()
+			|| self.min_value != 0
+			|| self.addresses.is_some()
+			|| !self.exclude_addresses.is_empty()
+			|| self.labels.is_some()
+			|| !self.exclude_labels.is_empty()
+			|| self.script_types.is_some()
+			|| self.max_inputs.is_some()
+	}
 }
 
-pub fn fetch_utxos(command: &clap::ArgMatches) -> Vec<bitcoin::UTXO> {
+pub fn fetch_utxos(
+	command: &clap::ArgMatches,
+) -> Vec<(bitcoin::UTXO, backend::bitcoind::WalletInfo)> {
 	// currently only bitcoind
-	if let Some(mut bitcoind) = backend::bitcoind::Backend::load(command) {
-		bitcoind.fetch_utxos()
-	} else {
+	let wallets = backend::bitcoind::Backend::load_wallets(command);
+	if wallets.is_empty() {
 		panic!("No argument provided with which we can fetch UTXOs!")
 	}
+
+	let mut utxos = Vec::new();
+	for mut bitcoind in wallets.into_iter() {
+		let fetched = bitcoind.fetch_utxos();
+		if let Some(wallet) = bitcoind.wallet() {
+			println!("Retrieved {} UTXOs from wallet '{}'", fetched.len(), wallet);
+		}
+		utxos.extend(fetched);
+	}
+	utxos
 }
 
-/// Execute the fetch-utxos command.
-pub fn execute(ctx: &mut context::Ctx) {
-	let mut pf = ctx.load_proof_file();
+/// Fetch the UTXOs of the keys of an xpub by scanning the UTXO set.  For both the receive and
+/// the change chain, keys are derived until `gap_limit` consecutive keys have never been used.
+/// Keys that received coins before count as used, even if their coins have been spent.
+///
+/// The UTXO set is scanned at the chain tip.  When the tip is past the given proof block, the
+/// coins that have been spent since the proof block are recovered from the blocks after it.
+/// Also returns whether any key of the xpub has been used.
+pub fn fetch_xpub_utxos(
+	command: &clap::ArgMatches,
+	xpub: &hd::OriginXpub,
+	script_type: hd::KeyScriptType,
+	gap_limit: u32,
+	block_number: u32,
+) -> (Vec<(bitcoin::UTXO, backend::bitcoind::WalletInfo)>, bool) {
+	if gap_limit == 0 {
+		panic!("The gap limit must be positive.");
+	}
+	let mut bitcoind = backend::bitcoind::Backend::load(command)
+		.expect("scanning an xpub requires a bitcoind backend");
 
-	let proof_id = ctx.command().value_of("id").expect("no proof identifier given");
+	// A ranged descriptor for the first keys of each chain.
+	let descriptors = |ends: &[u32; 2]| -> Vec<Value> {
+		(0..2)
+			.map(|chain| {
+				let key = format!("{}/{}/*", xpub.xpub, chain);
+				json!({ "desc": script_type.descriptor(&key), "range": ends[chain] - 1 })
+			}).collect()
+	};
 
-	let mut proof = pf
-		.take_proof(proof_id)
-		.or_else(|| Some(bitcoin::Proof::new(proof_id.to_owned(), Proof_Status::GATHERING_UTXOS)))
-		.unwrap();
+	// Extend the chains until the last used key of each chain is followed by the gap limit.
+	let mut keys = HashMap::new();
+	let mut used = false;
+	let mut ends = [0, 0];
+	let mut next_ends = [gap_limit, gap_limit];
+	while next_ends != ends {
+		for chain in 0..2 {
+			for index in ends[chain]..next_ends[chain] {
+				let path = hd::parse_path(&format!("{}/{}", chain, index)).unwrap();
+				let (pubkey, origin) = xpub.derive(&path).unwrap_or_else(|e| panic!("{}", e));
+				let (script, redeem_script) = script_type.scripts(&pubkey.serialize());
+				keys.insert(script, (chain, index, pubkey, origin, redeem_script));
+			}
+		}
+		ends = next_ends;
 
-	let utxos = fetch_utxos(ctx.command());
-	println!("Retrieved {} UTXOs from source", utxos.len());
+		let scripts = keys.keys().cloned().collect();
+		for script in bitcoind.used_scripts(descriptors(&ends), &scripts).iter() {
+			let (chain, index) = (keys[script].0, keys[script].1);
+			next_ends[chain] = cmp::max(next_ends[chain], index + 1 + gap_limit);
+			used = true;
+		}
+	}
 
-	// Add the UTXOs to the proof.
-	let len_before = proof.utxos.len();
+	let mut outputs = bitcoind.scan_utxo_set(descriptors(&ends));
+	if block_number != 0 && bitcoind.tip_height() > block_number {
+		let scripts = keys.keys().cloned().collect();
+		outputs.extend(bitcoind.spent_outputs(descriptors(&ends), &scripts, block_number + 1));
+	}
+
+	let mut utxos = Vec::new();
+	for output in outputs.into_iter() {
+		let (_, _, pubkey, origin, redeem_script) = match keys.get(&output.txout.script_pubkey) {
+			Some(key) => key.clone(),
+			None => panic!("bitcoind returned an unexpected output {}", output.point),
+		};
+		let mut utxo = bitcoind.scanned_utxo(output);
+		utxo.psbt_input.redeem_script = redeem_script;
+		utxo.psbt_input.hd_keypaths.insert(pubkey, (origin.fingerprint, origin.path));
+		let info = backend::bitcoind::WalletInfo {
+			spendable: true,
+			..Default::default()
+		};
+		utxos.push((utxo, info));
+	}
+	(utxos, used)
+}
+
+/// Fetch the UTXOs of the accounts on a Trezor device by scanning the UTXO set for the keys of
+/// the account xpubs, like `fetch_xpub_utxos`.  Without a given account, accounts are scanned
+/// from account 0 until an account whose keys have never been used is found.
+pub fn fetch_trezor_utxos(
+	ctx: &context::Ctx,
+	script_types: &[hd::KeyScriptType],
+	account: Option<u32>,
+	gap_limit: u32,
+	block_number: u32,
+) -> Vec<(bitcoin::UTXO, backend::bitcoind::WalletInfo)> {
+	let mut trezor = backend::trezor::Backend::load(ctx.command()).expect("no Trezor device");
+	let network = bitcoin::network(ctx.network());
+
+	let mut utxos = Vec::new();
+	for script_type in script_types.iter() {
+		let mut next_account = account.unwrap_or(0);
+		loop {
+			let xpub = trezor.account_xpub(*script_type, network, next_account);
+			let (found, used) =
+				fetch_xpub_utxos(ctx.command(), &xpub, *script_type, gap_limit, block_number);
+			println!("Found {} UTXOs in {} account {}", found.len(), script_type, next_account);
+			let done = account.is_some() || !used;
+			utxos.extend(found);
+			if done {
+				break;
+			}
+			next_account += 1;
+		}
+	}
+	utxos
+}
+
+pub fn fetch_liquid_utxos(command: &clap::ArgMatches) -> Vec<liquid::UTXO> {
+	// currently only elementsd
+	if let Some(mut elementsd) = backend::elementsd::Backend::load(command) {
+		elementsd.fetch_utxos()
+	} else {
+		panic!("No argument provided with which we can fetch Liquid UTXOs!")
+	}
+}
+
+/// Add the UTXOs that are not yet present to the proof and return how many were added.
+fn add_new_utxos<U: Debug, P: PartialEq, F: Fn(&U) -> P>(
+	proof_utxos: &mut Vec<U>,
+	utxos: Vec<U>,
+	point: F,
+) -> usize {
+	let len_before = proof_utxos.len();
 	for utxo in utxos.into_iter() {
 		// Add if not yet present.
-		if proof.utxos.iter().find(|u| u.point == utxo.point).is_some() {
+		if proof_utxos.iter().find(|u| point(u) == point(&utxo)).is_some() {
 			continue;
 		}
 
 		debug!("Adding UTXO: {:?}", utxo);
-		proof.utxos.push(utxo);
+		proof_utxos.push(utxo);
+	}
+	proof_utxos.len() - len_before
+}
+
+/// Check whether a fetched UTXO can be added to the proof: it has to be confirmed at the proof
+/// block and it can't be used in any other proof.
+fn usable<P: Display>(pf: &ProofFile, point: &P, block_number: u32, other: Option<&str>) -> bool {
+	if pf.block_number != 0 && block_number > pf.block_number {
+		println!("Skipping UTXO {}: confirmed after the proof block.", point);
+		false
+	} else if let Some(other) = other {
+		println!("Skipping UTXO {}: already used in proof '{}'.", point, other);
+		false
+	} else {
+		true
+	}
+}
+
+/// Apply the filter to the fetched UTXOs.  The UTXOs that are excluded are printed in a report.
+fn filter_utxos(
+	pf: &ProofFile,
+	proof: &bitcoin::Proof,
+	utxos: Vec<(bitcoin::UTXO, backend::bitcoind::WalletInfo)>,
+	filter: &UtxoFilter,
+	height: u32,
+) -> Vec<bitcoin::UTXO> {
+	let mut included = Vec::new();
+	let mut excluded = Vec::new();
+	for (utxo, info) in utxos.into_iter() {
+		let result = if pf.block_number != 0 && utxo.block_number > pf.block_number {
+			Err("confirmed after the proof block".to_owned())
+		} else if let Some(other) = pf.find_bitcoin_utxo(&utxo.point) {
+			Err(format!("already used in proof '{}'", other))
+		} else {
+			filter.check(&utxo, &info, height)
+		};
+		match result {
+			Ok(()) => included.push(utxo),
+			Err(reason) => excluded.push((utxo, reason)),
+		}
 	}
-	let added = proof.utxos.len() - len_before;
+
+	// Keep the largest UTXOs if there are more than the maximum number of inputs.
+	if let Some(max) = filter.max_inputs {
+		let in_proof = |u: &bitcoin::UTXO| proof.utxos.iter().any(|p| p.point == u.point);
+		let nb_new = included.iter().filter(|u| !in_proof(u)).count();
+		let room = max.saturating_sub(proof.utxos.len());
+		if nb_new > room {
+			included.sort_by_key(|u| -u.value().into_inner());
+			let mut nb_kept = 0;
+			let (kept, dropped): (Vec<_>, Vec<_>) = included.into_iter().partition(|u| {
+				if in_proof(u) {
+					true
+				} else {
+					nb_kept += 1;
+					nb_kept <= room
+				}
+			});
+			included = kept;
+			let reason = "maximum number of inputs";
+			excluded.extend(dropped.into_iter().map(|u| (u, reason.to_owned())));
+		}
+	}
+
+	if !excluded.is_empty() {
+		let mut total = 0;
+		println!("Excluded {} UTXOs:", excluded.len());
+		for (utxo, reason) in excluded.iter() {
+			let value = utxo.value().into_inner();
+			total += value;
+			println!("  {} ({} satoshis): {}", utxo.point, value, reason);
+		}
+		println!("Total value of the excluded UTXOs: {} satoshis", total);
+	}
+	included
+}
+
+/// Execute the fetch-utxos command.
+pub fn execute(ctx: &mut context::Ctx) {
+	let mut pf = ctx.load_proof_file();
+
+	let proof_id = ctx.command().value_of("id").expect("no proof identifier given");
+
+	let mut proof = common::take_editable_proof(&mut pf, proof_id);
+
+	let filter = UtxoFilter::from_matches(ctx.command());
+
+	// Add the UTXOs to the proof.
+	let added = match proof {
+		Proof::Bitcoin(ref mut proof) => {
+			let utxos = match ctx.command().value_of("xpub") {
+				Some(xpub) => {
+					let xpub = xpub.parse().unwrap_or_else(|e| panic!("Invalid xpub: {}", e));
+					let script_type = ctx.command().value_of("xpub-script-type").unwrap().parse();
+					let gap_limit = ctx.command().value_of("gap-limit").unwrap().parse();
+					fetch_xpub_utxos(
+						ctx.command(),
+						&xpub,
+						script_type.unwrap_or_else(|e| panic!("{}", e)),
+						gap_limit.expect("invalid gap limit"),
+						pf.block_number,
+					).0
+				}
+				None if ctx.command().is_present("trezor") => {
+					let script_types = match ctx.command().value_of("xpub-script-type") {
+						Some(t) => vec![t.parse().unwrap_or_else(|e| panic!("{}", e))],
+						None => vec![
+							hd::KeyScriptType::P2pkh,
+							hd::KeyScriptType::P2shP2wpkh,
+							hd::KeyScriptType::P2wpkh,
+						],
+					};
+					let account = ctx.command().value_of("account");
+					let gap_limit = ctx.command().value_of("gap-limit").unwrap().parse();
+					fetch_trezor_utxos(
+						ctx,
+						&script_types,
+						account.map(|a| a.parse().expect("invalid account")),
+						gap_limit.expect("invalid gap limit"),
+						pf.block_number,
+					)
+				}
+				None => fetch_utxos(ctx.command()),
+			};
+			println!("Retrieved {} UTXOs from source", utxos.len());
+			let height = if pf.block_number != 0 {
+				pf.block_number
+			} else {
+				let mut bitcoind = backend::bitcoind::Backend::load(ctx.command())
+					.expect("no bitcoind backend given");
+				bitcoind.tip_height()
+			};
+			let utxos = filter_utxos(&pf, proof, utxos, &filter, height);
+			add_new_utxos(&mut proof.utxos, utxos, |u| u.point)
+		}
+		Proof::Liquid(ref mut proof) => {
+			let command = ctx.command();
+			if filter.is_set() || command.is_present("xpub") || command.is_present("trezor") {
+				panic!("UTXO filters and key scanning are not supported for Liquid proofs yet.");
+			}
+			let mut utxos = fetch_liquid_utxos(ctx.command());
+			println!("Retrieved {} UTXOs from source", utxos.len());
+			utxos.retain(|u| usable(&pf, &u.point, u.block_number, pf.find_liquid_utxo(&u.point)));
+			add_new_utxos(&mut proof.utxos, utxos, |u| u.point)
+		}
+	};
 	println!("Added {} UTXOs to the proof", added);
+	if added > 0 {
+		common::finish_utxo_changes(&pf.challenge, &mut proof);
+	}
 
 	pf.proofs.insert(0, proof);
 	ctx.save_proof_file(pf);
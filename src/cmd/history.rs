@@ -0,0 +1,135 @@
+use std::collections::BTreeMap;
+
+use clap;
+use serde_json::Value;
+
+use backend;
+use cmd::verify;
+use context;
+use history::{self, Attestation, History};
+use utils;
+
+/// Create the CLI argument for the history directory.
+pub fn history_dir_arg<'a>() -> clap::Arg<'a, 'a> {
+	clap::Arg::with_name("history-dir")
+		.long("history-dir")
+		.help("the directory with the attestation history")
+		.takes_value(true)
+		.env("RESERVES_HISTORY_DIR")
+		.default_value("reserves-history")
+}
+
+/// Create the history subcommand.
+pub fn subcommand<'a>() -> clap::App<'a, 'a> {
+	clap::SubCommand::with_name("history")
+		.about("list the archived attestations or re-verify an archived proof file")
+		.args(&backend::bitcoind::args())
+		.args(&backend::elementsd::args())
+		.arg(verify::auditor_key_arg())
+		.arg(history_dir_arg())
+		.args(&[
+			clap::Arg::with_name("last")
+				.long("last")
+				.help("only list the last N attestations")
+				.takes_value(true),
+			clap::Arg::with_name("format")
+				.long("format")
+				.help("the output format of the list")
+				.takes_value(true)
+				.possible_values(&["text", "json"])
+				.default_value("text"),
+			clap::Arg::with_name("reverify")
+				.long("reverify")
+				.help("verify the archived proof file with the given name again")
+				.takes_value(true),
+		])
+}
+
+/// The change of the total per asset compared to the previous attestation.
+fn total_changes(a: &Attestation, prev: Option<&Attestation>) -> BTreeMap<String, i64> {
+	a.totals
+		.iter()
+		.map(|(asset, amount)| {
+			let prev = prev.and_then(|p| p.totals.get(asset)).cloned().unwrap_or(0);
+			(asset.clone(), *amount as i64 - prev as i64)
+		}).collect()
+}
+
+fn print_text(list: &[(&Attestation, Option<&Attestation>)]) {
+	for &(a, prev) in list.iter() {
+		let utxo_change = a.nb_utxos as i64 - prev.map(|p| p.nb_utxos as i64).unwrap_or(0);
+		println!(
+			"{}  block {}  {} UTXOs ({:+})  {}",
+			utils::utc_date(a.archived_at),
+			a.block_number,
+			a.nb_utxos,
+			utxo_change,
+			a.file
+		);
+		let changes = total_changes(a, prev);
+		for (asset, amount) in a.totals.iter() {
+			println!("  {}: {} ({:+})", asset, amount, changes[asset]);
+		}
+	}
+}
+
+fn print_json(list: &[(&Attestation, Option<&Attestation>)]) {
+	let list = list.iter().map(|&(a, prev)| {
+		let mut json = a.to_json();
+		json["date"] = utils::utc_date(a.archived_at).into();
+		json["utxo_change"] =
+			(a.nb_utxos as i64 - prev.map(|p| p.nb_utxos as i64).unwrap_or(0)).into();
+		json["total_changes"] = json!(total_changes(a, prev));
+		json
+	});
+	println!("{}", Value::Array(list.collect()));
+}
+
+/// Verify the archived proof file again and compare the result with the archived result.
+fn reverify(ctx: &mut context::Ctx, history: &History, file: &str) {
+	let a = history
+		.attestations
+		.iter()
+		.find(|a| a.file == file)
+		.expect("No archived attestation found with the given file name");
+	let path = history.path(a);
+	if history::file_sha256(&path) != a.sha256 {
+		panic!("The archived proof file '{}' was modified!", path.display());
+	}
+
+	let pf = ctx.load_other_proof_file(path.to_str().unwrap());
+	let reserves = verify::verify_reserves(ctx.command(), &pf);
+	if reserves.nb_utxos != a.nb_utxos || reserves.totals(pf.network) != a.totals {
+		panic!("The verification result of '{}' differs from the archived result!", file);
+	}
+	println!("Verified '{}' again: the result matches the archived result.", file);
+}
+
+/// Execute the history command.
+pub fn execute(ctx: &mut context::Ctx) {
+	let history = History::open(ctx.command().value_of("history-dir").unwrap());
+
+	if let Some(file) = ctx.command().value_of("reverify") {
+		reverify(ctx, &history, file);
+		return;
+	}
+
+	let mut sorted: Vec<&Attestation> = history.attestations.iter().collect();
+	sorted.sort_by_key(|a| (a.block_number, a.archived_at));
+	let mut list: Vec<_> = sorted
+		.iter()
+		.enumerate()
+		.map(|(idx, a)| (*a, if idx > 0 { Some(sorted[idx - 1]) } else { None }))
+		.collect();
+	if let Some(last) = ctx.command().value_of("last") {
+		let last: usize = last.parse().expect("invalid number for --last");
+		let skip = list.len().saturating_sub(last);
+		list.drain(..skip);
+	}
+
+	match ctx.command().value_of("format").unwrap() {
+		"json" => print_json(&list),
+		_ if list.is_empty() => println!("No attestations archived yet."),
+		_ => print_text(&list),
+	}
+}
@@ -0,0 +1,94 @@
+use std::fs;
+
+use base64;
+use clap;
+use hex;
+use rbitcoin::consensus::encode::deserialize;
+use rbitcoin::util::psbt;
+
+use backend;
+use bitcoin;
+use cmd::common;
+use cmd::import_utxos;
+use common::*;
+use context;
+
+/// The magic bytes of a binary PSBT.
+const PSBT_MAGIC: &'static [u8] = b"psbt\xff";
+
+/// Create the import-psbt subcommand.
+pub fn subcommand<'a>() -> clap::App<'a, 'a> {
+	clap::SubCommand::with_name("import-psbt")
+		.about("add the inputs of a PSBT to a proof")
+		.arg(common::id_arg())
+		// When bitcoind is given, the inputs are validated and the block metadata is looked up.
+		.args(&backend::bitcoind::args())
+		.arg(
+			clap::Arg::with_name("psbt")
+				.help("the PSBT file, in binary, base64 or hex format")
+				.takes_value(true)
+				.required(true),
+		)
+}
+
+/// Read a PSBT from a file in binary, base64 or hex format.
+fn read_psbt(path: &str) -> psbt::PartiallySignedTransaction {
+	let contents = fs::read(path).expect(&format!("error reading file at '{}'", path));
+	let bytes = if contents.starts_with(PSBT_MAGIC) {
+		contents
+	} else {
+		let text = String::from_utf8(contents).expect("the PSBT file is not binary nor text");
+		let text = text.trim();
+		hex::decode(text)
+			.or_else(|_| base64::decode(text))
+			.expect("the PSBT file is not in binary, base64 or hex format")
+	};
+	deserialize(&bytes).expect("invalid PSBT")
+}
+
+/// Execute the import-psbt command.
+pub fn execute(ctx: &mut context::Ctx) {
+	let mut pf = ctx.load_proof_file();
+	let proof_id = ctx.command().value_of("id").expect("no proof identifier given");
+
+	if pf.network == Network::LIQUID {
+		panic!("Importing PSBTs into Liquid proofs is not supported, use fetch-utxos instead.");
+	}
+
+	let psbt = read_psbt(ctx.command().value_of("psbt").unwrap());
+	let mut proof = common::take_editable_proof(&mut pf, proof_id);
+	let mut bitcoind = backend::bitcoind::Backend::load(ctx.command());
+
+	let txins = psbt.global.unsigned_tx.input.iter();
+	let utxos = txins.zip(psbt.inputs.into_iter()).map(|(txin, mut input)| {
+		// Signatures for the PSBT's transaction are not valid for the proof transaction.
+		input.partial_sigs.clear();
+		input.final_script_sig = None;
+		input.final_script_witness = None;
+		// The proof signatures have to commit to the challenge input and the outputs, so any
+		// other sighash type than the default SIGHASH_ALL is dropped.
+		input.sighash_type = None;
+
+		let mut utxo = bitcoin::UTXO {
+			point: txin.previous_output,
+			psbt_input: input,
+			block_number: 0,
+			block_hash: None,
+			wallet: None,
+		};
+		let result = if !utxo.has_txout() {
+			Err("the PSBT input has no UTXO data".into())
+		} else if let Some(ref mut bitcoind) = bitcoind {
+			bitcoind.validate_utxo(&mut utxo, pf.block_number).and_then(|_| utxo.check_scripts())
+		} else {
+			utxo.check_scripts()
+		};
+		(txin.previous_output.to_string(), result.map(|_| utxo))
+	});
+	let utxos: Vec<_> = utxos.collect();
+	import_utxos::add_utxos(&pf, &mut proof, utxos);
+	common::finish_utxo_changes(&pf.challenge, &mut proof);
+
+	pf.proofs.insert(0, proof);
+	ctx.save_proof_file(pf);
+}
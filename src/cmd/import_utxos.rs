@@ -0,0 +1,183 @@
+use std::collections::HashSet;
+use std::fs;
+
+use clap;
+use serde_json::{self, Value};
+
+use backend;
+use bitcoin;
+use cmd::add_utxo::UtxoFields;
+use cmd::common;
+use common::*;
+use context;
+
+/// Create the import-utxos subcommand.
+pub fn subcommand<'a>() -> clap::App<'a, 'a> {
+	clap::SubCommand::with_name("import-utxos")
+		.about("add UTXOs to a proof in bulk from a CSV or JSON file")
+		.arg(common::id_arg())
+		.args(&backend::bitcoind::args())
+		.args(&[
+			clap::Arg::with_name("file")
+				.help("the file with the UTXOs, with the add-utxo argument names as columns")
+				.takes_value(true)
+				.required(true),
+			clap::Arg::with_name("format")
+				.long("format")
+				.help("the format of the file [default: from the file extension]")
+				.takes_value(true)
+				.possible_values(&["csv", "json"]),
+		])
+}
+
+/// Set the field with the given name, as used for the add-utxo arguments.
+/// Underscores are accepted instead of dashes.
+fn set_field(fields: &mut UtxoFields, name: &str, value: String) -> Result<(), String> {
+	let value = if value.is_empty() { None } else { Some(value) };
+	match name.trim().replace('_', "-").as_str() {
+		"outpoint" => fields.outpoint = value.unwrap_or_default(),
+		"previous-tx" => fields.previous_tx = value,
+		"previous-output" => fields.previous_output = value,
+		"redeem-script" => fields.redeem_script = value,
+		"witness-script" => fields.witness_script = value,
+		"xpub" => fields.xpub = value,
+		"hd-keypath" => fields.hd_keypath = value,
+		"fingerprint" => fields.fingerprint = value,
+		"block-number" => fields.block_number = value,
+		"block-hash" => fields.block_hash = value,
+		_ => return Err(format!("unknown field: {}", name)),
+	}
+	Ok(())
+}
+
+/// Read the rows of a CSV file.  The first line holds the column names.
+fn read_csv(contents: &str) -> Vec<Result<UtxoFields, String>> {
+	let mut lines = contents.lines().map(|l| l.trim()).filter(|l| !l.is_empty());
+	let columns: Vec<&str> = match lines.next() {
+		Some(header) => header.split(',').map(|c| c.trim()).collect(),
+		None => return Vec::new(),
+	};
+	if !columns.contains(&"outpoint") {
+		panic!("The CSV header has no outpoint column.");
+	}
+
+	lines
+		.map(|line| {
+			let values: Vec<&str> = line.split(',').map(|v| v.trim()).collect();
+			if values.len() != columns.len() {
+				return Err(format!("expected {} columns, found {}", columns.len(), values.len()));
+			}
+			let mut fields = UtxoFields::default();
+			for (column, value) in columns.iter().zip(values.into_iter()) {
+				set_field(&mut fields, column, value.trim_matches('"').to_owned())?;
+			}
+			Ok(fields)
+		}).collect()
+}
+
+/// Read the rows of a JSON file holding an array of objects.
+fn read_json(contents: &str) -> Vec<Result<UtxoFields, String>> {
+	let rows = match serde_json::from_str(contents).expect("invalid JSON") {
+		Value::Array(rows) => rows,
+		_ => panic!("The JSON file must contain an array of UTXO objects."),
+	};
+
+	rows.into_iter()
+		.map(|row| {
+			let row = match row {
+				Value::Object(row) => row,
+				_ => return Err("not a JSON object".into()),
+			};
+			let mut fields = UtxoFields::default();
+			for (key, value) in row.into_iter() {
+				let value = match value {
+					Value::String(s) => s,
+					Value::Number(n) => n.to_string(),
+					Value::Null => String::new(),
+					v => return Err(format!("invalid value for {}: {}", key, v)),
+				};
+				set_field(&mut fields, &key, value)?;
+			}
+			Ok(fields)
+		}).collect()
+}
+
+/// Add the UTXOs to the proof, skipping invalid and duplicate UTXOs and UTXOs that are used in
+/// other proofs.  The UTXOs are named for the report of the skipped ones.
+pub fn add_utxos(
+	pf: &ProofFile,
+	proof: &mut Proof,
+	utxos: Vec<(String, Result<bitcoin::UTXO, String>)>,
+) {
+	let mut seen: HashSet<_> = proof.bitcoin().utxos.iter().map(|u| u.point).collect();
+	let mut skipped = Vec::new();
+	let mut nb_added = 0;
+	for (name, utxo) in utxos.into_iter() {
+		let utxo = match utxo {
+			Ok(u) => u,
+			Err(e) => {
+				skipped.push((name, e));
+				continue;
+			}
+		};
+		if !seen.insert(utxo.point) {
+			skipped.push((name, "duplicate UTXO".into()));
+			continue;
+		}
+		if let Some(other) = pf.find_bitcoin_utxo(&utxo.point) {
+			skipped.push((name, format!("already used in proof '{}'", other)));
+			continue;
+		}
+
+		debug!("Adding new UTXO to proof: {:?}", utxo);
+		proof.bitcoin_mut().utxos.push(utxo);
+		nb_added += 1;
+	}
+
+	println!("Added {} UTXOs to proof '{}'.", nb_added, proof.id());
+	if !skipped.is_empty() {
+		println!("Skipped {} UTXOs:", skipped.len());
+		for (name, reason) in skipped.into_iter() {
+			println!("  {}: {}", name, reason);
+		}
+	}
+}
+
+/// Execute the import-utxos command.
+pub fn execute(ctx: &mut context::Ctx) {
+	let mut pf = ctx.load_proof_file();
+	let proof_id = ctx.command().value_of("id").expect("no proof identifier given");
+
+	if pf.network == Network::LIQUID {
+		panic!("Importing Liquid UTXOs is not supported yet, use fetch-utxos instead.");
+	}
+
+	let path = ctx.command().value_of("file").unwrap();
+	let contents = fs::read_to_string(path).expect(&format!("error reading file at '{}'", path));
+	let format = match ctx.command().value_of("format") {
+		Some(f) => f,
+		None if path.ends_with(".json") => "json",
+		None if path.ends_with(".csv") => "csv",
+		None => panic!("Unknown file format, use --format."),
+	};
+	let rows = match format {
+		"json" => read_json(&contents),
+		_ => read_csv(&contents),
+	};
+
+	let mut proof = common::take_editable_proof(&mut pf, proof_id);
+	let mut bitcoind = backend::bitcoind::Backend::load(ctx.command());
+	let utxos = rows.into_iter().enumerate().map(|(idx, row)| match row {
+		Ok(fields) => (
+			format!("row #{} ({})", idx + 1, fields.outpoint),
+			fields.build(bitcoind.as_mut(), pf.block_number),
+		),
+		Err(e) => (format!("row #{}", idx + 1), Err(e)),
+	});
+	let utxos: Vec<_> = utxos.collect();
+	add_utxos(&pf, &mut proof, utxos);
+	common::finish_utxo_changes(&pf.challenge, &mut proof);
+
+	pf.proofs.insert(0, proof);
+	ctx.save_proof_file(pf);
+}
@@ -1,7 +1,12 @@
 use clap;
+use hex;
 
+use backend;
+use challenge::Challenge;
 use common::*;
 use context;
+use protos;
+use utils;
 
 /// Create the init subcommand.
 pub fn subcommand<'a>() -> clap::App<'a, 'a> {
@@ -13,15 +18,74 @@ pub fn subcommand<'a>() -> clap::App<'a, 'a> {
 				.short("c")
 				.help("the challenge string")
 				.takes_value(true)
-				.required(true),
+				.required_unless("challenge-file"),
+		).arg(
+			clap::Arg::with_name("challenge-file")
+				.long("challenge-file")
+				.help("use the challenge from a challenge file issued by an auditor")
+				.takes_value(true)
+				.conflicts_with_all(&["challenge", "liabilities-root", "block-hash", "nonce"]),
 		).arg(
 			clap::Arg::with_name("block-number")
 				.long("block-number")
 				.short("b")
-				.help("the block number the proofs are to be valid at")
+				.help("the block number the proofs are to be valid at [default: from the backend]")
 				.takes_value(true)
 				.required(false),
-		)
+		).arg(
+			clap::Arg::with_name("end-block")
+				.long("end-block")
+				.help("claim that the UTXOs stay unspent from the proof block up to this block")
+				.takes_value(true),
+		).arg(
+			clap::Arg::with_name("confirmations")
+				.long("confirmations")
+				.help("use the block this many blocks below the tip when fetching the block")
+				.takes_value(true)
+				.default_value("0"),
+		).args(&backend::bitcoind::args())
+		.args(&backend::elementsd::args())
+		.args(&[
+			// Fields for a structured challenge.
+			clap::Arg::with_name("liabilities-root")
+				.long("liabilities-root")
+				.help("commit to the liabilities Merkle root (hex) in the challenge")
+				.takes_value(true),
+			clap::Arg::with_name("block-hash")
+				.long("block-hash")
+				.help("commit to a recent block hash in the challenge to prove freshness")
+				.takes_value(true),
+			clap::Arg::with_name("nonce")
+				.long("nonce")
+				.help("commit to the auditor's nonce (hex) in the challenge")
+				.takes_value(true),
+		])
+}
+
+/// Fetch the number and hash of the proof block from the backend, if one is given.
+/// Without a block number, the block `confirmations` blocks below the tip is used.
+fn fetch_block(ctx: &context::Ctx, block_number: Option<u32>) -> Option<(u32, String)> {
+	let matches = ctx.command();
+	let confirmations: u32 =
+		matches.value_of("confirmations").unwrap().parse().expect("invalid confirmations");
+	let target = |tip: u32| {
+		block_number.unwrap_or_else(|| {
+			tip.checked_sub(confirmations).expect("more confirmations than blocks in the chain")
+		})
+	};
+
+	match ctx.network() {
+		Network::BITCOIN_MAINNET | Network::BITCOIN_TESTNET => {
+			backend::bitcoind::Backend::load(matches).map(|mut bitcoind| {
+				let height = target(bitcoind.tip_height());
+				(height, bitcoind.block_hash_at(height))
+			})
+		}
+		Network::LIQUID => backend::elementsd::Backend::load(matches).map(|mut elementsd| {
+			let height = target(elementsd.tip_height());
+			(height, elementsd.block_hash_at(height))
+		}),
+	}
 }
 
 /// Execute the init command.
@@ -29,16 +93,76 @@ pub fn execute(ctx: &mut context::Ctx) {
 	let mut p = ProofFile::new(ctx.network());
 	p.version = 1;
 
-	match ctx.command().value_of("challenge") {
-		None => panic!("challenge not provided"),
-		Some("") => panic!("empty challenge is not allowed"),
-		Some(challenge) => {
-			p.challenge = String::from(challenge);
-		}
-	};
+	if let Some(path) = ctx.command().value_of("challenge-file") {
+		p.challenge = utils::read_proto_file::<protos::Challenge>(path).into();
+		let auditor_key = p
+			.challenge
+			.auditor_key()
+			.unwrap_or_else(|e| panic!("Invalid challenge file: {}", e));
+		println!(
+			"Using challenge issued by auditor '{}' with key {}",
+			p.challenge.auditor.as_ref().map(|a| a.as_str()).unwrap_or("(unknown)"),
+			hex::encode(&auditor_key),
+		);
+	} else {
+		match ctx.command().value_of("challenge") {
+			None => panic!("challenge not provided"),
+			Some("") => panic!("empty challenge is not allowed"),
+			Some(message) => {
+				p.challenge = Challenge::new(String::from(message));
+			}
+		};
+	}
 
+	if let Some(root) = ctx.command().value_of("liabilities-root") {
+		let root = utils::parse_liabilities_root(root)
+			.unwrap_or_else(|e| panic!("Invalid liabilities root: {}", e));
+		p.challenge.liabilities_root = Some(root);
+	}
+	if let Some(hash) = ctx.command().value_of("block-hash") {
+		p.challenge.block_hash =
+			Some(utils::parse_block_hash(hash).expect("invalid challenge block hash"));
+	}
+	if let Some(nonce) = ctx.command().value_of("nonce") {
+		p.challenge.nonce = Some(hex::decode(nonce).expect("invalid hex nonce"));
+	}
+
+	let mut block_number = p.challenge.target_block;
 	if let Some(bn) = ctx.command().value_of("block-number") {
-		p.block_number = bn.parse().expect("failed to parse block number");
+		let bn = bn.parse().expect("failed to parse block number");
+		if block_number.map(|t| t != bn).unwrap_or(false) {
+			panic!("The block number differs from the target block of the challenge.");
+		}
+		block_number = Some(bn);
+	}
+
+	match fetch_block(ctx, block_number) {
+		Some((height, hash)) => {
+			println!("Using block {} with hash {}", height, hash);
+			p.block_number = height;
+			p.block_hash = Some(utils::parse_block_hash(&hash).expect("invalid block hash"));
+		}
+		None => p.block_number = block_number.unwrap_or(0),
+	}
+
+	// The end block is committed to in the challenge.  A signed challenge can't be changed.
+	let mut end_block = p.challenge.end_block;
+	if let Some(end) = ctx.command().value_of("end-block") {
+		let end = end.parse().expect("failed to parse end block number");
+		if p.challenge.auditor_signature.is_some() && end_block != Some(end) {
+			panic!("The end block differs from the end block of the signed challenge.");
+		}
+		end_block = Some(end);
+	}
+	if let Some(end) = end_block {
+		if p.network == Network::LIQUID {
+			panic!("Block ranges are not supported for Liquid proofs yet.");
+		}
+		if end < p.block_number {
+			panic!("The end block of the range is before the proof block.");
+		}
+		p.challenge.end_block = Some(end);
+		p.end_block_number = Some(end);
 	}
 
 	debug!("Creating proof file: {:?}", &p);
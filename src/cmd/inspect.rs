@@ -1,10 +1,13 @@
 use clap;
+use elements;
 use hex;
 use rbitcoin::consensus::encode as btc_encode;
 
 use bitcoin;
 use common::*;
 use context;
+use liquid;
+use provisions;
 use utils;
 
 /// Create the verify subcommand.
@@ -25,6 +28,9 @@ fn print_outputs(pre: &str, proof: &bitcoin::Proof) {
 			pre,
 			u.block_hash.map(|h| h.be_hex_string()).or_else(|| Some("unknown".to_owned())).unwrap()
 		);
+		if let Some(ref wallet) = u.wallet {
+			println!("{}  wallet: {}", pre, wallet);
+		}
 
 		if idx != nb_utxos - 1 {
 			println!("");
@@ -32,6 +38,88 @@ fn print_outputs(pre: &str, proof: &bitcoin::Proof) {
 	}
 }
 
+fn print_liquid_outputs(pre: &str, proof: &liquid::Proof) {
+	let nb_utxos = proof.utxos.len();
+	println!("{}{} UTXOs:", pre, nb_utxos);
+	for (idx, u) in proof.utxos.iter().enumerate() {
+		println!("{}  outpoint: {}", pre, u.point);
+		if u.is_confidential() && u.unblinding.is_none() {
+			println!("{}  confidential (no unblinding data)", pre);
+		} else {
+			let (asset, value) = u.asset_value();
+			println!("{}  asset: {}", pre, liquid::asset_name(&asset));
+			println!("{}  value: {}", pre, value);
+			if u.is_confidential() {
+				println!("{}  confidential (unblinded)", pre);
+			}
+		}
+		info!("PSET input: {:?}", u.pset_input);
+		println!("{}  block number: {}", pre, u.block_number);
+		println!(
+			"{}  block hash: {}",
+			pre,
+			u.block_hash.map(|h| h.to_string()).unwrap_or_else(|| "unknown".to_owned())
+		);
+
+		if idx != nb_utxos - 1 {
+			println!("");
+		}
+	}
+}
+
+fn inspect_bitcoin_proof(proof: &bitcoin::Proof) {
+	match proof.status {
+		Proof_Status::UNDEFINED => {}
+		Proof_Status::FINAL => {
+			println!("  amount: {} satoshis", proof.amount());
+			let txs = proof.proof_txs();
+			if txs.len() == 1 {
+				println!("  raw proof tx: {}", hex::encode(btc_encode::serialize(txs[0])));
+			} else {
+				println!("  proof txs: {}", txs.len());
+				for (idx, tx) in txs.iter().enumerate() {
+					let raw = hex::encode(btc_encode::serialize(*tx));
+					println!("  raw proof tx #{}: {}", idx, raw);
+				}
+			}
+			info!("decoded proof txs: {:?}", txs);
+			print_outputs("  ", &proof);
+		}
+		Proof_Status::GATHERING_UTXOS => {
+			print_outputs("  ", &proof);
+		}
+		Proof_Status::SIGNING => {
+			println!("  signed chunks: {} of {}", proof.nb_signed_chunks(), proof.chunks.len());
+			print_outputs("  ", &proof);
+		}
+	}
+}
+
+fn inspect_liquid_proof(proof: &liquid::Proof) {
+	match proof.status {
+		Proof_Status::UNDEFINED => {}
+		Proof_Status::FINAL => {
+			let tx = proof.proof_tx.as_ref().unwrap();
+			println!("  amounts:");
+			for output in tx.output.iter() {
+				match (output.asset, output.value) {
+					(
+						elements::confidential::Asset::Explicit(asset),
+						elements::confidential::Value::Explicit(value),
+					) => println!("    {}: {}", liquid::asset_name(&asset), value),
+					_ => println!("    (confidential output)"),
+				}
+			}
+			println!("  raw proof tx: {}", hex::encode(elements::encode::serialize(tx)));
+			info!("decoded proof tx: {:?}", proof.proof_tx);
+			print_liquid_outputs("  ", &proof);
+		}
+		Proof_Status::GATHERING_UTXOS | Proof_Status::SIGNING => {
+			print_liquid_outputs("  ", &proof);
+		}
+	}
+}
+
 /// Execute the verify command.
 pub fn execute(ctx: &mut context::Ctx) {
 	let pf = ctx.load_proof_file();
@@ -39,36 +127,76 @@ pub fn execute(ctx: &mut context::Ctx) {
 	println!("version: {}", pf.version);
 	println!("network: {}", utils::network_name(pf.network));
 	println!("challenge: {}", pf.challenge);
+	if pf.challenge.is_structured() {
+		if let Some(ref root) = pf.challenge.liabilities_root {
+			println!("  liabilities root: {}", hex::encode(root));
+		}
+		if let Some(ref hash) = pf.challenge.block_hash {
+			println!("  block hash: {}", utils::block_hash_hex(hash));
+		}
+		if let Some(ref nonce) = pf.challenge.nonce {
+			println!("  nonce: {}", hex::encode(nonce));
+		}
+		if let Some(ref auditor) = pf.challenge.auditor {
+			println!("  auditor: {}", auditor);
+		}
+		if let Some(ref date) = pf.challenge.date {
+			println!("  date: {}", date);
+		}
+		if let Some(block) = pf.challenge.target_block {
+			println!("  target block: {}", block);
+		}
+		if pf.challenge.auditor_signature.is_some() {
+			match pf.challenge.auditor_key() {
+				Ok(key) => println!("  signed by auditor key: {}", hex::encode(&key)),
+				Err(e) => println!("  auditor signature: {}", e),
+			}
+		}
+		println!("  challenge hash: {}", hex::encode(&pf.challenge.hash()));
+	}
 	println!("block number: {}", pf.block_number);
+	if let Some(ref hash) = pf.block_hash {
+		println!("block hash: {}", utils::block_hash_hex(hash));
+	}
+	if let Some(end) = pf.end_block_number {
+		println!("claimed block range: {} to {}", pf.block_number, end);
+	}
 
 	// Print all proofs:
 	let nb_proofs = pf.proofs.len();
 	println!("{} proof(s):", nb_proofs);
 	for (idx, proof) in pf.proofs.into_iter().enumerate() {
-		println!("  id: {}", proof.id);
-		println!("  status: {:?}", proof.status);
+		println!("  id: {}", proof.id());
+		println!("  status: {:?}", proof.status());
 
-		match proof.status {
-			Proof_Status::UNDEFINED => {}
-			Proof_Status::FINAL => {
-				let amount =
-					proof.proof_tx.as_ref().unwrap().output.iter().fold(0, |a, o| a + o.value);
-				println!("  amount: {} satoshis", amount);
-				println!("  raw proof tx: {}", hex::encode(btc_encode::serialize(&proof.proof_tx)));
-				info!("decoded proof tx: {:?}", proof.proof_tx);
-				print_outputs("  ", &proof);
-			}
-			Proof_Status::GATHERING_UTXOS => {
-				print_outputs("  ", &proof);
-			}
-			Proof_Status::SIGNING => {
-				//TODO(stevenroose)
-				print_outputs("  ", &proof);
-			}
+		match proof {
+			Proof::Bitcoin(ref p) => inspect_bitcoin_proof(p),
+			Proof::Liquid(ref p) => inspect_liquid_proof(p),
 		}
 
 		if idx != nb_proofs - 1 {
 			println!("");
 		}
 	}
+
+	if let Some(ref prov) = pf.provisions {
+		println!("");
+		println!("Provisions proof:");
+		println!("  anonymity set: {} keys", prov.keys.len());
+		println!(
+			"  total commitment: {}",
+			hex::encode(provisions::encode_point(&prov.total_commitment()))
+		);
+		if prov.range_proof.is_some() {
+			println!("  threshold: {} satoshis", prov.threshold);
+		}
+	}
+
+	if let Some(ref liabilities) = pf.liabilities {
+		println!("");
+		println!("Liabilities:");
+		println!("  root: {}", hex::encode(&liabilities.root));
+		println!("  total: {} satoshis", liabilities.total);
+		println!("  block number: {}", liabilities.block_number);
+	}
 }
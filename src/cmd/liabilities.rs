@@ -0,0 +1,181 @@
+use std::collections::HashSet;
+use std::fs;
+use std::path::Path;
+
+use clap;
+use hex;
+
+use common::*;
+use context;
+use liabilities;
+use protos;
+use utils;
+
+/// Create the CLI argument for the liabilities tree file.
+fn tree_file_arg<'a>() -> clap::Arg<'a, 'a> {
+	clap::Arg::with_name("tree-file")
+		.long("tree-file")
+		.help("the file holding the full liabilities tree (keep this private!)")
+		.takes_value(true)
+		.default_value("liabilities.tree")
+}
+
+/// Create the liabilities subcommand.
+pub fn subcommand<'a>() -> clap::App<'a, 'a> {
+	clap::SubCommand::with_name("liabilities")
+		.about("commit to the customer liabilities using a Merkle sum tree")
+		.setting(clap::AppSettings::SubcommandRequiredElseHelp)
+		.subcommand(
+			clap::SubCommand::with_name("build")
+				.about("build the liabilities tree from a CSV file and commit to it")
+				.arg(tree_file_arg())
+				.arg(
+					clap::Arg::with_name("csv")
+						.help("CSV file with lines of `<customer id>,<balance in satoshis>`")
+						.takes_value(true)
+						.required(true),
+				),
+		).subcommand(
+			clap::SubCommand::with_name("export-proofs")
+				.about("export the inclusion proofs for the customers")
+				.arg(tree_file_arg())
+				.args(&[
+					clap::Arg::with_name("customer")
+						.long("customer")
+						.help("only export the proof for this customer id")
+						.takes_value(true),
+					clap::Arg::with_name("output-dir")
+						.long("output-dir")
+						.help("the directory to write the proofs to")
+						.takes_value(true)
+						.default_value("."),
+				]),
+		)
+}
+
+/// Read the customer balances from a CSV file.
+/// A header line is allowed and skipped when the balance column is not a number.
+fn read_csv(path: &str) -> Vec<liabilities::Entry> {
+	let contents = fs::read_to_string(path).expect(&format!("error reading file at '{}'", path));
+	let mut customers = HashSet::new();
+	let mut entries = Vec::new();
+	for (idx, line) in contents.lines().enumerate() {
+		let line = line.trim();
+		if line.is_empty() {
+			continue;
+		}
+
+		let mut fields = line.rsplitn(2, ',');
+		let balance = fields.next().unwrap().trim();
+		let customer_id = match fields.next() {
+			Some(id) => id.trim().trim_matches('"'),
+			None => panic!("Invalid CSV line #{}: {}", idx + 1, line),
+		};
+		let balance = match balance.parse() {
+			Ok(b) => b,
+			Err(_) if idx == 0 => continue,
+			Err(_) => panic!("Invalid balance on CSV line #{}: {}", idx + 1, balance),
+		};
+
+		if !customers.insert(customer_id.to_owned()) {
+			panic!("Duplicate customer id in CSV: {}", customer_id);
+		}
+		entries.push(liabilities::Entry::new(customer_id.to_owned(), balance));
+	}
+	entries
+}
+
+fn build(ctx: &mut context::Ctx, matches: &clap::ArgMatches) {
+	let mut pf = ctx.load_proof_file();
+	if pf.block_number == 0 {
+		panic!("The proof file has no block number set.");
+	}
+
+	let tree = liabilities::Tree {
+		block_number: pf.block_number,
+		entries: read_csv(matches.value_of("csv").unwrap()),
+	};
+	let liabilities = tree.liabilities();
+	println!("Built liabilities tree for {} customers.", tree.entries.len());
+	println!("Root: {}", hex::encode(&liabilities.root));
+	println!("Total liabilities: {} satoshis", liabilities.total);
+
+	// Commit to the root in the challenge, as long as nothing commits to the challenge yet.
+	if pf.challenge.liabilities_root != Some(liabilities.root) {
+		// The auditor's signature covers the liabilities root.
+		let signed = pf.challenge.auditor_signature.is_some();
+		let committed = pf.provisions.is_some()
+			|| signed
+			|| pf.proofs.iter().any(|p| p.status() != Proof_Status::GATHERING_UTXOS);
+		if signed {
+			println!(
+				"Warning: the challenge can't commit to the new liabilities root because it is \
+				 signed by the auditor.  Ask the auditor for a new challenge file created with \
+				 --liabilities-root {}.",
+				hex::encode(&liabilities.root)
+			);
+		} else if committed {
+			println!(
+				"Warning: the challenge can't commit to the new liabilities root because some \
+				 proofs already commit to the challenge."
+			);
+		} else {
+			pf.challenge.liabilities_root = Some(liabilities.root);
+			println!("The challenge now commits to the liabilities root.");
+		}
+	}
+
+	if ctx.dry_run() {
+		println!("Dry-run: not writing liabilities tree to disk.");
+	} else {
+		let tree: protos::LiabilitiesTree = tree.into();
+		utils::write_proto_file(matches.value_of("tree-file").unwrap(), &tree);
+	}
+	pf.liabilities = Some(liabilities);
+	ctx.save_proof_file(pf);
+}
+
+/// Make a customer id safe to use as a file name.
+fn proof_file_name(customer_id: &str) -> String {
+	let name: String = customer_id
+		.chars()
+		.map(|c| if c.is_ascii_alphanumeric() || c == '-' || c == '_' { c } else { '_' })
+		.collect();
+	format!("{}.inclusion", name)
+}
+
+fn export_proofs(ctx: &mut context::Ctx, matches: &clap::ArgMatches) {
+	let tree: liabilities::Tree =
+		utils::read_proto_file::<protos::LiabilitiesTree>(matches.value_of("tree-file").unwrap())
+			.into();
+
+	let indices = match matches.value_of("customer") {
+		Some(customer) => {
+			vec![tree.find(customer).expect("No entry found for the given customer.")]
+		}
+		None => (0..tree.entries.len()).collect(),
+	};
+
+	let dir = Path::new(matches.value_of("output-dir").unwrap());
+	for idx in indices.into_iter() {
+		let proof = tree.inclusion_proof(idx);
+		let path = dir.join(proof_file_name(&proof.entry.customer_id));
+		let path = path.to_str().expect("invalid output path");
+		if ctx.dry_run() {
+			println!("Dry-run: not writing inclusion proof to {}", path);
+			continue;
+		}
+		let proof: protos::InclusionProof = proof.into();
+		utils::write_proto_file(path, &proof);
+		info!("Wrote inclusion proof to {}", path);
+	}
+}
+
+/// Execute the liabilities command.
+pub fn execute(ctx: &mut context::Ctx) {
+	match ctx.subcommand() {
+		("build", matches) => build(ctx, matches),
+		("export-proofs", matches) => export_proofs(ctx, matches),
+		(c, _) => println!("command {} unknown", c),
+	}
+}
@@ -0,0 +1,54 @@
+use clap;
+
+use context;
+
+/// Create the merge subcommand.
+pub fn subcommand<'a>() -> clap::App<'a, 'a> {
+	clap::SubCommand::with_name("merge")
+		.about("merge the proofs of other proof files into the proof file")
+		.arg(
+			clap::Arg::with_name("file")
+				.help("the proof files to merge, with the same network, challenge and block")
+				.multiple(true)
+				.takes_value(true)
+				.required(true),
+		)
+}
+
+/// Execute the merge command.
+pub fn execute(ctx: &mut context::Ctx) {
+	let mut pf = ctx.load_proof_file();
+
+	for path in ctx.command().values_of("file").unwrap() {
+		let mut other = ctx.load_other_proof_file(path);
+
+		let conflicts = pf.merge_conflicts(&other);
+		if !conflicts.is_empty() {
+			println!("Can't merge proof file '{}':", path);
+			for conflict in conflicts.into_iter() {
+				println!("  {}", conflict);
+			}
+			panic!("Conflicting proof files; nothing was merged.");
+		}
+
+		if other.provisions.is_some() {
+			if pf.provisions.is_none() {
+				pf.provisions = other.provisions.take();
+			} else {
+				println!("Warning: ignoring the provisions proof of '{}'.", path);
+			}
+		}
+		if other.liabilities.is_some() {
+			if pf.liabilities.is_none() {
+				pf.liabilities = other.liabilities.take();
+			} else {
+				println!("Warning: ignoring the liabilities of '{}'.", path);
+			}
+		}
+
+		println!("Merged {} proofs from '{}'.", other.proofs.len(), path);
+		pf.proofs.append(&mut other.proofs);
+	}
+
+	ctx.save_proof_file(pf);
+}
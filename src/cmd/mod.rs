@@ -4,10 +4,27 @@ pub mod common;
 
 pub mod add_proof;
 pub mod add_utxo;
+pub mod archive;
+pub mod challenge;
+pub mod diff;
 pub mod drop;
 pub mod drop_utxos;
 pub mod fetch_utxos;
+pub mod history;
+pub mod import_psbt;
+pub mod import_utxos;
 pub mod init;
 pub mod inspect;
+pub mod liabilities;
+pub mod merge;
+pub mod move_utxos;
+pub mod provisions;
+pub mod rename;
+pub mod reopen;
 pub mod sign;
+pub mod solvency;
+pub mod split;
+pub mod unblind_utxo;
 pub mod verify;
+pub mod verify_inclusion;
+pub mod watch;
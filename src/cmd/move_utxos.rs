@@ -0,0 +1,102 @@
+use std::collections::HashSet;
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::str::FromStr;
+
+use clap;
+use elements;
+use rbitcoin::blockdata::transaction::OutPoint;
+
+use cmd::common;
+use common::*;
+use context;
+
+/// Create the move-utxos subcommand.
+pub fn subcommand<'a>() -> clap::App<'a, 'a> {
+	clap::SubCommand::with_name("move-utxos")
+		.about("move some UTXOs from one proof to another")
+		.arg(common::id_arg())
+		.args(&[
+			clap::Arg::with_name("to")
+				.long("to")
+				.help("the identifier of the proof to move the UTXOs to, created when it's missing")
+				.takes_value(true)
+				.required(true),
+			clap::Arg::with_name("utxo")
+				.multiple(true)
+				.takes_value(true)
+				.required(true)
+				.help("UTXOs to move in the format of <txid>:<vout>"),
+		])
+}
+
+/// Move the UTXOs with the given outpoints from one list to another.
+fn move_utxos<'a, U: Debug, P: FromStr + Hash + Eq, F: Fn(&U) -> P>(
+	from: &mut Vec<U>,
+	to: &mut Vec<U>,
+	utxos: clap::Values<'a>,
+	point: F,
+) -> usize {
+	let mut moves = HashSet::new();
+	for utxo in utxos {
+		let point = P::from_str(utxo).unwrap_or_else(|_| panic!("failed to parse UTXO: {}", utxo));
+		moves.insert(point);
+	}
+
+	let len_before = to.len();
+	let mut idx = 0;
+	while idx < from.len() {
+		if moves.contains(&point(&from[idx])) {
+			let utxo = from.remove(idx);
+			debug!("Moving UTXO: {:?}", utxo);
+			to.push(utxo);
+		} else {
+			idx += 1;
+		}
+	}
+	to.len() - len_before
+}
+
+/// Execute the move-utxos command.
+pub fn execute(ctx: &mut context::Ctx) {
+	let mut pf = ctx.load_proof_file();
+
+	let proof_id = ctx.command().value_of("id").expect("no proof identifier given");
+	let target_id = ctx.command().value_of("to").unwrap();
+	if proof_id == target_id {
+		panic!("Can't move UTXOs to the same proof.");
+	}
+
+	let mut source = pf.take_proof(proof_id).expect("No proof found with given id");
+	let mut target = pf.take_proof(target_id).unwrap_or_else(|| {
+		Proof::new(pf.network, target_id.to_owned(), Proof_Status::GATHERING_UTXOS)
+	});
+	for proof in &[&source, &target] {
+		if proof.status() != Proof_Status::GATHERING_UTXOS {
+			panic!("Proof '{}' is no longer gathering UTXOs.", proof.id());
+		}
+	}
+
+	let utxos = ctx.command().values_of("utxo").unwrap();
+	let nb_requested = utxos.len();
+	let moved = match (&mut source, &mut target) {
+		(&mut Proof::Bitcoin(ref mut from), &mut Proof::Bitcoin(ref mut to)) => {
+			move_utxos::<_, OutPoint, _>(&mut from.utxos, &mut to.utxos, utxos, |u| u.point)
+		}
+		(&mut Proof::Liquid(ref mut from), &mut Proof::Liquid(ref mut to)) => {
+			move_utxos::<_, elements::OutPoint, _>(&mut from.utxos, &mut to.utxos, utxos, |u| {
+				u.point
+			})
+		}
+		_ => unreachable!("proofs of different networks in a single file"),
+	};
+
+	println!("Moved {} UTXOs from proof '{}' to proof '{}'.", moved, proof_id, target_id);
+	if moved < nb_requested {
+		println!("Warning: {} UTXOs were not found in proof '{}'.", nb_requested - moved, proof_id);
+	}
+
+	pf.proofs.insert(0, target);
+	pf.proofs.insert(0, source);
+	ctx.save_proof_file(pf);
+}
@@ -0,0 +1,164 @@
+use std::collections::HashMap;
+use std::fs;
+
+use clap;
+use hex;
+
+use backend;
+use common::*;
+use context;
+use provisions;
+use utils;
+
+/// Create the provisions subcommand.
+pub fn subcommand<'a>() -> clap::App<'a, 'a> {
+	clap::SubCommand::with_name("provisions")
+		.about("create and verify privacy-preserving proofs using the Provisions scheme")
+		.setting(clap::AppSettings::SubcommandRequiredElseHelp)
+		.subcommand(
+			clap::SubCommand::with_name("prove")
+				.about("create a Provisions proof over an anonymity set of public keys")
+				.args(&backend::bitcoind::args())
+				.args(&[
+					clap::Arg::with_name("pubkeys")
+						.long("pubkeys")
+						.help("file with the hex public keys of the anonymity set, one per line")
+						.takes_value(true)
+						.required(true),
+					clap::Arg::with_name("privkeys")
+						.long("privkeys")
+						.help("file with the WIF private keys we own, one per line")
+						.takes_value(true)
+						.required(true),
+					clap::Arg::with_name("threshold")
+						.long("threshold")
+						.help("prove that the reserves are at least this amount of satoshis")
+						.takes_value(true),
+				]),
+		).subcommand(
+			clap::SubCommand::with_name("verify")
+				.about("verify the Provisions proof in the proof file")
+				.args(&backend::bitcoind::args()),
+		)
+}
+
+/// Read the non-empty lines of a file, skipping comments.
+fn read_lines(path: &str) -> Vec<String> {
+	let contents = fs::read_to_string(path).expect(&format!("error reading file at '{}'", path));
+	contents
+		.lines()
+		.map(|l| l.trim())
+		.filter(|l| !l.is_empty() && !l.starts_with('#'))
+		.map(|l| l.to_owned())
+		.collect()
+}
+
+fn prove(ctx: &mut context::Ctx, matches: &clap::ArgMatches) {
+	let mut pf = ctx.load_proof_file();
+	if pf.network == Network::LIQUID {
+		panic!("Provisions proofs are only supported for Bitcoin.");
+	}
+	if pf.block_number == 0 {
+		panic!("The proof file has no block number set.");
+	}
+	if pf.provisions.is_some() {
+		println!("The existing Provisions proof will be replaced.");
+	}
+
+	// Our own keys are added to the anonymity set if they aren't already in there.
+	let mut pubkeys: Vec<Vec<u8>> = read_lines(matches.value_of("pubkeys").unwrap())
+		.into_iter()
+		.map(|l| hex::decode(&l).expect(&format!("invalid hex public key: {}", l)))
+		.collect();
+	let mut owned = HashMap::new();
+	for wif in read_lines(matches.value_of("privkeys").unwrap()).into_iter() {
+		let (secret, compressed) = utils::parse_wif(&wif).expect("invalid WIF private key");
+		let secret = provisions::secret_from_slice(&secret).expect("invalid private key");
+		let pubkey = provisions::pubkey_from_secret(&secret, compressed);
+		pubkeys.push(pubkey.clone());
+		owned.insert(pubkey, secret);
+	}
+	// Sort so that the position of a key doesn't leak which keys are ours.
+	pubkeys.sort();
+	pubkeys.dedup();
+
+	let mut bitcoind =
+		backend::bitcoind::Backend::load(matches).expect("bitcoind is required for Provisions");
+	let balances = bitcoind.fetch_pubkey_balances(&pubkeys, pf.block_number);
+
+	let mut keys = Vec::with_capacity(pubkeys.len());
+	let mut secrets = HashMap::new();
+	for (idx, (pubkey, balance)) in pubkeys.into_iter().zip(balances.into_iter()).enumerate() {
+		if let Some(secret) = owned.remove(&pubkey) {
+			secrets.insert(idx, secret);
+		}
+		keys.push(provisions::Key {
+			point: provisions::decode_point(&pubkey)
+				.expect(&format!("invalid public key: {}", hex::encode(&pubkey))),
+			pubkey: pubkey,
+			balance: balance,
+		});
+	}
+	info!("Anonymity set of {} keys, {} of which are ours", keys.len(), secrets.len());
+
+	let threshold = matches.value_of("threshold").map(|t| t.parse().expect("invalid threshold"));
+	let proof = provisions::ProvisionsProof::prove(
+		&pf.challenge,
+		pf.block_number,
+		keys,
+		&secrets,
+		threshold,
+	).unwrap_or_else(|e| panic!("Failed to create the Provisions proof: {}", e));
+	println!(
+		"Created Provisions proof over {} keys with total commitment {}",
+		proof.keys.len(),
+		hex::encode(provisions::encode_point(&proof.total_commitment())),
+	);
+
+	pf.provisions = Some(proof);
+	ctx.save_proof_file(pf);
+}
+
+fn verify(ctx: &mut context::Ctx, matches: &clap::ArgMatches) {
+	let pf = ctx.load_proof_file();
+	let proof = pf.provisions.as_ref().expect("The proof file has no Provisions proof.");
+
+	// Check the balances of all the keys.
+	let mut bitcoind =
+		backend::bitcoind::Backend::load(matches).expect("bitcoind is required for Provisions");
+	let pubkeys: Vec<Vec<u8>> = proof.keys.iter().map(|k| k.pubkey.clone()).collect();
+	let balances = bitcoind.fetch_pubkey_balances(&pubkeys, pf.block_number);
+	for (key, balance) in proof.keys.iter().zip(balances.into_iter()) {
+		if key.balance != balance {
+			panic!(
+				"Balance of key {} is {} satoshis, but the proof uses {}",
+				hex::encode(&key.pubkey),
+				balance,
+				key.balance,
+			);
+		}
+	}
+
+	proof
+		.verify(&pf.challenge, pf.block_number)
+		.unwrap_or_else(|e| panic!("Invalid Provisions proof: {}", e));
+
+	println!("Provisions proof verified for the following challenge: \"{}\"", pf.challenge);
+	println!("Size of the anonymity set: {} keys", proof.keys.len());
+	println!(
+		"Commitment to the total reserves: {}",
+		hex::encode(provisions::encode_point(&proof.total_commitment()))
+	);
+	if proof.range_proof.is_some() {
+		println!("Total amount of reserves is at least {} satoshis", proof.threshold);
+	}
+}
+
+/// Execute the provisions command.
+pub fn execute(ctx: &mut context::Ctx) {
+	match ctx.subcommand() {
+		("prove", matches) => prove(ctx, matches),
+		("verify", matches) => verify(ctx, matches),
+		(c, _) => println!("command {} unknown", c),
+	}
+}
@@ -0,0 +1,39 @@
+use clap;
+
+use cmd::common;
+use common::*;
+use context;
+
+/// Create the rename subcommand.
+pub fn subcommand<'a>() -> clap::App<'a, 'a> {
+	clap::SubCommand::with_name("rename")
+		.about("change the identifier of a proof")
+		.arg(common::id_arg())
+		.arg(
+			clap::Arg::with_name("new-id")
+				.help("the new identifier of the proof")
+				.takes_value(true)
+				.required(true),
+		)
+}
+
+/// Execute the rename command.
+pub fn execute(ctx: &mut context::Ctx) {
+	let mut pf = ctx.load_proof_file();
+
+	let proof_id = ctx.command().value_of("id").expect("no proof identifier given");
+	let new_id = ctx.command().value_of("new-id").unwrap();
+
+	if pf.has_proof(new_id) {
+		panic!("A proof with id '{}' already exists.", new_id);
+	}
+	let mut proof = pf.take_proof(proof_id).expect("No proof found with given id");
+	match proof {
+		Proof::Bitcoin(ref mut p) => p.id = new_id.to_owned(),
+		Proof::Liquid(ref mut p) => p.id = new_id.to_owned(),
+	}
+	println!("Renamed proof '{}' to '{}'.", proof_id, new_id);
+
+	pf.proofs.insert(0, proof);
+	ctx.save_proof_file(pf);
+}
@@ -0,0 +1,42 @@
+use clap;
+
+use cmd::common;
+use common::*;
+use context;
+
+/// Create the reopen subcommand.
+pub fn subcommand<'a>() -> clap::App<'a, 'a> {
+	clap::SubCommand::with_name("reopen")
+		.alias("unsign")
+		.about("discard the proof transactions of a proof being signed to gather UTXOs again")
+		.arg(common::id_arg())
+}
+
+/// Execute the reopen command.
+pub fn execute(ctx: &mut context::Ctx) {
+	let mut pf = ctx.load_proof_file();
+
+	let proof_id = ctx.command().value_of("id").expect("no proof identifier given");
+
+	let mut proof = pf.take_proof(proof_id).expect("No proof found with given id");
+	match proof.status() {
+		Proof_Status::UNDEFINED => panic!("Corrupt proof file"),
+		Proof_Status::GATHERING_UTXOS => panic!("Proof is still gathering UTXOs"),
+		Proof_Status::FINAL => panic!("Proof already in FINAL state"),
+		Proof_Status::SIGNING => { /* ok */ }
+	}
+
+	match proof {
+		Proof::Bitcoin(ref mut p) => {
+			let nb_sigs = p.reopen();
+			if nb_sigs > 0 {
+				println!("Warning: discarded {} signatures collected so far.", nb_sigs);
+			}
+		}
+		Proof::Liquid(ref mut p) => p.reopen(),
+	}
+	println!("Proof '{}' is gathering UTXOs again.", proof_id);
+
+	pf.proofs.insert(0, proof);
+	ctx.save_proof_file(pf);
+}
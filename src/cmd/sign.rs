@@ -5,6 +5,7 @@ use bitcoin;
 use cmd::common;
 use common::*;
 use context;
+use liquid;
 
 //TODO(stevenroose) Indicate which inputs should be signed; some signers don't really know.
 // Alternative would be to afterwards verify which inputs are validly signed and keep those,
@@ -16,23 +17,65 @@ pub fn subcommand<'a>() -> clap::App<'a, 'a> {
 		.about("sign a proof")
 		.arg(common::id_arg())
 		.args(&backend::bitcoind::args())
+		.args(&backend::elementsd::args())
 		.args(&backend::trezor::args())
+		.args(&[
+			clap::Arg::with_name("chunk-max-inputs")
+				.long("chunk-max-inputs")
+				.help("the maximum number of inputs of a proof tx, larger proofs are split")
+				.takes_value(true),
+			clap::Arg::with_name("chunk-max-weight")
+				.long("chunk-max-weight")
+				.help("the maximum weight of a proof tx, larger proofs are split [default: 400000]")
+				.takes_value(true),
+		])
 }
 
-/// Sign the tx with the active backend in the context.
-pub fn sign_proof(ctx: &mut context::Ctx, proof: &mut bitcoin::Proof) {
-	if let Some(mut trezor) = backend::trezor::Backend::load(ctx.command()) {
-		let signed = trezor.sign_tx(ctx, &mut proof.psbt.as_mut().unwrap());
-		//TODO(stevenroose) update psbt?
-		proof.proof_tx = Some(signed);
+/// Sign the tx of the given chunk with the active backend in the context.
+/// Returns the number of chunks that are still unsigned.
+pub fn sign_chunk(ctx: &mut context::Ctx, proof: &mut bitcoin::Proof, chunk_idx: usize) -> usize {
+	{
+		let chunk = &mut proof.chunks[chunk_idx];
+		if let Some(mut trezor) = backend::trezor::Backend::load(ctx.command()) {
+			let signed = trezor.sign_tx(ctx, &mut chunk.psbt.as_mut().unwrap());
+			//TODO(stevenroose) update psbt?
+			chunk.proof_tx = Some(signed);
+		} else if let Some(mut bitcoind) = backend::bitcoind::Backend::load(ctx.command()) {
+			let signed = bitcoind.sign_tx(chunk.psbt.clone().unwrap().global.unsigned_tx);
+			//TODO(stevenroose) update psbt?
+			chunk.proof_tx = Some(signed);
+		} else {
+			panic!("No argument provided with which we can sign txs!")
+		}
+	}
+
+	let nb_unsigned = proof.chunks.len() - proof.nb_signed_chunks();
+	if nb_unsigned == 0 {
 		proof.status = Proof_Status::FINAL;
-	} else if let Some(mut bitcoind) = backend::bitcoind::Backend::load(ctx.command()) {
-		let signed = bitcoind.sign_tx(proof.psbt.clone().unwrap().global.unsigned_tx);
-		//TODO(stevenroose) update psbt?
+	}
+	nb_unsigned
+}
+
+/// The limits for the proof transactions from the arguments.
+fn chunk_limits(matches: &clap::ArgMatches) -> bitcoin::ChunkLimits {
+	let mut limits = bitcoin::ChunkLimits::default();
+	if let Some(max) = matches.value_of("chunk-max-inputs") {
+		limits.max_inputs = Some(max.parse().expect("invalid chunk-max-inputs"));
+	}
+	if let Some(max) = matches.value_of("chunk-max-weight") {
+		limits.max_weight = max.parse().expect("invalid chunk-max-weight");
+	}
+	limits
+}
+
+/// Sign the Liquid tx with the active backend in the context.
+pub fn sign_liquid_proof(ctx: &mut context::Ctx, proof: &mut liquid::Proof) {
+	if let Some(mut elementsd) = backend::elementsd::Backend::load(ctx.command()) {
+		let signed = elementsd.sign_pset(proof.pset.as_ref().unwrap());
 		proof.proof_tx = Some(signed);
 		proof.status = Proof_Status::FINAL;
 	} else {
-		panic!("No argument provided with which we can sign txs!")
+		panic!("No argument provided with which we can sign Liquid txs!")
 	}
 }
 
@@ -40,20 +83,52 @@ pub fn sign_proof(ctx: &mut context::Ctx, proof: &mut bitcoin::Proof) {
 pub fn execute(ctx: &mut context::Ctx) {
 	let mut pf = ctx.load_proof_file();
 
-	let proof_id = ctx.command().value_of("id").expect("no proof identifier given");
+	let proof_id = ctx.command().value_of("id").expect("no proof identifier given").to_owned();
 
-	let mut proof = pf.take_proof(proof_id).expect("No proof found with given id");
-	match proof.status {
+	let mut proof = pf.take_proof(&proof_id).expect("No proof found with given id");
+	match proof.status() {
 		Proof_Status::SIGNING => { /* ok */ }
 		Proof_Status::UNDEFINED => panic!("Corrupt proof file"),
 		Proof_Status::FINAL => panic!("Proof already in final state"),
 		Proof_Status::GATHERING_UTXOS => {
 			// Done with outputs, set state to signing.
-			proof.start_signing(&pf.challenge)
+			match proof {
+				Proof::Bitcoin(ref mut p) => {
+					p.start_signing(&pf.challenge, &chunk_limits(ctx.command()));
+					if p.chunks.len() > 1 {
+						println!("The proof is split into {} chunks.", p.chunks.len());
+					}
+				}
+				Proof::Liquid(ref mut p) => p.start_signing(&pf.challenge),
+			}
 		}
 	}
 
-	sign_proof(ctx, &mut proof);
+	// Sign chunk by chunk and save the progress after every chunk, so that signing can be
+	// resumed when it's interrupted.
+	loop {
+		let nb_unsigned = match proof {
+			Proof::Bitcoin(ref mut p) => {
+				let idx = p.chunks.iter().position(|c| c.proof_tx.is_none()).unwrap();
+				let nb_unsigned = sign_chunk(ctx, p, idx);
+				if p.chunks.len() > 1 {
+					println!("Signed chunk {} of {}.", idx + 1, p.chunks.len());
+				}
+				nb_unsigned
+			}
+			Proof::Liquid(ref mut p) => {
+				sign_liquid_proof(ctx, p);
+				0
+			}
+		};
+		if nb_unsigned == 0 {
+			break;
+		}
+
+		pf.proofs.insert(0, proof);
+		pf = ctx.checkpoint_proof_file(pf);
+		proof = pf.take_proof(&proof_id).unwrap();
+	}
 	println!("Successfully signed proof.");
 
 	pf.proofs.insert(0, proof);
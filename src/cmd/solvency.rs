@@ -0,0 +1,57 @@
+use std::str::FromStr;
+
+use clap;
+use elements::AssetId;
+use hex;
+
+use backend;
+use cmd::verify;
+use common::*;
+use context;
+use liquid;
+
+/// Create the solvency subcommand.
+pub fn subcommand<'a>() -> clap::App<'a, 'a> {
+	clap::SubCommand::with_name("solvency")
+		.about("verify the reserves and compare them to the committed liabilities")
+		.args(&backend::bitcoind::args())
+		.args(&backend::elementsd::args())
+		.arg(verify::auditor_key_arg())
+}
+
+/// Execute the solvency command.
+pub fn execute(ctx: &mut context::Ctx) {
+	let pf = ctx.load_proof_file();
+	let liabilities = pf.liabilities.clone().expect("The proof file has no liabilities.");
+	if liabilities.block_number != pf.block_number {
+		panic!(
+			"The liabilities are for block {} while the reserves are for block {}.",
+			liabilities.block_number, pf.block_number
+		);
+	}
+
+	let reserves = verify::verify_reserves(ctx.command(), &pf);
+	// Liabilities are denominated in bitcoin, so on Liquid we compare with L-BTC.
+	let total_reserves = match pf.network {
+		Network::BITCOIN_MAINNET | Network::BITCOIN_TESTNET => reserves.amount.into_inner() as u64,
+		Network::LIQUID => {
+			let lbtc = AssetId::from_str(liquid::LBTC_ASSET_ID).unwrap();
+			reserves.assets.get(&lbtc).cloned().unwrap_or(0)
+		}
+	};
+
+	println!("");
+	println!("Solvency attestation");
+	println!("  challenge: \"{}\"", pf.challenge);
+	println!("  block number: {}", pf.block_number);
+	println!("  reserves: {} satoshis", total_reserves);
+	println!("  liabilities: {} satoshis", liabilities.total);
+	println!("  liabilities root: {}", hex::encode(&liabilities.root));
+	if total_reserves >= liabilities.total {
+		let surplus = total_reserves - liabilities.total;
+		println!("  result: SOLVENT (surplus of {} satoshis)", surplus);
+	} else {
+		let deficit = liabilities.total - total_reserves;
+		println!("  result: INSOLVENT (deficit of {} satoshis)", deficit);
+	}
+}
@@ -0,0 +1,50 @@
+use std::path::Path;
+
+use clap;
+
+use common::*;
+use context;
+
+/// Create the split subcommand.
+pub fn subcommand<'a>() -> clap::App<'a, 'a> {
+	clap::SubCommand::with_name("split")
+		.about("move proofs from the proof file to a new proof file")
+		.args(&[
+			clap::Arg::with_name("output")
+				.long("output")
+				.short("o")
+				.help("the new proof file to write the proofs to")
+				.takes_value(true)
+				.required(true),
+			clap::Arg::with_name("proof-id")
+				.help("the identifiers of the proofs to move")
+				.multiple(true)
+				.takes_value(true)
+				.required(true),
+		])
+}
+
+/// Execute the split command.
+pub fn execute(ctx: &mut context::Ctx) {
+	let mut pf = ctx.load_proof_file();
+
+	let output = ctx.command().value_of("output").unwrap();
+	if Path::new(output).exists() {
+		panic!("The file '{}' already exists.", output);
+	}
+
+	let mut new = ProofFile::new(pf.network);
+	new.version = pf.version;
+	new.challenge = pf.challenge.clone();
+	new.block_number = pf.block_number;
+	new.block_hash = pf.block_hash;
+	new.end_block_number = pf.end_block_number;
+	for id in ctx.command().values_of("proof-id").unwrap() {
+		let proof = pf.take_proof(id).expect(&format!("No proof found with id '{}'", id));
+		new.proofs.push(proof);
+	}
+
+	println!("Moved {} proofs to '{}'.", new.proofs.len(), output);
+	ctx.save_other_proof_file(new, output);
+	ctx.save_proof_file(pf);
+}
@@ -0,0 +1,104 @@
+use std::str::FromStr;
+
+use clap;
+use elements::confidential::{AssetBlindingFactor, ValueBlindingFactor};
+use elements::hashes::hex::FromHex;
+use elements::secp256k1_zkp::{Secp256k1, SecretKey};
+use elements::{AssetId, OutPoint, TxOutSecrets};
+
+use cmd::common;
+use common::*;
+use context;
+use liquid;
+
+/// Create the unblind-utxo subcommand.
+pub fn subcommand<'a>() -> clap::App<'a, 'a> {
+	clap::SubCommand::with_name("unblind-utxo")
+		.about("add the unblinding data for a confidential Liquid UTXO to a proof")
+		.arg(common::id_arg())
+		.args(&vec![
+			clap::Arg::with_name("outpoint")
+				.help("the outpoint of the UTXO (`<txid>:<vout>` format)")
+				.takes_value(true)
+				.required(true),
+			// Either provide the blinding key..
+			clap::Arg::with_name("blinding-key")
+				.long("blinding-key")
+				.help("the private blinding key of the output's address (hex)")
+				.takes_value(true)
+				.conflicts_with_all(&["asset", "value", "asset-blinder", "value-blinder"]),
+			// ..or the explicit unblinding data.
+			clap::Arg::with_name("asset")
+				.long("asset")
+				.help("the asset id of the output")
+				.takes_value(true)
+				.requires_all(&["value", "asset-blinder", "value-blinder"]),
+			clap::Arg::with_name("value")
+				.long("value")
+				.help("the value of the output in satoshis")
+				.takes_value(true),
+			clap::Arg::with_name("asset-blinder")
+				.long("asset-blinder")
+				.help("the asset blinding factor of the output (hex)")
+				.takes_value(true),
+			clap::Arg::with_name("value-blinder")
+				.long("value-blinder")
+				.help("the value blinding factor of the output (hex)")
+				.takes_value(true),
+		])
+}
+
+/// Execute the unblind-utxo command.
+pub fn execute(ctx: &mut context::Ctx) {
+	let mut pf = ctx.load_proof_file();
+	if pf.network != Network::LIQUID {
+		panic!("Unblinding UTXOs is only possible for Liquid proofs.");
+	}
+
+	let proof_id = ctx.command().value_of("id").expect("no proof identifier given");
+	let outpoint_str = ctx.command().value_of("outpoint").expect("outpoint is required");
+	let outpoint = OutPoint::from_str(outpoint_str).expect("failed to parse outpoint");
+
+	let mut proof = pf.take_proof(proof_id).expect("No proof found with given id");
+	if proof.status() == Proof_Status::UNDEFINED {
+		panic!("Corrupt proof file");
+	}
+
+	// Adding unblinding data doesn't change the proof tx, so this is possible in any state.
+	if let Proof::Liquid(ref mut proof) = proof {
+		let utxo: &mut liquid::UTXO = proof
+			.utxos
+			.iter_mut()
+			.find(|u| u.point == outpoint)
+			.expect("Proof has no UTXO with this outpoint.");
+		if !utxo.is_confidential() {
+			panic!("UTXO {} is not confidential.", outpoint);
+		}
+
+		let secrets = if let Some(key) = ctx.command().value_of("blinding-key") {
+			let key = SecretKey::from_str(key).expect("invalid blinding key");
+			utxo.txout().unblind(&Secp256k1::new(), key).expect("failed to unblind the UTXO")
+		} else if let Some(asset) = ctx.command().value_of("asset") {
+			let arg = |name: &str| ctx.command().value_of(name).unwrap();
+			TxOutSecrets {
+				asset: AssetId::from_hex(asset).expect("invalid asset id"),
+				asset_bf: AssetBlindingFactor::from_hex(arg("asset-blinder"))
+					.expect("invalid asset blinder"),
+				value: arg("value").parse().expect("invalid value"),
+				value_bf: ValueBlindingFactor::from_hex(arg("value-blinder"))
+					.expect("invalid value blinder"),
+			}
+		} else {
+			panic!("Either the blinding key or the explicit unblinding data is required.")
+		};
+
+		liquid::verify_unblinding(utxo.txout(), &secrets)
+			.unwrap_or_else(|e| panic!("Incorrect unblinding data: {}", e));
+		debug!("Adding unblinding data for UTXO {}: {:?}", outpoint, secrets);
+		utxo.unblinding = Some(secrets);
+		println!("Successfully added the unblinding data to the UTXO.");
+	}
+
+	pf.proofs.insert(0, proof);
+	ctx.save_proof_file(pf);
+}
@@ -1,55 +1,286 @@
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashMap, HashSet};
 
 use bitcoin_amount::Amount;
 use clap;
+use elements::AssetId;
 
 use backend;
 use common::*;
 use context;
+use liquid;
 use protos;
+use utils;
 
 /// Create the verify subcommand.
 pub fn subcommand<'a>() -> clap::App<'a, 'a> {
 	clap::SubCommand::with_name("verify")
 		.about("verify the proofs in the proof file")
 		.args(&backend::bitcoind::args())
+		.args(&backend::elementsd::args())
+		.arg(auditor_key_arg())
 }
 
-/// Execute the verify command.
-pub fn execute(ctx: &mut context::Ctx) {
-	let pf = ctx.load_proof_file();
+/// Create the CLI argument for the auditor key to check the challenge signature against.
+pub fn auditor_key_arg<'a>() -> clap::Arg<'a, 'a> {
+	clap::Arg::with_name("auditor-key")
+		.long("auditor-key")
+		.help("check that the challenge was issued by this auditor (public key or P2PKH address)")
+		.takes_value(true)
+}
+
+/// The verified reserves of a proof file.
+pub struct Reserves {
+	pub nb_utxos: usize,
+	/// The total amount of the Bitcoin proofs.
+	pub amount: Amount,
+	/// The total amounts per asset of the Liquid proofs.
+	pub assets: BTreeMap<AssetId, u64>,
+}
+
+impl Reserves {
+	/// The total amounts per asset name, with "BTC" for the Bitcoin amount.
+	pub fn totals(&self, network: Network) -> BTreeMap<String, u64> {
+		match network {
+			Network::BITCOIN_MAINNET | Network::BITCOIN_TESTNET => {
+				Some(("BTC".to_owned(), self.amount.into_inner() as u64)).into_iter().collect()
+			}
+			Network::LIQUID => {
+				self.assets.iter().map(|(a, v)| (liquid::asset_name(a), *v)).collect()
+			}
+		}
+	}
+}
+
+/// Look up the height of the block with the given hash in the active chain.
+fn active_block_height(matches: &clap::ArgMatches, network: Network, hash: &str) -> Option<u32> {
+	match network {
+		Network::BITCOIN_MAINNET | Network::BITCOIN_TESTNET => {
+			backend::bitcoind::Backend::load(matches)
+				.expect("failed to load bitcoind")
+				.active_block_height(hash)
+		}
+		Network::LIQUID => backend::elementsd::Backend::load(matches)
+			.expect("failed to load elementsd")
+			.active_block_height(hash),
+	}
+}
+
+/// Verify that the pinned proof block is still in the active chain.
+pub fn verify_block(matches: &clap::ArgMatches, pf: &ProofFile) {
+	let hash = match pf.block_hash {
+		Some(ref hash) => utils::block_hash_hex(hash),
+		None => return,
+	};
+	match active_block_height(matches, pf.network, &hash) {
+		None => panic!("The proof block {} is no longer in the active chain.", hash),
+		Some(height) if height != pf.block_number => panic!(
+			"The proof block {} is at height {} instead of {}.",
+			hash, height, pf.block_number
+		),
+		Some(_) => {}
+	}
+}
+
+/// Verify the data the challenge commits to.
+pub fn verify_challenge(matches: &clap::ArgMatches, pf: &ProofFile) {
+	if let Some(key) = matches.value_of("auditor-key") {
+		let signed = pf
+			.challenge
+			.is_signed_by(key)
+			.unwrap_or_else(|e| panic!("Failed to verify the auditor signature: {}", e));
+		if !signed {
+			panic!("The challenge was not issued by auditor key {}.", key);
+		}
+		println!(
+			"Challenge was issued by auditor '{}' on {}.",
+			pf.challenge.auditor.as_ref().map(|a| a.as_str()).unwrap_or("(unknown)"),
+			pf.challenge.date.as_ref().map(|d| d.as_str()).unwrap_or("(unknown date)"),
+		);
+	}
+	if let Some(target) = pf.challenge.target_block {
+		if target != pf.block_number {
+			panic!(
+				"The proof block {} differs from the target block {} of the challenge.",
+				pf.block_number, target
+			);
+		}
+	}
+
+	if pf.challenge.end_block != pf.end_block_number {
+		panic!("The claimed end block of the range is not committed to in the challenge.");
+	}
+
+	if let Some(root) = pf.challenge.liabilities_root {
+		match pf.liabilities {
+			None => panic!("The challenge commits to liabilities, but the file has none."),
+			Some(ref liabilities) if root != liabilities.root => {
+				panic!("The liabilities root in the challenge doesn't match the liabilities.")
+			}
+			Some(_) => {}
+		}
+	}
+
+	// The block hash in the challenge has to be in the chain before the proof block.
+	if let Some(ref hash) = pf.challenge.block_hash {
+		let hash = utils::block_hash_hex(hash);
+		match active_block_height(matches, pf.network, &hash) {
+			None => panic!("The challenge block hash {} is not in the active chain.", hash),
+			Some(height) if height > pf.block_number => panic!(
+				"The challenge block {} is at height {}, after the proof block {}.",
+				hash, height, pf.block_number
+			),
+			Some(height) => println!("Challenge block hash found at height {}.", height),
+		}
+	}
+}
+
+/// Verify that the proven UTXOs stayed unspent over the claimed block range.
+/// Verification fails if coins left the UTXO set, after reporting the blocks where they did.
+pub fn verify_range(matches: &clap::ArgMatches, pf: &ProofFile, end: u32) {
+	if pf.network == Network::LIQUID {
+		panic!("Block ranges are not supported for Liquid proofs yet.");
+	}
+	let mut bitcoind = backend::bitcoind::Backend::load(matches).expect("failed to load bitcoind");
+	let tip = bitcoind.tip_height();
+	if end > tip {
+		panic!("The end block {} of the claimed range is after the chain tip {}.", end, tip);
+	}
+
+	let mut values = HashMap::new();
+	for proof in pf.proofs.iter() {
+		values.extend(bitcoind.proven_coins(proof.bitcoin()).into_iter());
+	}
+	let total = values.values().fold(0, |a, v| a + v);
+
+	// Coins that are still unspent now stayed unspent over the whole range, so only the spent
+	// coins have to be looked up in the blocks of the range.
+	let spent: HashSet<_> = values.keys().filter(|p| !bitcoind.is_unspent(p)).cloned().collect();
+	let spends = if spent.is_empty() {
+		Vec::new()
+	} else {
+		bitcoind.find_spends(&spent, pf.block_number, end)
+	};
+
+	let mut by_block: BTreeMap<u32, Vec<_>> = BTreeMap::new();
+	for (height, point) in spends.into_iter() {
+		by_block.entry(height).or_insert_with(Vec::new).push(point);
+	}
+	let mut min_amount = total;
+	for (height, points) in by_block.iter() {
+		let value = points.iter().fold(0, |a, p| a + values[p]);
+		min_amount -= value;
+		println!(
+			"Block {}: {} proven UTXOs ({} satoshis) left the UTXO set.",
+			height,
+			points.len(),
+			value
+		);
+		for point in points.iter() {
+			println!("  {}", point);
+		}
+	}
+	if !by_block.is_empty() {
+		panic!(
+			"Not all proven UTXOs stayed unspent from block {} to block {}; the minimum \
+			 reserves over the range are {} satoshis.",
+			pf.block_number, end, min_amount
+		);
+	}
+	println!("All proven UTXOs stayed unspent from block {} to block {}.", pf.block_number, end);
+}
+
+/// Verify all proofs in the proof file and return the total reserves.
+/// The backends are loaded from the given arguments.
+pub fn verify_reserves(matches: &clap::ArgMatches, pf: &ProofFile) -> Reserves {
+	verify_block(matches, pf);
+	verify_challenge(matches, pf);
 
 	// Check if any UTXO is spent by multiple proofs.
+	let mut nb_utxos = 0;
 	let mut outpoints = HashSet::new();
+	let mut liquid_outpoints = HashSet::new();
 	for proof in pf.proofs.iter() {
-		if proof.status != protos::Proof_Status::FINAL {
-			panic!("Proof '{}' is not final yet.", proof.id);
+		if proof.status() != protos::Proof_Status::FINAL {
+			panic!("Proof '{}' is not final yet.", proof.id());
 		}
 
-		for out in proof.spending_utxos().into_iter() {
-			if !outpoints.insert(out) {
-				panic!("UTXO {} is spent in two different proofs!", out);
+		match *proof {
+			Proof::Bitcoin(ref proof) => {
+				for out in proof.spending_utxos().into_iter() {
+					if !outpoints.insert(out) {
+						panic!("UTXO {} is spent in two different proofs!", out);
+					}
+					nb_utxos += 1;
+				}
+			}
+			Proof::Liquid(ref proof) => {
+				for out in proof.spending_utxos().into_iter() {
+					if !liquid_outpoints.insert(out) {
+						panic!("UTXO {} is spent in two different proofs!", out);
+					}
+					nb_utxos += 1;
+				}
 			}
 		}
 	}
-	println!("Total number of UTXOs: {}", outpoints.len());
 
 	// Then verify all the proof txs.
 	let mut total_amount = Amount::from_sat(0);
-	for mut proof in pf.proofs.iter() {
-		match pf.network {
-			Network::BITCOIN_MAINNET | Network::BITCOIN_TESTNET => {
-				let mut bitcoind = backend::bitcoind::Backend::load(ctx.command())
-					.expect("failed to load bitcoind");
+	let mut asset_totals = BTreeMap::new();
+	for proof in pf.proofs.iter() {
+		match *proof {
+			Proof::Bitcoin(ref proof) => {
+				let mut bitcoind =
+					backend::bitcoind::Backend::load(matches).expect("failed to load bitcoind");
 				let txouts = bitcoind.fetch_proof_prevouts(&proof, pf.block_number);
 				let amount = proof.verify(&pf.challenge, txouts);
 				total_amount = total_amount + amount;
 				println!("Verified proof '{}' for {} satoshis.", proof.id, amount.into_inner());
 			}
-			Network::LIQUID => panic!("can't verify Liquid proofs yet"),
+			Proof::Liquid(ref proof) => {
+				let mut elementsd =
+					backend::elementsd::Backend::load(matches).expect("failed to load elementsd");
+				let txouts = elementsd.fetch_proof_prevouts(&proof, pf.block_number);
+				let amounts = proof.verify(&pf.challenge, txouts);
+				println!("Verified proof '{}' for the following amounts:", proof.id);
+				for (asset, amount) in amounts.into_iter() {
+					println!("  {}: {}", liquid::asset_name(&asset), amount);
+					*asset_totals.entry(asset).or_insert(0) += amount;
+				}
+			}
 		}
 	}
 
+	if let Some(end) = pf.end_block_number {
+		verify_range(matches, pf, end);
+	}
+
+	Reserves {
+		nb_utxos: nb_utxos,
+		amount: total_amount,
+		assets: asset_totals,
+	}
+}
+
+/// Execute the verify command.
+pub fn execute(ctx: &mut context::Ctx) {
+	let pf = ctx.load_proof_file();
+	let reserves = verify_reserves(ctx.command(), &pf);
+
+	println!("Total number of UTXOs: {}", reserves.nb_utxos);
 	println!("All proofs verified for the following challenge: \"{}\"", pf.challenge);
-	println!("Total amount of reserves: {} satoshis", total_amount.into_inner());
+	match pf.network {
+		Network::BITCOIN_MAINNET | Network::BITCOIN_TESTNET => {
+			println!("Total amount of reserves: {} satoshis", reserves.amount.into_inner());
+			if let Some(end) = pf.end_block_number {
+				println!("The reserves were held from block {} to block {}.", pf.block_number, end);
+			}
+		}
+		Network::LIQUID => {
+			println!("Total amount of reserves per asset:");
+			for (asset, amount) in reserves.assets.into_iter() {
+				println!("  {}: {}", liquid::asset_name(&asset), amount);
+			}
+		}
+	}
 }
@@ -0,0 +1,37 @@
+use clap;
+use hex;
+
+use context;
+use liabilities;
+use protos;
+use utils;
+
+/// Create the verify-inclusion subcommand.
+pub fn subcommand<'a>() -> clap::App<'a, 'a> {
+	clap::SubCommand::with_name("verify-inclusion")
+		.about("verify that a customer balance is included in the liabilities of the proof file")
+		.arg(
+			clap::Arg::with_name("inclusion-proof")
+				.help("the inclusion proof file received from the custodian")
+				.takes_value(true)
+				.required(true),
+		)
+}
+
+/// Execute the verify-inclusion command.
+pub fn execute(ctx: &mut context::Ctx) {
+	let pf = ctx.load_proof_file();
+	let published = pf.liabilities.as_ref().expect("The proof file has no liabilities.");
+
+	let path = ctx.command().value_of("inclusion-proof").unwrap();
+	let proof: liabilities::InclusionProof =
+		utils::read_proto_file::<protos::InclusionProof>(path).into();
+	proof.verify(published).unwrap_or_else(|e| panic!("Invalid inclusion proof: {}", e));
+
+	println!(
+		"Balance of {} satoshis for customer '{}' is included in the liabilities.",
+		proof.entry.balance, proof.entry.customer_id
+	);
+	println!("Liabilities root: {}", hex::encode(&published.root));
+	println!("Total liabilities: {} satoshis at block {}", published.total, published.block_number);
+}
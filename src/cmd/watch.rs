@@ -0,0 +1,208 @@
+use std::thread;
+use std::time::Duration;
+
+use clap;
+use rbitcoin::util::hash::BitcoinHash;
+use rbitcoin::OutPoint;
+
+use backend;
+use common::*;
+use context;
+
+/// Create the watch subcommand.
+pub fn subcommand<'a>() -> clap::App<'a, 'a> {
+	clap::SubCommand::with_name("watch")
+		.about("check whether the proven coins have been spent since the proof block")
+		.args(&backend::bitcoind::args())
+		.args(&[
+			clap::Arg::with_name("interval")
+				.long("interval")
+				.help("keep watching and check again every this many seconds")
+				.takes_value(true),
+			clap::Arg::with_name("format")
+				.long("format")
+				.help("the output format of the alerts and status reports")
+				.takes_value(true)
+				.possible_values(&["text", "json"])
+				.default_value("text"),
+		])
+}
+
+/// The transaction that spent a proven coin.
+struct Spend {
+	txid: String,
+	height: u32,
+}
+
+/// A proven coin that is being watched.
+struct Coin {
+	point: OutPoint,
+	proof: String,
+	value: u64,
+	/// Whether the coin is no longer in the UTXO set.
+	spent: bool,
+	spend: Option<Spend>,
+}
+
+/// The state of the watcher.
+struct Watcher {
+	coins: Vec<Coin>,
+	/// The last block that was scanned for spending transactions.
+	scanned_height: u32,
+	json: bool,
+}
+
+impl Watcher {
+	/// Collect the coins of all final proofs.
+	fn new(pf: &ProofFile, bitcoind: &mut backend::bitcoind::Backend, json: bool) -> Watcher {
+		let mut coins = Vec::new();
+		for proof in pf.proofs.iter() {
+			if proof.status() != Proof_Status::FINAL {
+				continue;
+			}
+			let proof = proof.bitcoin();
+			for (point, value) in bitcoind.proven_coins(proof).into_iter() {
+				coins.push(Coin {
+					point: point,
+					proof: proof.id.clone(),
+					value: value,
+					spent: false,
+					spend: None,
+				});
+			}
+		}
+		coins.sort_by_key(|c| c.point.to_string());
+
+		Watcher {
+			coins: coins,
+			scanned_height: pf.block_number,
+			json: json,
+		}
+	}
+
+	/// Scan the blocks after the last scanned block for the transactions spending the coins
+	/// that are no longer unspent.  The scan stops when all those spends are found.
+	fn scan_spends(&mut self, bitcoind: &mut backend::bitcoind::Backend, tip: u32) {
+		while self.coins.iter().any(|c| c.spent && c.spend.is_none()) && self.scanned_height < tip {
+			let height = self.scanned_height + 1;
+			let block = bitcoind.block_at(height);
+			for tx in block.txdata.iter() {
+				for input in tx.input.iter() {
+					let coin = self.coins.iter_mut().find(|c| c.point == input.previous_output);
+					if let Some(coin) = coin {
+						coin.spent = true;
+						coin.spend = Some(Spend {
+							txid: tx.bitcoin_hash().to_string(),
+							height: height,
+						});
+					}
+				}
+			}
+			self.scanned_height = height;
+		}
+	}
+
+	/// Check the spend status of all coins and report the new spends and the status.
+	fn check(&mut self, bitcoind: &mut backend::bitcoind::Backend) {
+		let tip = bitcoind.tip_height();
+		let was_spent: Vec<bool> = self.coins.iter().map(|c| c.spent).collect();
+		for coin in self.coins.iter_mut() {
+			if !coin.spent && !bitcoind.is_unspent(&coin.point) {
+				coin.spent = true;
+			}
+		}
+		// The scan can also find spends of coins that were spent after the check above.
+		self.scan_spends(bitcoind, tip);
+
+		for (coin, was_spent) in self.coins.iter().zip(was_spent.into_iter()) {
+			if coin.spent && !was_spent {
+				self.alert(coin);
+			}
+		}
+		self.report(tip);
+	}
+
+	fn alert(&self, coin: &Coin) {
+		let (txid, height) = match coin.spend {
+			Some(ref s) => (Some(s.txid.as_str()), Some(s.height)),
+			None => (None, None),
+		};
+		if self.json {
+			let alert = json!({
+				"type": "spent",
+				"outpoint": coin.point.to_string(),
+				"proof": coin.proof,
+				"value": coin.value,
+				"spending_txid": txid,
+				"spending_height": height,
+			});
+			println!("{}", alert);
+		} else {
+			println!(
+				"ALERT: UTXO {} of proof '{}' ({} satoshis) was spent in tx {} at height {}",
+				coin.point,
+				coin.proof,
+				coin.value,
+				txid.unwrap_or("(unknown)"),
+				height.map(|h| h.to_string()).unwrap_or_else(|| "(unknown)".to_owned()),
+			);
+		}
+	}
+
+	fn report(&self, tip: u32) {
+		let total = self.coins.iter().fold(0, |a, c| a + c.value);
+		let unspent_coins = self.coins.iter().filter(|c| !c.spent);
+		let unspent = unspent_coins.fold(0, |a, c| a + c.value);
+		let share = if total == 0 { 0.0 } else { unspent as f64 / total as f64 * 100.0 };
+		let nb_spent = self.coins.iter().filter(|c| c.spent).count();
+		if self.json {
+			let status = json!({
+				"type": "status",
+				"tip_height": tip,
+				"nb_utxos": self.coins.len(),
+				"nb_spent": nb_spent,
+				"total": total,
+				"unspent": unspent,
+				"unspent_share": share,
+			});
+			println!("{}", status);
+		} else {
+			println!(
+				"At height {}: {} of {} proven UTXOs spent, {} of {} satoshis ({:.2}%) unspent.",
+				tip,
+				nb_spent,
+				self.coins.len(),
+				unspent,
+				total,
+				share,
+			);
+		}
+	}
+}
+
+/// Execute the watch command.
+pub fn execute(ctx: &mut context::Ctx) {
+	let pf = ctx.load_proof_file();
+	if pf.network == Network::LIQUID {
+		panic!("Watching Liquid proofs is not supported yet.");
+	}
+
+	let mut bitcoind =
+		backend::bitcoind::Backend::load(ctx.command()).expect("failed to load bitcoind");
+	let json = ctx.command().value_of("format") == Some("json");
+	let mut watcher = Watcher::new(&pf, &mut bitcoind, json);
+	if watcher.coins.is_empty() {
+		panic!("The proof file has no final proofs to watch.");
+	}
+
+	let interval = ctx.command().value_of("interval").map(|i| {
+		Duration::from_secs(i.parse().expect("invalid interval"))
+	});
+	loop {
+		watcher.check(&mut bitcoind);
+		match interval {
+			Some(interval) => thread::sleep(interval),
+			None => break,
+		}
+	}
+}
@@ -1,34 +1,143 @@
+use std::collections::HashMap;
+
+use elements;
+use rbitcoin::OutPoint;
+
 use bitcoin;
+use challenge::Challenge;
+use liabilities;
+use liquid;
 use protos;
+use provisions;
 pub use protos::{Network, Proof_Status};
 
-//TODO(stevenroose) when supporting Liquid, we can make an enum Proof that will hold either
-// a Bitcoin(bitcoin::Proof) or Liquid(liquid::Proof).
+/// A proof for one of the supported networks.
+/// All proofs in a proof file are for the same network.
+#[derive(Debug)]
+pub enum Proof {
+	Bitcoin(bitcoin::Proof),
+	Liquid(liquid::Proof),
+}
+
+impl Proof {
+	/// Create a new proof for the given network.
+	pub fn new(network: Network, id: String, status: Proof_Status) -> Proof {
+		match network {
+			Network::BITCOIN_MAINNET | Network::BITCOIN_TESTNET => {
+				Proof::Bitcoin(bitcoin::Proof::new(id, status))
+			}
+			Network::LIQUID => Proof::Liquid(liquid::Proof::new(id, status)),
+		}
+	}
+
+	pub fn id(&self) -> &str {
+		match *self {
+			Proof::Bitcoin(ref p) => &p.id,
+			Proof::Liquid(ref p) => &p.id,
+		}
+	}
+
+	pub fn status(&self) -> Proof_Status {
+		match *self {
+			Proof::Bitcoin(ref p) => p.status,
+			Proof::Liquid(ref p) => p.status,
+		}
+	}
+
+	/// The outpoints of the UTXOs of the proof in string format.
+	/// For final proofs, these are the outpoints spent by the proof txs.
+	pub fn outpoints(&self) -> Vec<String> {
+		let fin = self.status() == Proof_Status::FINAL;
+		match *self {
+			Proof::Bitcoin(ref p) if fin => {
+				p.spending_utxos().into_iter().map(|o| o.to_string()).collect()
+			}
+			Proof::Liquid(ref p) if fin => {
+				p.spending_utxos().into_iter().map(|o| o.to_string()).collect()
+			}
+			Proof::Bitcoin(ref p) => p.utxos.iter().map(|u| u.point.to_string()).collect(),
+			Proof::Liquid(ref p) => p.utxos.iter().map(|u| u.point.to_string()).collect(),
+		}
+	}
+
+	/// Get the inner Bitcoin proof.  Panics for Liquid proofs.
+	pub fn bitcoin(&self) -> &bitcoin::Proof {
+		match *self {
+			Proof::Bitcoin(ref p) => p,
+			Proof::Liquid(ref p) => panic!("Proof '{}' is not a Bitcoin proof", p.id),
+		}
+	}
+
+	/// Get the inner Bitcoin proof.  Panics for Liquid proofs.
+	pub fn bitcoin_mut(&mut self) -> &mut bitcoin::Proof {
+		match *self {
+			Proof::Bitcoin(ref mut p) => p,
+			Proof::Liquid(ref p) => panic!("Proof '{}' is not a Bitcoin proof", p.id),
+		}
+	}
+}
 
 #[derive(Debug)]
 pub struct ProofFile {
 	pub version: u32,
 	pub network: Network,
-	pub challenge: String,
+	pub challenge: Challenge,
 	pub block_number: u32,
-	pub proofs: Vec<bitcoin::Proof>,
+	/// The hash of the proof block in internal byte order.
+	pub block_hash: Option<[u8; 32]>,
+	/// The last block of the claimed block range that starts at the proof block.
+	pub end_block_number: Option<u32>,
+	pub proofs: Vec<Proof>,
+	pub provisions: Option<provisions::ProvisionsProof>,
+	pub liabilities: Option<liabilities::Liabilities>,
 }
 
 impl From<protos::ProofOfReserves> for ProofFile {
-	fn from(p: protos::ProofOfReserves) -> Self {
+	fn from(mut p: protos::ProofOfReserves) -> Self {
+		let network = p.network;
+		let provisions = if p.has_provisions() {
+			Some(p.take_provisions().into())
+		} else {
+			None
+		};
+		let challenge = if p.has_structured_challenge() {
+			p.take_structured_challenge().into()
+		} else {
+			Challenge::new(p.take_challenge())
+		};
+		let liabilities = if p.has_liabilities() {
+			Some(p.take_liabilities().into())
+		} else {
+			None
+		};
 		ProofFile {
 			version: p.version,
-			network: p.network,
-			challenge: p.challenge.into(),
+			network: network,
+			challenge: challenge,
 			block_number: p.block_number,
-			//TODO(stevenroose) proofs: p.take_proofs().into_vec().into_iter().into().collect(),
+			block_hash: if p.get_block_hash().len() == 32 {
+				let mut hash = [0; 32];
+				hash.copy_from_slice(p.get_block_hash());
+				Some(hash)
+			} else {
+				None
+			},
+			end_block_number: if p.end_block_number != 0 {
+				Some(p.end_block_number)
+			} else {
+				None
+			},
 			proofs: p
 				.proofs
 				.into_iter()
-				.map(|p| {
-					let i: bitcoin::Proof = p.into();
-					i
+				.map(|p| match network {
+					Network::BITCOIN_MAINNET | Network::BITCOIN_TESTNET => {
+						Proof::Bitcoin(p.into())
+					}
+					Network::LIQUID => Proof::Liquid(p.into()),
 				}).collect(),
+			provisions: provisions,
+			liabilities: liabilities,
 		}
 	}
 }
@@ -38,17 +147,34 @@ impl Into<protos::ProofOfReserves> for ProofFile {
 		let mut p = protos::ProofOfReserves::new();
 		p.set_version(self.version);
 		p.set_network(self.network);
-		p.set_challenge(self.challenge);
+		p.set_challenge(self.challenge.message.clone());
+		if self.challenge.is_structured() {
+			p.set_structured_challenge(self.challenge.into());
+		}
 		p.set_block_number(self.block_number);
-		//TODO(stevenroose) p.set_proofs(self.proofs.into_iter().into().collect());
+		if let Some(hash) = self.block_hash {
+			p.set_block_hash(hash.to_vec());
+		}
+		if let Some(end) = self.end_block_number {
+			p.set_end_block_number(end);
+		}
 		p.set_proofs(
 			self.proofs
 				.into_iter()
 				.map(|p| {
-					let i: protos::Proof = p.into();
+					let i: protos::Proof = match p {
+						Proof::Bitcoin(p) => p.into(),
+						Proof::Liquid(p) => p.into(),
+					};
 					i
 				}).collect(),
 		);
+		if let Some(provisions) = self.provisions {
+			p.set_provisions(provisions.into());
+		}
+		if let Some(liabilities) = self.liabilities {
+			p.set_liabilities(liabilities.into());
+		}
 		p
 	}
 }
@@ -58,17 +184,82 @@ impl ProofFile {
 		ProofFile {
 			version: 0,
 			network: network,
-			challenge: String::new(),
+			challenge: Challenge::default(),
 			block_number: 0,
+			block_hash: None,
+			end_block_number: None,
 			proofs: vec![],
+			provisions: None,
+			liabilities: None,
+		}
+	}
+
+	/// Find the id of the proof that contains a Bitcoin UTXO with the given outpoint.
+	pub fn find_bitcoin_utxo(&self, point: &OutPoint) -> Option<&str> {
+		self.proofs.iter().find(|p| match **p {
+			Proof::Bitcoin(ref p) => p.utxos.iter().any(|u| u.point == *point),
+			Proof::Liquid(_) => false,
+		}).map(|p| p.id())
+	}
+
+	/// Find the id of the proof that contains a Liquid UTXO with the given outpoint.
+	pub fn find_liquid_utxo(&self, point: &elements::OutPoint) -> Option<&str> {
+		self.proofs.iter().find(|p| match **p {
+			Proof::Bitcoin(_) => false,
+			Proof::Liquid(ref p) => p.utxos.iter().any(|u| u.point == *point),
+		}).map(|p| p.id())
+	}
+
+	/// Whether the file has a proof with the given id.
+	pub fn has_proof(&self, id: &str) -> bool {
+		self.proofs.iter().any(|p| p.id() == id)
+	}
+
+	/// The reasons why the other proof file can't be merged into this one.
+	pub fn merge_conflicts(&self, other: &ProofFile) -> Vec<String> {
+		let mut conflicts = Vec::new();
+		if self.network != other.network {
+			conflicts.push("the network is different".to_owned());
+		}
+		if self.challenge != other.challenge {
+			conflicts.push("the challenge is different".to_owned());
+		}
+		if self.block_number != other.block_number || self.block_hash != other.block_hash {
+			conflicts.push("the proof block is different".to_owned());
+		}
+		if self.end_block_number != other.end_block_number {
+			conflicts.push("the claimed block range is different".to_owned());
+		}
+
+		let mut outpoints = HashMap::new();
+		for proof in self.proofs.iter() {
+			for point in proof.outpoints().into_iter() {
+				outpoints.insert(point, proof.id());
+			}
+		}
+		for proof in other.proofs.iter() {
+			if self.has_proof(proof.id()) {
+				conflicts.push(format!("both files have a proof with id '{}'", proof.id()));
+			}
+			for point in proof.outpoints().into_iter() {
+				if let Some(id) = outpoints.get(&point) {
+					conflicts.push(format!(
+						"UTXO {} of proof '{}' is also used in proof '{}'",
+						point,
+						proof.id(),
+						id
+					));
+				}
+			}
 		}
+		conflicts
 	}
 
 	/// Find a proof with the given id.
-	pub fn take_proof(&mut self, id: &str) -> Option<bitcoin::Proof> {
+	pub fn take_proof(&mut self, id: &str) -> Option<Proof> {
 		let mut found = None;
 		for (idx, proof) in self.proofs.iter().enumerate() {
-			if proof.id == id {
+			if proof.id() == id {
 				found = Some(idx);
 			}
 		}
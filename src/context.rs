@@ -28,6 +28,12 @@ pub fn global_args<'a>() -> Vec<clap::Arg<'a, 'a>> {
 			.takes_value(false)
 			.help("use the Bitcoin testnet network")
 			.global(true),
+		clap::Arg::with_name("liquid")
+			.long("liquid")
+			.takes_value(false)
+			.conflicts_with("testnet")
+			.help("use the Liquid network")
+			.global(true),
 		clap::Arg::with_name("dry-run")
 			.short("n")
 			.long("dry-run")
@@ -50,20 +56,22 @@ impl<'a> Ctx<'a> {
 		}
 	}
 
-	fn proof_file_path(&self) -> &str {
+	pub fn proof_file_path(&self) -> &'a str {
 		self.matches.value_of("proof-file").expect("--proof-file cannot be empty")
 	}
 
-	pub fn load_proof_file(&mut self) -> common::ProofFile {
-		let mut file = {
-			let path = self.proof_file_path();
-			fs::File::open(path).expect(&format!("error opening file at '{}'", path))
-		};
+	fn read_proof_file(&self, path: &str) -> protos::ProofOfReserves {
+		let mut file = fs::File::open(path).expect(&format!("error opening file at '{}'", path));
 		let pf: protos::ProofOfReserves =
 			protobuf::parse_from_reader(&mut file).expect("error parsing reserve file");
 		if pf.get_version() != 1 {
 			panic!("Unknown proof file version: {}", pf.get_version())
 		}
+		pf
+	}
+
+	pub fn load_proof_file(&mut self) -> common::ProofFile {
+		let pf = self.read_proof_file(self.proof_file_path());
 		let proof_network = pf.get_network();
 		if let Some(args_network) = self.args_network() {
 			if args_network != proof_network {
@@ -78,22 +86,60 @@ impl<'a> Ctx<'a> {
 		pf.into()
 	}
 
+	/// Load a proof file other than the one given with --proof-file.
+	pub fn load_other_proof_file(&self, path: &str) -> common::ProofFile {
+		self.read_proof_file(path).into()
+	}
+
+	fn write_proof_file(&self, path: &str, proto: &protos::ProofOfReserves) {
+		let mut file = fs::File::create(path).expect(&format!("error opening file at '{}'", path));
+		proto.write_to_writer(&mut file).expect("error writing reserve file");
+	}
+
 	pub fn save_proof_file(&self, pf: common::ProofFile) {
 		if self.dry_run() {
 			println!("Dry-run: not writing proof file to disk.");
 			return;
 		}
 
-		let path = self.proof_file_path();
-		let mut file = fs::File::create(path).expect(&format!("error opening file at '{}'", path));
 		let proto: protos::ProofOfReserves = pf.into();
-		proto.write_to_writer(&mut file).expect("error writing reserve file");
+		self.write_proof_file(self.proof_file_path(), &proto);
+	}
+
+	/// Save a proof file other than the one given with --proof-file.
+	pub fn save_other_proof_file(&self, pf: common::ProofFile, path: &str) {
+		if self.dry_run() {
+			println!("Dry-run: not writing proof file '{}' to disk.", path);
+			return;
+		}
+
+		let proto: protos::ProofOfReserves = pf.into();
+		self.write_proof_file(path, &proto);
+	}
+
+	/// Save intermediate progress to the proof file and return the proof file to continue with.
+	pub fn checkpoint_proof_file(&self, pf: common::ProofFile) -> common::ProofFile {
+		if self.dry_run() {
+			return pf;
+		}
+
+		let proto: protos::ProofOfReserves = pf.into();
+		self.write_proof_file(self.proof_file_path(), &proto);
+		proto.into()
 	}
 
 	pub fn command(&self) -> &'a clap::ArgMatches<'a> {
 		self.matches.subcommand().1.unwrap()
 	}
 
+	/// The nested subcommand for commands that have subcommands themselves.
+	pub fn subcommand(&self) -> (&'a str, &'a clap::ArgMatches<'a>) {
+		match self.command().subcommand() {
+			(name, Some(matches)) => (name, matches),
+			_ => panic!("no subcommand given"),
+		}
+	}
+
 	pub fn verbosity(&self) -> usize {
 		self.matches.occurrences_of("verbose") as usize
 	}
@@ -102,6 +148,8 @@ impl<'a> Ctx<'a> {
 	fn args_network(&self) -> Option<protos::Network> {
 		if self.matches.is_present("testnet") {
 			Some(protos::Network::BITCOIN_TESTNET)
+		} else if self.matches.is_present("liquid") {
+			Some(protos::Network::LIQUID)
 		} else {
 			None
 		}
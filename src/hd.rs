@@ -0,0 +1,171 @@
+//! BIP32 key origins.
+//!
+//! Extended public keys can be given with their key origin in the `[fingerprint/path]xpub`
+//! notation used by output descriptors.  Without key origin, the xpub itself is taken as the
+//! root of the derivation.
+
+use std::fmt;
+use std::str::FromStr;
+
+use hex;
+use rbitcoin::blockdata::script::Script;
+use rbitcoin::util::bip32::{self, ChildNumber, ExtendedPubKey, Fingerprint};
+use secp256k1;
+
+use bitcoin;
+
+/// Parse a BIP32 derivation path.  The leading `m/` is optional and hardened steps can be
+/// marked with either `'` or `h`.
+pub fn parse_path(path: &str) -> Result<Vec<ChildNumber>, String> {
+	let path = path.trim().replace('h', "'");
+	let path = if path == "m" || path.starts_with("m/") {
+		path
+	} else if path.is_empty() {
+		"m".to_owned()
+	} else {
+		format!("m/{}", path)
+	};
+	bip32::parse_derivation_path(&path).map_err(|e| format!("invalid path '{}': {:?}", path, e))
+}
+
+/// The output script types that single keys derived from an xpub are used with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyScriptType {
+	P2pkh,
+	P2shP2wpkh,
+	P2wpkh,
+}
+
+impl FromStr for KeyScriptType {
+	type Err = String;
+
+	fn from_str(s: &str) -> Result<KeyScriptType, String> {
+		match s.to_lowercase().as_str() {
+			"p2pkh" => Ok(KeyScriptType::P2pkh),
+			"p2sh-p2wpkh" => Ok(KeyScriptType::P2shP2wpkh),
+			"p2wpkh" => Ok(KeyScriptType::P2wpkh),
+			_ => Err(format!("unsupported script type for xpub keys: {}", s)),
+		}
+	}
+}
+
+impl fmt::Display for KeyScriptType {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.write_str(match *self {
+			KeyScriptType::P2pkh => "P2PKH",
+			KeyScriptType::P2shP2wpkh => "P2SH-P2WPKH",
+			KeyScriptType::P2wpkh => "P2WPKH",
+		})
+	}
+}
+
+impl KeyScriptType {
+	/// The output script for the public key and the redeem script, if one is needed.
+	pub fn scripts(&self, pubkey: &[u8]) -> (Script, Option<Script>) {
+		match *self {
+			KeyScriptType::P2pkh => (bitcoin::p2pkh_script(pubkey), None),
+			KeyScriptType::P2shP2wpkh => {
+				let redeem_script = bitcoin::p2wpkh_script(pubkey);
+				(bitcoin::p2sh_script(&redeem_script), Some(redeem_script))
+			}
+			KeyScriptType::P2wpkh => (bitcoin::p2wpkh_script(pubkey), None),
+		}
+	}
+
+	/// The output descriptor for the given key expression.
+	pub fn descriptor(&self, key: &str) -> String {
+		match *self {
+			KeyScriptType::P2pkh => format!("pkh({})", key),
+			KeyScriptType::P2shP2wpkh => format!("sh(wpkh({}))", key),
+			KeyScriptType::P2wpkh => format!("wpkh({})", key),
+		}
+	}
+}
+
+/// The origin of a BIP32 key: the fingerprint of the master key and the path from the master key.
+#[derive(Debug, Clone, PartialEq)]
+pub struct KeyOrigin {
+	pub fingerprint: Fingerprint,
+	pub path: Vec<ChildNumber>,
+}
+
+/// An extended public key with its key origin.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OriginXpub {
+	pub origin: KeyOrigin,
+	pub xpub: ExtendedPubKey,
+}
+
+impl FromStr for OriginXpub {
+	type Err = String;
+
+	fn from_str(s: &str) -> Result<OriginXpub, String> {
+		let s = s.trim();
+		if !s.starts_with('[') {
+			let xpub = ExtendedPubKey::from_str(s).map_err(|e| format!("invalid xpub: {:?}", e))?;
+			return Ok(OriginXpub {
+				origin: KeyOrigin {
+					fingerprint: xpub.fingerprint(),
+					path: Vec::new(),
+				},
+				xpub: xpub,
+			});
+		}
+
+		let end = s.find(']').ok_or("key origin is missing the closing ']'")?;
+		let origin = &s[1..end];
+		let (fingerprint, path) = match origin.find('/') {
+			Some(idx) => (&origin[..idx], &origin[idx + 1..]),
+			None => (origin, ""),
+		};
+		let fingerprint = hex::decode(fingerprint).map_err(|_| "invalid hex fingerprint")?;
+		if fingerprint.len() != 4 {
+			return Err("the fingerprint must be 4 bytes".into());
+		}
+		let path = parse_path(path)?;
+
+		let xpub = ExtendedPubKey::from_str(&s[end + 1..])
+			.map_err(|e| format!("invalid xpub: {:?}", e))?;
+		if xpub.depth as usize != path.len() {
+			return Err(format!(
+				"the key origin path has {} steps, but the xpub has depth {}",
+				path.len(),
+				xpub.depth
+			));
+		}
+		Ok(OriginXpub {
+			origin: KeyOrigin {
+				fingerprint: Fingerprint::from(&fingerprint[..]),
+				path: path,
+			},
+			xpub: xpub,
+		})
+	}
+}
+
+impl OriginXpub {
+	/// Derive the public key at the given path relative to the xpub.
+	/// Returns the public key and its key origin.
+	pub fn derive(
+		&self,
+		path: &[ChildNumber],
+	) -> Result<(secp256k1::PublicKey, KeyOrigin), String> {
+		let secp = secp256k1::Secp256k1::verification_only();
+		let mut xpub = self.xpub.clone();
+		for child in path.iter() {
+			xpub = xpub
+				.ckd_pub(&secp, *child)
+				.map_err(|e| format!("failed to derive child key {}: {:?}", child, e))?;
+		}
+
+		let mut full_path = self.origin.path.clone();
+		full_path.extend_from_slice(path);
+		Ok((
+			xpub.public_key,
+			KeyOrigin {
+				fingerprint: self.origin.fingerprint,
+				path: full_path,
+			},
+		))
+	}
+}
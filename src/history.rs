@@ -0,0 +1,145 @@
+//! A local store of past attestations.
+//!
+//! The store is a directory with a copy of every archived proof file and an index file in JSON
+//! format that records the verification result of every archived file.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use hex;
+use serde_json::{self, Value};
+
+use utils;
+
+/// The name of the index file in the history directory.
+const INDEX_FILE: &'static str = "index.json";
+
+/// An archived attestation.
+#[derive(Debug, Clone)]
+pub struct Attestation {
+	/// The name of the archived copy of the proof file in the history directory.
+	pub file: String,
+	/// The SHA-256 hash of the proof file in hex.
+	pub sha256: String,
+	/// The time of archiving in seconds since the Unix epoch.
+	pub archived_at: u64,
+	pub network: String,
+	pub challenge: String,
+	pub block_number: u32,
+	pub block_hash: Option<String>,
+	pub nb_utxos: usize,
+	/// The verified total amounts per asset.
+	pub totals: BTreeMap<String, u64>,
+}
+
+impl Attestation {
+	pub fn to_json(&self) -> Value {
+		json!({
+			"file": self.file,
+			"sha256": self.sha256,
+			"archived_at": self.archived_at,
+			"network": self.network,
+			"challenge": self.challenge,
+			"block_number": self.block_number,
+			"block_hash": self.block_hash,
+			"nb_utxos": self.nb_utxos,
+			"totals": self.totals,
+		})
+	}
+
+	fn from_json(v: &Value) -> Result<Attestation, String> {
+		let string = |key: &str| {
+			v[key].as_str().map(|s| s.to_owned()).ok_or_else(|| format!("missing field {}", key))
+		};
+		let number = |key: &str| v[key].as_u64().ok_or_else(|| format!("missing field {}", key));
+		let totals = v["totals"].as_object().ok_or("missing field totals")?;
+		Ok(Attestation {
+			file: string("file")?,
+			sha256: string("sha256")?,
+			archived_at: number("archived_at")?,
+			network: string("network")?,
+			challenge: string("challenge")?,
+			block_number: number("block_number")? as u32,
+			block_hash: v["block_hash"].as_str().map(|s| s.to_owned()),
+			nb_utxos: number("nb_utxos")? as usize,
+			totals: totals
+				.iter()
+				.map(|(asset, amount)| match amount.as_u64() {
+					Some(amount) => Ok((asset.clone(), amount)),
+					None => Err(format!("invalid total for {}", asset)),
+				}).collect::<Result<_, String>>()?,
+		})
+	}
+}
+
+/// The history directory with its index.
+pub struct History {
+	dir: PathBuf,
+	/// The archived attestations in order of archiving.
+	pub attestations: Vec<Attestation>,
+}
+
+impl History {
+	/// Open the history in the given directory.  The directory is created when the history is
+	/// saved.
+	pub fn open(dir: &str) -> History {
+		let dir = PathBuf::from(dir);
+		let index = dir.join(INDEX_FILE);
+		let attestations = if index.exists() {
+			let contents = fs::read_to_string(&index)
+				.expect(&format!("error reading history index at '{}'", index.display()));
+			let json: Value = serde_json::from_str(&contents).expect("corrupt history index");
+			json["attestations"]
+				.as_array()
+				.expect("corrupt history index")
+				.iter()
+				.map(|a| {
+					Attestation::from_json(a)
+						.unwrap_or_else(|e| panic!("corrupt history index: {}", e))
+				}).collect()
+		} else {
+			Vec::new()
+		};
+		History {
+			dir: dir,
+			attestations: attestations,
+		}
+	}
+
+	/// The path of the archived copy of the proof file of the attestation.
+	pub fn path(&self, attestation: &Attestation) -> PathBuf {
+		self.dir.join(&attestation.file)
+	}
+
+	/// Find an archived attestation of a proof file with the given hash.
+	pub fn find(&self, sha256: &str) -> Option<&Attestation> {
+		self.attestations.iter().find(|a| a.sha256 == sha256)
+	}
+
+	/// Archive a copy of the proof file at the given path and add the attestation to the index.
+	pub fn archive(&mut self, proof_file: &Path, attestation: Attestation) {
+		fs::create_dir_all(&self.dir)
+			.expect(&format!("error creating history directory '{}'", self.dir.display()));
+		let target = self.path(&attestation);
+		if target.exists() {
+			panic!("The history file '{}' already exists.", target.display());
+		}
+		fs::copy(proof_file, &target)
+			.expect(&format!("error copying '{}' to the history", proof_file.display()));
+		self.attestations.push(attestation);
+
+		let index = json!({
+			"attestations": self.attestations.iter().map(|a| a.to_json()).collect::<Vec<_>>(),
+		});
+		let path = self.dir.join(INDEX_FILE);
+		let contents = serde_json::to_string_pretty(&index).unwrap();
+		fs::write(&path, contents).expect(&format!("error writing '{}'", path.display()));
+	}
+}
+
+/// The hash of the file at the given path in hex.
+pub fn file_sha256(path: &Path) -> String {
+	let contents = fs::read(path).expect(&format!("error reading file '{}'", path.display()));
+	hex::encode(&utils::sha256(&contents))
+}
@@ -0,0 +1,382 @@
+//! Proof of liabilities using a Merkle sum tree.
+//!
+//! Every customer balance is a leaf of the tree, hashed together with the customer id and a
+//! random nonce.  Every inner node commits to the hashes and sums of its children, so that the
+//! root commits to the total of all balances.  A customer can verify that their balance is
+//! included using the sibling nodes on the path from their leaf to the root.
+
+use rand;
+
+use protos;
+use utils;
+
+/// A customer balance.
+#[derive(Debug, Clone)]
+pub struct Entry {
+	pub customer_id: String,
+	pub balance: u64,
+	pub nonce: [u8; 32],
+}
+
+impl Entry {
+	pub fn new(customer_id: String, balance: u64) -> Entry {
+		Entry {
+			customer_id: customer_id,
+			balance: balance,
+			nonce: rand::random(),
+		}
+	}
+}
+
+/// A node in the Merkle sum tree.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Node {
+	pub hash: [u8; 32],
+	pub sum: u64,
+}
+
+impl Node {
+	/// The node used to pad levels with an odd number of nodes.
+	pub fn empty() -> Node {
+		Node {
+			hash: [0; 32],
+			sum: 0,
+		}
+	}
+
+	pub fn leaf(entry: &Entry) -> Node {
+		let mut data = b"reserves/liabilities/leaf".to_vec();
+		data.extend_from_slice(&(entry.customer_id.len() as u64).to_le_bytes());
+		data.extend_from_slice(entry.customer_id.as_bytes());
+		data.extend_from_slice(&entry.balance.to_le_bytes());
+		data.extend_from_slice(&entry.nonce);
+		Node {
+			hash: utils::sha256(&data),
+			sum: entry.balance,
+		}
+	}
+
+	pub fn parent(left: &Node, right: &Node) -> Result<Node, String> {
+		let sum = left.sum.checked_add(right.sum).ok_or("overflow in liabilities sum")?;
+		let mut data = b"reserves/liabilities/node".to_vec();
+		data.extend_from_slice(&left.hash);
+		data.extend_from_slice(&left.sum.to_le_bytes());
+		data.extend_from_slice(&right.hash);
+		data.extend_from_slice(&right.sum.to_le_bytes());
+		Ok(Node {
+			hash: utils::sha256(&data),
+			sum: sum,
+		})
+	}
+}
+
+fn hash_from_slice(b: &[u8]) -> [u8; 32] {
+	if b.len() != 32 {
+		panic!("corrupt liabilities hash");
+	}
+	let mut hash = [0; 32];
+	hash.copy_from_slice(b);
+	hash
+}
+
+/// The published commitment to the liabilities.
+#[derive(Debug, Clone)]
+pub struct Liabilities {
+	pub root: [u8; 32],
+	pub total: u64,
+	pub block_number: u32,
+}
+
+/// The full tree with all customer balances.
+#[derive(Debug)]
+pub struct Tree {
+	pub block_number: u32,
+	pub entries: Vec<Entry>,
+}
+
+impl Tree {
+	/// Calculate all levels of the tree, starting with the leaves.
+	fn levels(&self) -> Vec<Vec<Node>> {
+		let mut levels = vec![self.entries.iter().map(Node::leaf).collect::<Vec<_>>()];
+		while levels.last().unwrap().len() > 1 {
+			let next = levels
+				.last()
+				.unwrap()
+				.chunks(2)
+				.map(|pair| {
+					let right = pair.get(1).cloned().unwrap_or_else(Node::empty);
+					Node::parent(&pair[0], &right).expect("invalid liabilities tree")
+				}).collect();
+			levels.push(next);
+		}
+		levels
+	}
+
+	pub fn root(&self) -> Node {
+		self.levels().pop().unwrap().pop().unwrap_or_else(Node::empty)
+	}
+
+	/// The commitment to publish.
+	pub fn liabilities(&self) -> Liabilities {
+		let root = self.root();
+		Liabilities {
+			root: root.hash,
+			total: root.sum,
+			block_number: self.block_number,
+		}
+	}
+
+	/// Find the index of the entry for the given customer.
+	pub fn find(&self, customer_id: &str) -> Option<usize> {
+		self.entries.iter().position(|e| e.customer_id == customer_id)
+	}
+
+	/// Create the inclusion proof for the entry with the given index.
+	pub fn inclusion_proof(&self, index: usize) -> InclusionProof {
+		let levels = self.levels();
+		let mut path = Vec::with_capacity(levels.len());
+		let mut idx = index;
+		for level in levels.iter().take(levels.len() - 1) {
+			path.push(level.get(idx ^ 1).cloned().unwrap_or_else(Node::empty));
+			idx /= 2;
+		}
+		InclusionProof {
+			block_number: self.block_number,
+			entry: self.entries[index].clone(),
+			index: index as u32,
+			path: path,
+		}
+	}
+}
+
+/// The proof that an entry is included in the liabilities tree.
+#[derive(Debug)]
+pub struct InclusionProof {
+	pub block_number: u32,
+	pub entry: Entry,
+	pub index: u32,
+	pub path: Vec<Node>,
+}
+
+impl InclusionProof {
+	/// Calculate the root of the tree the entry is included in.
+	pub fn root(&self) -> Result<Node, String> {
+		if self.path.len() < 32 && (self.index as u64) >> self.path.len() != 0 {
+			return Err("entry index doesn't match the proof length".into());
+		}
+		let mut node = Node::leaf(&self.entry);
+		for (level, sibling) in self.path.iter().enumerate() {
+			node = if (self.index >> level) & 1 == 0 {
+				Node::parent(&node, sibling)?
+			} else {
+				Node::parent(sibling, &node)?
+			};
+		}
+		Ok(node)
+	}
+
+	/// Verify the proof against the published liabilities.
+	pub fn verify(&self, liabilities: &Liabilities) -> Result<(), String> {
+		if self.block_number != liabilities.block_number {
+			return Err(format!(
+				"proof is for block {} while the liabilities are for block {}",
+				self.block_number, liabilities.block_number
+			));
+		}
+		let root = self.root()?;
+		if root.hash != liabilities.root || root.sum != liabilities.total {
+			return Err("entry is not included in the liabilities tree".into());
+		}
+		Ok(())
+	}
+}
+
+impl From<protos::LiabilitiesEntry> for Entry {
+	fn from(e: protos::LiabilitiesEntry) -> Self {
+		Entry {
+			customer_id: e.get_customer_id().to_owned(),
+			balance: e.get_balance(),
+			nonce: hash_from_slice(e.get_nonce()),
+		}
+	}
+}
+
+impl Into<protos::LiabilitiesEntry> for Entry {
+	fn into(self) -> protos::LiabilitiesEntry {
+		let mut e = protos::LiabilitiesEntry::new();
+		e.set_customer_id(self.customer_id);
+		e.set_balance(self.balance);
+		e.set_nonce(self.nonce.to_vec());
+		e
+	}
+}
+
+impl From<protos::Liabilities> for Liabilities {
+	fn from(l: protos::Liabilities) -> Self {
+		Liabilities {
+			root: hash_from_slice(l.get_root()),
+			total: l.get_total(),
+			block_number: l.get_block_number(),
+		}
+	}
+}
+
+impl Into<protos::Liabilities> for Liabilities {
+	fn into(self) -> protos::Liabilities {
+		let mut l = protos::Liabilities::new();
+		l.set_root(self.root.to_vec());
+		l.set_total(self.total);
+		l.set_block_number(self.block_number);
+		l
+	}
+}
+
+impl From<protos::LiabilitiesTree> for Tree {
+	fn from(mut t: protos::LiabilitiesTree) -> Self {
+		Tree {
+			block_number: t.get_block_number(),
+			entries: t.take_entries().into_iter().map(Entry::from).collect(),
+		}
+	}
+}
+
+impl Into<protos::LiabilitiesTree> for Tree {
+	fn into(self) -> protos::LiabilitiesTree {
+		let mut t = protos::LiabilitiesTree::new();
+		t.set_block_number(self.block_number);
+		for entry in self.entries.into_iter() {
+			t.mut_entries().push(entry.into());
+		}
+		t
+	}
+}
+
+impl From<protos::InclusionProof> for InclusionProof {
+	fn from(mut p: protos::InclusionProof) -> Self {
+		InclusionProof {
+			block_number: p.get_block_number(),
+			entry: p.take_entry().into(),
+			index: p.get_index(),
+			path: p
+				.get_path()
+				.iter()
+				.map(|n| Node {
+					hash: hash_from_slice(n.get_hash()),
+					sum: n.get_sum(),
+				}).collect(),
+		}
+	}
+}
+
+impl Into<protos::InclusionProof> for InclusionProof {
+	fn into(self) -> protos::InclusionProof {
+		let mut p = protos::InclusionProof::new();
+		p.set_block_number(self.block_number);
+		p.set_entry(self.entry.into());
+		p.set_index(self.index);
+		for node in self.path.iter() {
+			let mut n = protos::SumTreeNode::new();
+			n.set_hash(node.hash.to_vec());
+			n.set_sum(node.sum);
+			p.mut_path().push(n);
+		}
+		p
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn tree(n: usize) -> Tree {
+		Tree {
+			block_number: 100,
+			entries: (0..n)
+				.map(|i| Entry::new(format!("customer{}", i), 1000 * i as u64))
+				.collect(),
+		}
+	}
+
+	#[test]
+	fn inclusion_proofs() {
+		for n in 1..10 {
+			let tree = tree(n);
+			let liabilities = tree.liabilities();
+			assert_eq!(liabilities.total, (0..n as u64).map(|i| 1000 * i).sum());
+			for idx in 0..n {
+				assert!(tree.inclusion_proof(idx).verify(&liabilities).is_ok());
+			}
+		}
+	}
+
+	#[test]
+	fn proto_roundtrip() {
+		let tree = tree(5);
+		let liabilities = tree.liabilities();
+		let proto: protos::InclusionProof = tree.inclusion_proof(3).into();
+		let proof: InclusionProof = proto.into();
+		assert!(proof.verify(&liabilities).is_ok());
+
+		let proto: protos::LiabilitiesTree = tree.into();
+		let tree: Tree = proto.into();
+		assert_eq!(tree.liabilities().root, liabilities.root);
+	}
+
+	#[test]
+	fn changed_balance() {
+		let tree = tree(5);
+		let liabilities = tree.liabilities();
+		let mut proof = tree.inclusion_proof(2);
+		proof.entry.balance -= 1;
+		assert!(proof.verify(&liabilities).is_err());
+
+		// A sibling sum can't be lowered either.
+		let mut proof = tree.inclusion_proof(2);
+		proof.path[0].sum -= 1;
+		assert!(proof.verify(&liabilities).is_err());
+	}
+
+	#[test]
+	fn changed_root() {
+		let tree = tree(5);
+		let mut liabilities = tree.liabilities();
+		let proof = tree.inclusion_proof(2);
+		liabilities.root[0] ^= 1;
+		assert!(proof.verify(&liabilities).is_err());
+
+		let mut liabilities = tree.liabilities();
+		liabilities.total += 1;
+		assert!(proof.verify(&liabilities).is_err());
+
+		let mut liabilities = tree.liabilities();
+		liabilities.block_number += 1;
+		assert!(proof.verify(&liabilities).is_err());
+	}
+
+	#[test]
+	fn changed_index() {
+		let tree = tree(5);
+		let liabilities = tree.liabilities();
+		let mut proof = tree.inclusion_proof(2);
+		proof.index = 3;
+		assert!(proof.verify(&liabilities).is_err());
+
+		// An index beyond the tree depth must not alias to another position.
+		proof.index = 2 + (1 << proof.path.len());
+		assert!(proof.verify(&liabilities).is_err());
+	}
+
+	#[test]
+	fn sum_overflow() {
+		let left = Node {
+			hash: [1; 32],
+			sum: u64::max_value(),
+		};
+		assert!(Node::parent(&left, &Node::empty()).is_ok());
+		let right = Node {
+			hash: [2; 32],
+			sum: 1,
+		};
+		assert!(Node::parent(&left, &right).is_err());
+	}
+}
@@ -0,0 +1,791 @@
+use std::collections::{BTreeMap, HashSet};
+
+use elements::confidential;
+use elements::encode::{deserialize, serialize};
+use elements::hashes::{hash160, sha256, Hash};
+use elements::opcodes;
+use elements::pset;
+use elements::script::{Builder, Instruction};
+use elements::secp256k1_zkp::{self as secp256k1, ecdsa, Message, PublicKey, Secp256k1};
+use elements::sighash::SigHashCache;
+use elements::{
+	AssetId, BlockHash, EcdsaSigHashType, OutPoint, PackedLockTime, Script, Sequence, Transaction,
+	TxIn, TxOut, TxOutSecrets, Txid,
+};
+
+use challenge::Challenge;
+use common::*;
+use protos;
+
+/// The asset id of L-BTC on the Liquid network.
+pub const LBTC_ASSET_ID: &'static str =
+	"6f0279e9ed041c3d710a9f57d0c02928416460c4b722ae3457a11eec381c526d";
+
+/// Human readable name for an asset, for the assets we know.
+pub fn asset_name(asset: &AssetId) -> String {
+	if asset.to_string() == LBTC_ASSET_ID {
+		format!("{} (L-BTC)", asset)
+	} else {
+		asset.to_string()
+	}
+}
+
+/// Check that the unblinding data opens the asset and value commitments of the output.
+pub fn verify_unblinding(txout: &TxOut, secrets: &TxOutSecrets) -> Result<(), String> {
+	let secp = Secp256k1::new();
+	match txout.asset {
+		confidential::Asset::Explicit(asset) => {
+			if asset != secrets.asset {
+				return Err("explicit asset doesn't match the unblinding data".into());
+			}
+		}
+		confidential::Asset::Confidential(_) => {
+			let comm =
+				confidential::Asset::new_confidential(&secp, secrets.asset, secrets.asset_bf);
+			if comm != txout.asset {
+				return Err("asset commitment doesn't open to the given asset".into());
+			}
+		}
+		confidential::Asset::Null => return Err("output has no asset".into()),
+	}
+	match txout.value {
+		confidential::Value::Explicit(value) => {
+			if value != secrets.value {
+				return Err("explicit value doesn't match the unblinding data".into());
+			}
+		}
+		confidential::Value::Confidential(_) => {
+			let generator =
+				txout.asset.into_asset_gen(&secp).ok_or("invalid asset commitment")?;
+			let comm = confidential::Value::new_confidential(
+				&secp,
+				secrets.value,
+				generator,
+				secrets.value_bf,
+			);
+			if comm != txout.value {
+				return Err("value commitment doesn't open to the given value".into());
+			}
+		}
+		confidential::Value::Null => return Err("output has no value".into()),
+	}
+	Ok(())
+}
+
+/// Get the asset and value of an output.  If the output is confidential, the unblinding data
+/// is required and the commitments are checked to open correctly.
+pub fn open_txout(
+	txout: &TxOut,
+	unblinding: Option<&TxOutSecrets>,
+) -> Result<(AssetId, u64), String> {
+	if let (confidential::Asset::Explicit(asset), confidential::Value::Explicit(value)) =
+		(txout.asset, txout.value)
+	{
+		return Ok((asset, value));
+	}
+
+	let secrets = unblinding.ok_or("output is confidential and no unblinding data is known")?;
+	verify_unblinding(txout, secrets)?;
+	Ok((secrets.asset, secrets.value))
+}
+
+fn unblinding_from_proto(u: &protos::Unblinding) -> TxOutSecrets {
+	TxOutSecrets {
+		asset: AssetId::from_slice(u.get_asset()).expect("corrupt asset in reserve file"),
+		asset_bf: confidential::AssetBlindingFactor::from_slice(u.get_asset_blinding_factor())
+			.expect("corrupt asset blinding factor in reserve file"),
+		value: u.get_value(),
+		value_bf: confidential::ValueBlindingFactor::from_slice(u.get_value_blinding_factor())
+			.expect("corrupt value blinding factor in reserve file"),
+	}
+}
+
+fn unblinding_to_proto(secrets: &TxOutSecrets) -> protos::Unblinding {
+	let mut u = protos::Unblinding::new();
+	u.set_asset(serialize(&secrets.asset));
+	u.set_value(secrets.value);
+	u.set_asset_blinding_factor(secrets.asset_bf.into_inner().as_ref().to_vec());
+	u.set_value_blinding_factor(secrets.value_bf.into_inner().as_ref().to_vec());
+	u
+}
+
+/// An internal type used to represent a Liquid transaction output with extra metadata.
+#[derive(Debug)]
+pub struct UTXO {
+	pub point: OutPoint,
+
+	pub pset_input: pset::Input,
+
+	// The data to open the commitments of confidential outputs.
+	pub unblinding: Option<TxOutSecrets>,
+
+	// meta-information: This information is not critical for proof verification.
+	pub block_number: u32,
+	pub block_hash: Option<BlockHash>,
+}
+
+impl UTXO {
+	/// Get the tx output for this UTXO.
+	pub fn txout(&self) -> &TxOut {
+		if let Some(ref txout) = self.pset_input.witness_utxo {
+			txout
+		} else if let Some(ref tx) = self.pset_input.non_witness_utxo {
+			&tx.output[self.point.vout as usize]
+		} else {
+			panic!("Incorrect PSET input data for UTXO {}", self.point);
+		}
+	}
+
+	/// Returns whether the asset or the value of this UTXO is confidential.
+	pub fn is_confidential(&self) -> bool {
+		let txout = self.txout();
+		!txout.asset.is_explicit() || !txout.value.is_explicit()
+	}
+
+	/// Get the asset and the amount of this UTXO.
+	pub fn asset_value(&self) -> (AssetId, u64) {
+		open_txout(self.txout(), self.unblinding.as_ref())
+			.unwrap_or_else(|e| panic!("Can't open UTXO {}: {}", self.point, e))
+	}
+}
+
+impl From<protos::UTXO> for UTXO {
+	fn from(o: protos::UTXO) -> Self {
+		UTXO {
+			point: OutPoint {
+				txid: Txid::from_slice(o.get_txid()).expect("corrupt txid in reserve file"),
+				vout: o.get_vout(),
+			},
+			pset_input: deserialize(o.get_psbt_input())
+				.expect("corrupt PSET input in reserve file"),
+			unblinding: if o.has_unblinding() {
+				Some(unblinding_from_proto(o.get_unblinding()))
+			} else {
+				None
+			},
+			block_number: o.get_block_number(),
+			block_hash: if o.get_block_hash().len() != 0 {
+				Some(BlockHash::from_slice(o.get_block_hash()).expect("corrupt block hash"))
+			} else {
+				None
+			},
+		}
+	}
+}
+
+impl Into<protos::UTXO> for UTXO {
+	fn into(self) -> protos::UTXO {
+		let mut p = protos::UTXO::new();
+		p.set_txid(self.point.txid[..].into());
+		p.set_vout(self.point.vout);
+		p.set_psbt_input(serialize(&self.pset_input));
+		if let Some(ref unblinding) = self.unblinding {
+			p.set_unblinding(unblinding_to_proto(unblinding));
+		}
+		p.set_block_number(self.block_number);
+		if let Some(hash) = self.block_hash {
+			p.set_block_hash(hash[..].into());
+		}
+		p
+	}
+}
+
+/// Generate the challenge input based on the challenge.
+/// This is done in exactly the same way as for Bitcoin proofs.
+pub fn challenge_txin(challenge: &Challenge) -> TxIn {
+	let challenge_hash = challenge.hash();
+	TxIn {
+		previous_output: OutPoint {
+			txid: Txid::from_inner(challenge_hash),
+			vout: 0,
+		},
+		is_pegin: false,
+		script_sig: Script::new(),
+		sequence: Sequence::MAX,
+		asset_issuance: Default::default(),
+		witness: Default::default(),
+	}
+}
+
+/// The unspendable script used for the outputs of Liquid proof transactions.
+fn unspendable_script() -> Script {
+	Script::new_p2pkh(&hash160::Hash::hash(&[0]).into())
+}
+
+#[derive(Debug)]
+pub struct Proof {
+	pub id: String,
+	pub status: Proof_Status,
+
+	pub proof_tx: Option<Transaction>,
+	pub utxos: Vec<UTXO>,
+	pub pset: Option<pset::PartiallySignedTransaction>,
+}
+
+impl From<protos::Proof> for Proof {
+	fn from(p: protos::Proof) -> Self {
+		Proof {
+			id: p.id.into(),
+			status: p.status,
+			proof_tx: if p.proof_tx.len() > 0 {
+				Some(deserialize(&p.proof_tx).expect("corrupt proof tx"))
+			} else {
+				None
+			},
+			utxos: p
+				.utxos
+				.into_vec()
+				.into_iter()
+				.map(|u| {
+					let i: UTXO = u.into();
+					i
+				}).collect(),
+			pset: if p.psbt.len() > 0 {
+				Some(deserialize(&p.psbt).expect("corrupt PSET in proof"))
+			} else {
+				None
+			},
+		}
+	}
+}
+
+impl Into<protos::Proof> for Proof {
+	fn into(self) -> protos::Proof {
+		let mut p = protos::Proof::new();
+		p.set_id(self.id.into());
+		p.set_status(self.status);
+		if let Some(proof_tx) = self.proof_tx {
+			p.set_proof_tx(serialize(&proof_tx));
+		}
+		p.set_utxos(
+			self.utxos
+				.into_iter()
+				.map(|u| {
+					let i: protos::UTXO = u.into();
+					i
+				}).collect(),
+		);
+		if let Some(pset) = self.pset {
+			p.set_psbt(serialize(&pset));
+		}
+		p
+	}
+}
+
+impl Proof {
+	pub fn new(id: String, status: Proof_Status) -> Proof {
+		Proof {
+			id: id,
+			status: status,
+			proof_tx: None,
+			utxos: vec![],
+			pset: None,
+		}
+	}
+
+	/// Discard the PSET and return to gathering UTXOs.
+	pub fn reopen(&mut self) {
+		self.pset = None;
+		self.proof_tx = None;
+		self.status = Proof_Status::GATHERING_UTXOS;
+	}
+
+	/// Panic if any confidential UTXO of the proof still lacks its unblinding data.
+	pub fn check_unblinding(&self) {
+		let missing: Vec<String> = self
+			.utxos
+			.iter()
+			.filter(|u| u.is_confidential() && u.unblinding.is_none())
+			.map(|u| u.point.to_string())
+			.collect();
+		if !missing.is_empty() {
+			panic!(
+				"Proof '{}' has confidential UTXOs without unblinding data, add it with \
+				 unblind-utxo: {}",
+				self.id,
+				missing.join(", ")
+			);
+		}
+	}
+
+	/// Advance the proof to the SIGNING state by constructing a PSET to be signed.
+	///
+	/// The proof tx has one explicit output per asset that holds the sum of all the inputs of
+	/// that asset.
+	pub fn start_signing(&mut self, challenge: &Challenge) {
+		self.check_unblinding();
+
+		let mut pset = pset::PartiallySignedTransaction::new_v2();
+		pset.global.tx_data.fallback_locktime = Some(PackedLockTime(0xffffffff));
+
+		// Add the challenge txin.
+		let challenge_txin = challenge_txin(challenge);
+		let mut challenge_input = pset::Input::from_prevout(challenge_txin.previous_output);
+		challenge_input.witness_utxo = Some(TxOut {
+			asset: confidential::Asset::Null,
+			value: confidential::Value::Explicit(0),
+			nonce: confidential::Nonce::Null,
+			script_pubkey: Builder::new().push_opcode(opcodes::OP_TRUE).into_script(),
+			witness: Default::default(),
+		});
+		challenge_input.final_script_sig = Some(Script::new());
+		pset.add_input(challenge_input);
+
+		// Then add all proof UTXOs as inputs.
+		let mut totals = BTreeMap::new();
+		for utxo in self.utxos.iter() {
+			let mut input = utxo.pset_input.clone();
+			input.previous_txid = utxo.point.txid;
+			input.previous_output_index = utxo.point.vout;
+			pset.add_input(input);
+
+			let (asset, value) = utxo.asset_value();
+			*totals.entry(asset).or_insert(0) += value;
+		}
+
+		// Add an unspendable output for every asset.
+		for (asset, value) in totals.into_iter() {
+			pset.add_output(pset::Output::new_explicit(unspendable_script(), value, asset, None));
+		}
+
+		self.pset = Some(pset);
+		self.status = Proof_Status::SIGNING;
+	}
+
+	/// Return all the outpoins this proof is spending.
+	pub fn spending_utxos(&self) -> HashSet<OutPoint> {
+		let mut set = HashSet::new();
+		// Skip the challenge UTXO.
+		for input in self.proof_tx.as_ref().unwrap().input.iter().skip(1) {
+			if !set.insert(input.previous_output) {
+				panic!("Proof '{}' is spending UTXO {} twice!", self.id, input.previous_output);
+			}
+		}
+		set
+	}
+
+	/// The amounts per asset of the outputs of the proof tx.
+	pub fn amounts(&self) -> BTreeMap<AssetId, u64> {
+		let tx = self.proof_tx.as_ref().expect("proof in wrong state");
+		let mut totals = BTreeMap::new();
+		for (idx, output) in tx.output.iter().enumerate() {
+			match (output.asset, output.value) {
+				(confidential::Asset::Explicit(asset), confidential::Value::Explicit(value)) => {
+					*totals.entry(asset).or_insert(0) += value;
+				}
+				_ => panic!("output #{} of proof tx '{}' is not explicit", idx, self.id),
+			}
+		}
+		totals
+	}
+
+	/// Verify the proof against the given prevouts and return the proven amount per asset.
+	pub fn verify(&self, challenge: &Challenge, prevouts: Vec<TxOut>) -> BTreeMap<AssetId, u64> {
+		self.check_unblinding();
+		let tx = self.proof_tx.as_ref().expect("proof in wrong state");
+		if tx.output.is_empty() {
+			panic!("Proof tx for proof '{}' has no outputs!", self.id);
+		}
+		if tx.input.len() <= 1 {
+			panic!(
+				"Proof tx for proof '{}' must have more than one inputs (has {})!",
+				self.id,
+				tx.input.len()
+			);
+		}
+		if prevouts.len() != tx.input.len() - 1 {
+			panic!("Wrong amount of prevouts provided");
+		}
+
+		// First check the challenge input.
+		let challenge_txin = challenge_txin(challenge);
+		if tx.input[0].previous_output != challenge_txin.previous_output {
+			panic!("Challenge for proof '{}' is incorrect", self.id);
+		}
+
+		// Verify other inputs against prevouts and calculate the amount per asset.
+		// For confidential prevouts, the unblinding data from the proof's UTXOs is used.
+		let mut input_totals = BTreeMap::new();
+		for (idx, txout) in prevouts.into_iter().enumerate() {
+			verify_input(tx, idx + 1, &txout).expect(&format!(
+				"script verification of input #{} of proof tx '{}' failed",
+				idx, self.id
+			));
+
+			let point = tx.input[idx + 1].previous_output;
+			let unblinding =
+				self.utxos.iter().find(|u| u.point == point).and_then(|u| u.unblinding.as_ref());
+			let (asset, value) = open_txout(&txout, unblinding).unwrap_or_else(|e| {
+				panic!("can't open input #{} of proof tx '{}': {}", idx, self.id, e)
+			});
+			*input_totals.entry(asset).or_insert(0) += value;
+		}
+
+		// Verify the amounts per asset.  They must match exactly; no fee.
+		let output_totals = self.amounts();
+		if input_totals != output_totals {
+			panic!(
+				"Amounts of proof '{}' do not add up! Inputs: {:?}; Outputs: {:?}.",
+				self.id, input_totals, output_totals,
+			);
+		}
+
+		input_totals
+	}
+}
+
+/// Collect the data pushes from a script_sig.
+fn script_pushes(script: &Script) -> Result<Vec<Vec<u8>>, String> {
+	let mut pushes = Vec::new();
+	for ins in script.instructions() {
+		match ins.map_err(|e| format!("invalid script: {:?}", e))? {
+			Instruction::PushBytes(b) => pushes.push(b.to_vec()),
+			Instruction::Op(op) => return Err(format!("non-push opcode in script_sig: {:?}", op)),
+		}
+	}
+	Ok(pushes)
+}
+
+/// Check a DER-encoded signature with sighash byte against the given pubkey.
+/// Only SIGHASH_ALL signatures are accepted, so that the signature commits to the challenge
+/// input and all outputs.
+fn check_sig(
+	secp: &Secp256k1<secp256k1::VerifyOnly>,
+	sig: &[u8],
+	pubkey: &[u8],
+	sighash: &[u8; 32],
+) -> Result<(), String> {
+	if sig.is_empty() {
+		return Err("empty signature".into());
+	}
+	if sig[sig.len() - 1] as u32 != EcdsaSigHashType::All.as_u32() {
+		return Err(format!("sighash type {:#x} is not SIGHASH_ALL", sig[sig.len() - 1]));
+	}
+	let mut signature = ecdsa::Signature::from_der(&sig[..sig.len() - 1])
+		.map_err(|e| format!("invalid signature encoding: {}", e))?;
+	signature.normalize_s();
+	let pubkey = PublicKey::from_slice(pubkey).map_err(|e| format!("invalid pubkey: {}", e))?;
+	let msg = Message::from_slice(&sighash[..]).unwrap();
+	secp.verify_ecdsa(&msg, &signature, &pubkey).map_err(|e| format!("invalid signature: {}", e))
+}
+
+/// Parse a bare m-of-n CHECKMULTISIG script into m and the pubkeys.
+fn parse_multisig(script: &Script) -> Result<(usize, Vec<Vec<u8>>), String> {
+	let mut ins = Vec::new();
+	for i in script.instructions() {
+		ins.push(i.map_err(|e| format!("invalid witness script: {:?}", e))?);
+	}
+	let small_int = |i: &Instruction| -> Option<usize> {
+		match *i {
+			Instruction::Op(op) => {
+				let n = op.into_u8();
+				let first = opcodes::all::OP_PUSHNUM_1.into_u8();
+				let last = opcodes::all::OP_PUSHNUM_16.into_u8();
+				if n >= first && n <= last {
+					Some((n - first + 1) as usize)
+				} else {
+					None
+				}
+			}
+			_ => None,
+		}
+	};
+	if ins.len() < 4 || ins[ins.len() - 1] != Instruction::Op(opcodes::all::OP_CHECKMULTISIG) {
+		return Err("only CHECKMULTISIG witness scripts are supported".into());
+	}
+	let m = small_int(&ins[0]).ok_or("invalid multisig threshold")?;
+	let n = small_int(&ins[ins.len() - 2]).ok_or("invalid multisig key count")?;
+	let mut keys = Vec::new();
+	for i in ins[1..ins.len() - 2].iter() {
+		match *i {
+			Instruction::PushBytes(b) => keys.push(b.to_vec()),
+			_ => return Err("invalid multisig pubkey".into()),
+		}
+	}
+	if keys.len() != n || m > n {
+		return Err("inconsistent multisig script".into());
+	}
+	Ok((m, keys))
+}
+
+/// Verify the signatures of a segwit v0 input.
+fn verify_witness(
+	secp: &Secp256k1<secp256k1::VerifyOnly>,
+	tx: &Transaction,
+	idx: usize,
+	program: &Script,
+	witness: &[Vec<u8>],
+	value: confidential::Value,
+) -> Result<(), String> {
+	let mut cache = SigHashCache::new(tx);
+	if program.is_v0_p2wpkh() {
+		if witness.len() != 2 {
+			return Err("invalid p2wpkh witness".into());
+		}
+		if hash160::Hash::hash(&witness[1])[..] != program[2..] {
+			return Err("witness pubkey doesn't match the output".into());
+		}
+		let script_code = Script::new_p2pkh(&hash160::Hash::hash(&witness[1]).into());
+		let sighash = cache.segwitv0_sighash(idx, &script_code, value, EcdsaSigHashType::All);
+		check_sig(secp, &witness[0], &witness[1], &sighash.into_inner())
+	} else if program.is_v0_p2wsh() {
+		if witness.len() < 2 {
+			return Err("invalid p2wsh witness".into());
+		}
+		let witness_script = Script::from(witness[witness.len() - 1].clone());
+		if sha256::Hash::hash(&witness_script[..])[..] != program[2..] {
+			return Err("witness script doesn't match the output".into());
+		}
+		let (m, keys) = parse_multisig(&witness_script)?;
+		// The first element is the dummy for the CHECKMULTISIG bug, which has to be empty.
+		if !witness[0].is_empty() {
+			return Err("the CHECKMULTISIG dummy element is not empty".into());
+		}
+		let sigs = &witness[1..witness.len() - 1];
+		if sigs.len() != m {
+			return Err(format!("expected {} signatures, found {}", m, sigs.len()));
+		}
+		let sighash = cache
+			.segwitv0_sighash(idx, &witness_script, value, EcdsaSigHashType::All)
+			.into_inner();
+		// Signatures have to be in the same order as the keys.
+		let mut key_iter = keys.iter();
+		for sig in sigs.iter() {
+			let mut ok = false;
+			while let Some(key) = key_iter.next() {
+				if check_sig(secp, sig, key, &sighash).is_ok() {
+					ok = true;
+					break;
+				}
+			}
+			if !ok {
+				return Err("invalid multisig signature".into());
+			}
+		}
+		Ok(())
+	} else {
+		Err(format!("unsupported witness program: {}", program))
+	}
+}
+
+/// Verify the signatures of the given input of the Liquid transaction.
+///
+/// Because there is no consensus library for Elements we can use here, we verify the standard
+/// script types ourselves: p2pkh, p2wpkh and p2wsh multisig, either native or wrapped in p2sh.
+pub fn verify_input(tx: &Transaction, idx: usize, prevout: &TxOut) -> Result<(), String> {
+	let secp = Secp256k1::verification_only();
+	let input = &tx.input[idx];
+	let spk = &prevout.script_pubkey;
+	let witness = &input.witness.script_witness;
+
+	if spk.is_p2pkh() {
+		let pushes = script_pushes(&input.script_sig)?;
+		if pushes.len() != 2 {
+			return Err("invalid p2pkh script_sig".into());
+		}
+		if hash160::Hash::hash(&pushes[1])[..] != spk[3..23] {
+			return Err("script_sig pubkey doesn't match the output".into());
+		}
+		let sighash = SigHashCache::new(tx).legacy_sighash(idx, spk, EcdsaSigHashType::All);
+		check_sig(&secp, &pushes[0], &pushes[1], &sighash.into_inner())
+	} else if spk.is_p2sh() {
+		let pushes = script_pushes(&input.script_sig)?;
+		if pushes.len() != 1 {
+			return Err("only p2sh-wrapped segwit is supported".into());
+		}
+		let redeem = Script::from(pushes[0].clone());
+		if hash160::Hash::hash(&redeem[..])[..] != spk[2..22] {
+			return Err("redeem script doesn't match the output".into());
+		}
+		verify_witness(&secp, tx, idx, &redeem, witness, prevout.value)
+	} else {
+		if !input.script_sig.is_empty() {
+			return Err("non-empty script_sig for segwit input".into());
+		}
+		verify_witness(&secp, tx, idx, spk, witness, prevout.value)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use elements::secp256k1_zkp::SecretKey;
+
+	fn secret(n: u8) -> SecretKey {
+		SecretKey::from_slice(&[n; 32]).unwrap()
+	}
+
+	fn pubkey(secret: &SecretKey) -> Vec<u8> {
+		PublicKey::from_secret_key(&Secp256k1::new(), secret).serialize().to_vec()
+	}
+
+	fn txout(script_pubkey: Script, value: u64) -> TxOut {
+		TxOut {
+			asset: confidential::Asset::Explicit(AssetId::from_slice(&[1; 32]).unwrap()),
+			value: confidential::Value::Explicit(value),
+			nonce: confidential::Nonce::Null,
+			script_pubkey: script_pubkey,
+			witness: Default::default(),
+		}
+	}
+
+	/// A proof tx spending a single UTXO of 1000 after the challenge input.
+	fn proof_tx() -> Transaction {
+		Transaction {
+			version: 2,
+			lock_time: PackedLockTime(0xffffffff),
+			input: vec![
+				challenge_txin(&Challenge::new("Liquid test".into())),
+				TxIn {
+					previous_output: OutPoint::new(Txid::from_inner([2; 32]), 0),
+					is_pegin: false,
+					script_sig: Script::new(),
+					sequence: Sequence::MAX,
+					asset_issuance: Default::default(),
+					witness: Default::default(),
+				},
+			],
+			output: vec![txout(unspendable_script(), 1000)],
+		}
+	}
+
+	/// Sign the sighash and append the given sighash type byte.
+	fn sign(secret: &SecretKey, sighash: &[u8], sighash_type: EcdsaSigHashType) -> Vec<u8> {
+		let msg = Message::from_slice(sighash).unwrap();
+		let sig = Secp256k1::new().sign_ecdsa(&msg, secret);
+		let mut sig = sig.serialize_der().to_vec();
+		sig.push(sighash_type.as_u32() as u8);
+		sig
+	}
+
+	/// Create a p2wpkh prevout for the given key and sign the proof tx spending it.
+	fn p2wpkh(
+		key: &SecretKey,
+		signer: &SecretKey,
+		sighash_type: EcdsaSigHashType,
+	) -> (Transaction, TxOut) {
+		let hash = hash160::Hash::hash(&pubkey(key));
+		let prevout = txout(Script::new_v0_wpkh(&hash.into()), 1000);
+		let mut tx = proof_tx();
+		let script_code = Script::new_p2pkh(&hash.into());
+		let sighash = SigHashCache::new(&tx).segwitv0_sighash(
+			1,
+			&script_code,
+			prevout.value,
+			sighash_type,
+		);
+		let sig = sign(signer, &sighash[..], sighash_type);
+		tx.input[1].witness.script_witness = vec![sig, pubkey(signer)];
+		(tx, prevout)
+	}
+
+	#[test]
+	fn p2wpkh_valid() {
+		let (tx, prevout) = p2wpkh(&secret(1), &secret(1), EcdsaSigHashType::All);
+		assert_eq!(verify_input(&tx, 1, &prevout), Ok(()));
+	}
+
+	#[test]
+	fn p2wpkh_changed() {
+		let (tx, prevout) = p2wpkh(&secret(1), &secret(1), EcdsaSigHashType::All);
+
+		// The segwit sighash commits to the value of the prevout.
+		let changed = txout(prevout.script_pubkey.clone(), 1001);
+		assert!(verify_input(&tx, 1, &changed).is_err());
+
+		let mut changed = tx.clone();
+		changed.output[0].value = confidential::Value::Explicit(1001);
+		assert!(verify_input(&changed, 1, &prevout).is_err());
+
+		let mut changed = tx.clone();
+		changed.input[0] = challenge_txin(&Challenge::new("other challenge".into()));
+		assert!(verify_input(&changed, 1, &prevout).is_err());
+	}
+
+	#[test]
+	fn p2wpkh_wrong_key() {
+		// The pubkey in the witness doesn't match the output.
+		let (tx, prevout) = p2wpkh(&secret(1), &secret(2), EcdsaSigHashType::All);
+		assert!(verify_input(&tx, 1, &prevout).is_err());
+
+		// The signature is made with another key than the one in the witness.
+		let (mut tx, prevout) = p2wpkh(&secret(1), &secret(2), EcdsaSigHashType::All);
+		tx.input[1].witness.script_witness[1] = pubkey(&secret(1));
+		assert!(verify_input(&tx, 1, &prevout).is_err());
+	}
+
+	#[test]
+	fn only_sighash_all() {
+		let types = [
+			EcdsaSigHashType::None,
+			EcdsaSigHashType::Single,
+			EcdsaSigHashType::AllPlusAnyoneCanPay,
+		];
+		for sighash_type in types.iter() {
+			let (tx, prevout) = p2wpkh(&secret(1), &secret(1), *sighash_type);
+			assert!(verify_input(&tx, 1, &prevout).unwrap_err().contains("SIGHASH_ALL"));
+		}
+	}
+
+	#[test]
+	fn p2pkh() {
+		let pk = pubkey(&secret(1));
+		let prevout = txout(Script::new_p2pkh(&hash160::Hash::hash(&pk).into()), 1000);
+		let mut tx = proof_tx();
+		let sighash =
+			SigHashCache::new(&tx).legacy_sighash(1, &prevout.script_pubkey, EcdsaSigHashType::All);
+		let sig = sign(&secret(1), &sighash[..], EcdsaSigHashType::All);
+		tx.input[1].script_sig = Builder::new().push_slice(&sig).push_slice(&pk).into_script();
+		assert_eq!(verify_input(&tx, 1, &prevout), Ok(()));
+
+		let sig = sign(&secret(2), &sighash[..], EcdsaSigHashType::All);
+		tx.input[1].script_sig = Builder::new().push_slice(&sig).push_slice(&pk).into_script();
+		assert!(verify_input(&tx, 1, &prevout).is_err());
+	}
+
+	#[test]
+	fn p2sh_p2wsh_multisig() {
+		let keys: Vec<SecretKey> = (1..4).map(secret).collect();
+		let witness_script = Builder::new()
+			.push_opcode(opcodes::all::OP_PUSHNUM_2)
+			.push_slice(&pubkey(&keys[0]))
+			.push_slice(&pubkey(&keys[1]))
+			.push_slice(&pubkey(&keys[2]))
+			.push_opcode(opcodes::all::OP_PUSHNUM_3)
+			.push_opcode(opcodes::all::OP_CHECKMULTISIG)
+			.into_script();
+		let redeem = Script::new_v0_wsh(&sha256::Hash::hash(&witness_script[..]).into());
+		let spk = Script::new_p2sh(&hash160::Hash::hash(&redeem[..]).into());
+		let prevout = txout(spk, 1000);
+
+		let mut tx = proof_tx();
+		tx.input[1].script_sig = Builder::new().push_slice(&redeem[..]).into_script();
+		let sighash = SigHashCache::new(&tx).segwitv0_sighash(
+			1,
+			&witness_script,
+			prevout.value,
+			EcdsaSigHashType::All,
+		);
+		let sig1 = sign(&keys[0], &sighash[..], EcdsaSigHashType::All);
+		let sig3 = sign(&keys[2], &sighash[..], EcdsaSigHashType::All);
+
+		let witness = vec![vec![], sig1.clone(), sig3.clone(), witness_script.to_bytes()];
+		tx.input[1].witness.script_witness = witness;
+		assert_eq!(verify_input(&tx, 1, &prevout), Ok(()));
+
+		// Signatures out of order.
+		let witness = vec![vec![], sig3.clone(), sig1.clone(), witness_script.to_bytes()];
+		tx.input[1].witness.script_witness = witness;
+		assert!(verify_input(&tx, 1, &prevout).is_err());
+
+		// Not enough signatures.
+		let witness = vec![vec![], sig1.clone(), witness_script.to_bytes()];
+		tx.input[1].witness.script_witness = witness;
+		assert!(verify_input(&tx, 1, &prevout).is_err());
+
+		// A non-empty dummy element violates NULLDUMMY.
+		let witness = vec![vec![1], sig1.clone(), sig3.clone(), witness_script.to_bytes()];
+		tx.input[1].witness.script_witness = witness;
+		assert!(verify_input(&tx, 1, &prevout).is_err());
+	}
+}
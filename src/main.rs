@@ -1,16 +1,23 @@
 extern crate bitcoin as rbitcoin;
 #[macro_use]
 extern crate log;
+extern crate base64;
 extern crate bitcoin_amount;
 extern crate bitcoinconsensus;
 extern crate bitcoincore_rpc;
 extern crate clap;
 extern crate crypto;
+extern crate elements;
 extern crate fern;
 extern crate hex;
+extern crate jsonrpc;
+extern crate k256;
 extern crate protobuf;
+extern crate rand;
 extern crate rpassword;
 extern crate secp256k1;
+#[macro_use]
+extern crate serde_json;
 extern crate trezor;
 
 use std::panic;
@@ -20,10 +27,16 @@ use clap::{App, AppSettings};
 
 mod backend;
 mod bitcoin;
+mod challenge;
 mod cmd;
 mod common;
 mod context;
+mod hd;
+mod history;
+mod liabilities;
+mod liquid;
 mod protos;
+mod provisions;
 mod utils;
 
 fn setup_logger(lvl: log::LevelFilter) {
@@ -63,14 +76,31 @@ fn main() {
 		.args(&context::global_args())
 		//TODO(stevenroose) consider not having clap autosort them
 		.subcommand(cmd::init::subcommand())
+		.subcommand(cmd::challenge::subcommand())
 		.subcommand(cmd::inspect::subcommand())
 		.subcommand(cmd::drop::subcommand())
 		.subcommand(cmd::verify::subcommand())
+		.subcommand(cmd::diff::subcommand())
+		.subcommand(cmd::archive::subcommand())
+		.subcommand(cmd::history::subcommand())
+		.subcommand(cmd::watch::subcommand())
 		.subcommand(cmd::add_utxo::subcommand())
 		.subcommand(cmd::fetch_utxos::subcommand())
+		.subcommand(cmd::import_utxos::subcommand())
+		.subcommand(cmd::import_psbt::subcommand())
 		.subcommand(cmd::add_proof::subcommand())
 		.subcommand(cmd::drop_utxos::subcommand())
+		.subcommand(cmd::move_utxos::subcommand())
+		.subcommand(cmd::rename::subcommand())
+		.subcommand(cmd::merge::subcommand())
+		.subcommand(cmd::split::subcommand())
+		.subcommand(cmd::reopen::subcommand())
 		.subcommand(cmd::sign::subcommand())
+		.subcommand(cmd::unblind_utxo::subcommand())
+		.subcommand(cmd::provisions::subcommand())
+		.subcommand(cmd::liabilities::subcommand())
+		.subcommand(cmd::verify_inclusion::subcommand())
+		.subcommand(cmd::solvency::subcommand())
 		.get_matches();
 
 	let mut ctx = context::Ctx::new(&matches);
@@ -84,15 +114,32 @@ fn main() {
 	// Execute other commands.
 	match matches.subcommand() {
 		("init", _) => cmd::init::execute(&mut ctx),
+		("challenge", _) => cmd::challenge::execute(&mut ctx),
 		("inspect", _) => cmd::inspect::execute(&mut ctx),
 		("drop", _) => cmd::drop::execute(&mut ctx),
 		//("add-proof", Some(sub)) => cmd::add_proof::execute(&mut ctx, sub),
 		("verify", _) => cmd::verify::execute(&mut ctx),
+		("diff", _) => cmd::diff::execute(&mut ctx),
+		("archive", _) => cmd::archive::execute(&mut ctx),
+		("history", _) => cmd::history::execute(&mut ctx),
+		("watch", _) => cmd::watch::execute(&mut ctx),
 		("add-utxo", _) => cmd::add_utxo::execute(&mut ctx),
 		("fetch-utxos", _) => cmd::fetch_utxos::execute(&mut ctx),
+		("import-utxos", _) => cmd::import_utxos::execute(&mut ctx),
+		("import-psbt", _) => cmd::import_psbt::execute(&mut ctx),
 		("add-proof", _) => cmd::add_proof::execute(&mut ctx),
 		("drop-utxos", _) => cmd::drop_utxos::execute(&mut ctx),
+		("move-utxos", _) => cmd::move_utxos::execute(&mut ctx),
+		("rename", _) => cmd::rename::execute(&mut ctx),
+		("merge", _) => cmd::merge::execute(&mut ctx),
+		("split", _) => cmd::split::execute(&mut ctx),
+		("reopen", _) => cmd::reopen::execute(&mut ctx),
 		("sign", _) => cmd::sign::execute(&mut ctx),
+		("unblind-utxo", _) => cmd::unblind_utxo::execute(&mut ctx),
+		("provisions", _) => cmd::provisions::execute(&mut ctx),
+		("liabilities", _) => cmd::liabilities::execute(&mut ctx),
+		("verify-inclusion", _) => cmd::verify_inclusion::execute(&mut ctx),
+		("solvency", _) => cmd::solvency::execute(&mut ctx),
 		(c, _) => println!("command {} unknown", c),
 	};
 }
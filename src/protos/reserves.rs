@@ -21,6 +21,288 @@
 use protobuf::Message as Message_imported_for_functions;
 use protobuf::ProtobufEnum as ProtobufEnum_imported_for_functions;
 
+#[derive(PartialEq,Clone,Default)]
+pub struct Unblinding {
+    // message fields
+    pub asset: ::std::vec::Vec<u8>,
+    pub value: u64,
+    pub asset_blinding_factor: ::std::vec::Vec<u8>,
+    pub value_blinding_factor: ::std::vec::Vec<u8>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+impl Unblinding {
+    pub fn new() -> Unblinding {
+        ::std::default::Default::default()
+    }
+
+    // bytes asset = 1;
+
+    pub fn clear_asset(&mut self) {
+        self.asset.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_asset(&mut self, v: ::std::vec::Vec<u8>) {
+        self.asset = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_asset(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.asset
+    }
+
+    // Take field
+    pub fn take_asset(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.asset, ::std::vec::Vec::new())
+    }
+
+    pub fn get_asset(&self) -> &[u8] {
+        &self.asset
+    }
+
+    // uint64 value = 2;
+
+    pub fn clear_value(&mut self) {
+        self.value = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_value(&mut self, v: u64) {
+        self.value = v;
+    }
+
+    pub fn get_value(&self) -> u64 {
+        self.value
+    }
+
+    // bytes asset_blinding_factor = 3;
+
+    pub fn clear_asset_blinding_factor(&mut self) {
+        self.asset_blinding_factor.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_asset_blinding_factor(&mut self, v: ::std::vec::Vec<u8>) {
+        self.asset_blinding_factor = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_asset_blinding_factor(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.asset_blinding_factor
+    }
+
+    // Take field
+    pub fn take_asset_blinding_factor(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.asset_blinding_factor, ::std::vec::Vec::new())
+    }
+
+    pub fn get_asset_blinding_factor(&self) -> &[u8] {
+        &self.asset_blinding_factor
+    }
+
+    // bytes value_blinding_factor = 4;
+
+    pub fn clear_value_blinding_factor(&mut self) {
+        self.value_blinding_factor.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_value_blinding_factor(&mut self, v: ::std::vec::Vec<u8>) {
+        self.value_blinding_factor = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_value_blinding_factor(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.value_blinding_factor
+    }
+
+    // Take field
+    pub fn take_value_blinding_factor(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.value_blinding_factor, ::std::vec::Vec::new())
+    }
+
+    pub fn get_value_blinding_factor(&self) -> &[u8] {
+        &self.value_blinding_factor
+    }
+}
+
+impl ::protobuf::Message for Unblinding {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.asset)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.value = tmp;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.asset_blinding_factor)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.value_blinding_factor)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.asset.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.asset);
+        }
+        if self.value != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.value, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.asset_blinding_factor.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(3, &self.asset_blinding_factor);
+        }
+        if !self.value_blinding_factor.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(4, &self.value_blinding_factor);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if !self.asset.is_empty() {
+            os.write_bytes(1, &self.asset)?;
+        }
+        if self.value != 0 {
+            os.write_uint64(2, self.value)?;
+        }
+        if !self.asset_blinding_factor.is_empty() {
+            os.write_bytes(3, &self.asset_blinding_factor)?;
+        }
+        if !self.value_blinding_factor.is_empty() {
+            os.write_bytes(4, &self.value_blinding_factor)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Unblinding {
+        Unblinding::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "asset",
+                    |m: &Unblinding| { &m.asset },
+                    |m: &mut Unblinding| { &mut m.asset },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "value",
+                    |m: &Unblinding| { &m.value },
+                    |m: &mut Unblinding| { &mut m.value },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "asset_blinding_factor",
+                    |m: &Unblinding| { &m.asset_blinding_factor },
+                    |m: &mut Unblinding| { &mut m.asset_blinding_factor },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "value_blinding_factor",
+                    |m: &Unblinding| { &m.value_blinding_factor },
+                    |m: &mut Unblinding| { &mut m.value_blinding_factor },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<Unblinding>(
+                    "Unblinding",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static Unblinding {
+        static mut instance: ::protobuf::lazy::Lazy<Unblinding> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const Unblinding,
+        };
+        unsafe {
+            instance.get(Unblinding::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for Unblinding {
+    fn clear(&mut self) {
+        self.clear_asset();
+        self.clear_value();
+        self.clear_asset_blinding_factor();
+        self.clear_value_blinding_factor();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Unblinding {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Unblinding {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
 #[derive(PartialEq,Clone,Default)]
 pub struct UTXO {
     // message fields
@@ -29,6 +311,8 @@ pub struct UTXO {
     pub psbt_input: ::std::vec::Vec<u8>,
     pub block_number: u32,
     pub block_hash: ::std::vec::Vec<u8>,
+    pub unblinding: ::protobuf::SingularPtrField<Unblinding>,
+    pub wallet: ::std::string::String,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
@@ -146,10 +430,74 @@ impl UTXO {
     pub fn get_block_hash(&self) -> &[u8] {
         &self.block_hash
     }
+
+    // .Unblinding unblinding = 6;
+
+    pub fn clear_unblinding(&mut self) {
+        self.unblinding.clear();
+    }
+
+    pub fn has_unblinding(&self) -> bool {
+        self.unblinding.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_unblinding(&mut self, v: Unblinding) {
+        self.unblinding = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_unblinding(&mut self) -> &mut Unblinding {
+        if self.unblinding.is_none() {
+            self.unblinding.set_default();
+        }
+        self.unblinding.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_unblinding(&mut self) -> Unblinding {
+        self.unblinding.take().unwrap_or_else(|| Unblinding::new())
+    }
+
+    pub fn get_unblinding(&self) -> &Unblinding {
+        self.unblinding.as_ref().unwrap_or_else(|| Unblinding::default_instance())
+    }
+
+    // string wallet = 7;
+
+    pub fn clear_wallet(&mut self) {
+        self.wallet.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_wallet(&mut self, v: ::std::string::String) {
+        self.wallet = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_wallet(&mut self) -> &mut ::std::string::String {
+        &mut self.wallet
+    }
+
+    // Take field
+    pub fn take_wallet(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.wallet, ::std::string::String::new())
+    }
+
+    pub fn get_wallet(&self) -> &str {
+        &self.wallet
+    }
 }
 
 impl ::protobuf::Message for UTXO {
     fn is_initialized(&self) -> bool {
+        for v in &self.unblinding {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
         true
     }
 
@@ -180,6 +528,12 @@ impl ::protobuf::Message for UTXO {
                 5 => {
                     ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.block_hash)?;
                 },
+                6 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.unblinding)?;
+                },
+                7 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.wallet)?;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -207,6 +561,13 @@ impl ::protobuf::Message for UTXO {
         if !self.block_hash.is_empty() {
             my_size += ::protobuf::rt::bytes_size(5, &self.block_hash);
         }
+        if let Some(ref v) = self.unblinding.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if !self.wallet.is_empty() {
+            my_size += ::protobuf::rt::string_size(7, &self.wallet);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -228,6 +589,14 @@ impl ::protobuf::Message for UTXO {
         if !self.block_hash.is_empty() {
             os.write_bytes(5, &self.block_hash)?;
         }
+        if let Some(ref v) = self.unblinding.as_ref() {
+            os.write_tag(6, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if !self.wallet.is_empty() {
+            os.write_string(7, &self.wallet)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -295,6 +664,16 @@ impl ::protobuf::Message for UTXO {
                     |m: &UTXO| { &m.block_hash },
                     |m: &mut UTXO| { &mut m.block_hash },
                 ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Unblinding>>(
+                    "unblinding",
+                    |m: &UTXO| { &m.unblinding },
+                    |m: &mut UTXO| { &mut m.unblinding },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "wallet",
+                    |m: &UTXO| { &m.wallet },
+                    |m: &mut UTXO| { &mut m.wallet },
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<UTXO>(
                     "UTXO",
                     fields,
@@ -322,6 +701,8 @@ impl ::protobuf::Clear for UTXO {
         self.clear_psbt_input();
         self.clear_block_number();
         self.clear_block_hash();
+        self.clear_unblinding();
+        self.clear_wallet();
         self.unknown_fields.clear();
     }
 }
@@ -346,6 +727,9 @@ pub struct Proof {
     pub proof_tx: ::std::vec::Vec<u8>,
     pub utxos: ::protobuf::RepeatedField<UTXO>,
     pub psbt: ::std::vec::Vec<u8>,
+    pub chunks: ::protobuf::RepeatedField<ProofChunk>,
+    pub chunk_max_inputs: u32,
+    pub chunk_max_weight: u32,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
@@ -473,30 +857,90 @@ impl Proof {
     pub fn get_psbt(&self) -> &[u8] {
         &self.psbt
     }
-}
 
-impl ::protobuf::Message for Proof {
-    fn is_initialized(&self) -> bool {
-        for v in &self.utxos {
-            if !v.is_initialized() {
-                return false;
-            }
-        };
-        true
+    // repeated .ProofChunk chunks = 6;
+
+    pub fn clear_chunks(&mut self) {
+        self.chunks.clear();
     }
 
-    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
-        while !is.eof()? {
-            let (field_number, wire_type) = is.read_tag_unpack()?;
-            match field_number {
-                1 => {
-                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.id)?;
-                },
-                2 => {
-                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.status, 2, &mut self.unknown_fields)?
-                },
-                3 => {
-                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.proof_tx)?;
+    // Param is passed by value, moved
+    pub fn set_chunks(&mut self, v: ::protobuf::RepeatedField<ProofChunk>) {
+        self.chunks = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_chunks(&mut self) -> &mut ::protobuf::RepeatedField<ProofChunk> {
+        &mut self.chunks
+    }
+
+    // Take field
+    pub fn take_chunks(&mut self) -> ::protobuf::RepeatedField<ProofChunk> {
+        ::std::mem::replace(&mut self.chunks, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_chunks(&self) -> &[ProofChunk] {
+        &self.chunks
+    }
+
+    // uint32 chunk_max_inputs = 7;
+
+    pub fn clear_chunk_max_inputs(&mut self) {
+        self.chunk_max_inputs = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_chunk_max_inputs(&mut self, v: u32) {
+        self.chunk_max_inputs = v;
+    }
+
+    pub fn get_chunk_max_inputs(&self) -> u32 {
+        self.chunk_max_inputs
+    }
+
+    // uint32 chunk_max_weight = 8;
+
+    pub fn clear_chunk_max_weight(&mut self) {
+        self.chunk_max_weight = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_chunk_max_weight(&mut self, v: u32) {
+        self.chunk_max_weight = v;
+    }
+
+    pub fn get_chunk_max_weight(&self) -> u32 {
+        self.chunk_max_weight
+    }
+}
+
+impl ::protobuf::Message for Proof {
+    fn is_initialized(&self) -> bool {
+        for v in &self.utxos {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.chunks {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.status, 2, &mut self.unknown_fields)?
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.proof_tx)?;
                 },
                 4 => {
                     ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.utxos)?;
@@ -504,6 +948,23 @@ impl ::protobuf::Message for Proof {
                 5 => {
                     ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.psbt)?;
                 },
+                6 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.chunks)?;
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.chunk_max_inputs = tmp;
+                },
+                8 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.chunk_max_weight = tmp;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -532,6 +993,16 @@ impl ::protobuf::Message for Proof {
         if !self.psbt.is_empty() {
             my_size += ::protobuf::rt::bytes_size(5, &self.psbt);
         }
+        for value in &self.chunks {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if self.chunk_max_inputs != 0 {
+            my_size += ::protobuf::rt::value_size(7, self.chunk_max_inputs, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.chunk_max_weight != 0 {
+            my_size += ::protobuf::rt::value_size(8, self.chunk_max_weight, ::protobuf::wire_format::WireTypeVarint);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -555,6 +1026,17 @@ impl ::protobuf::Message for Proof {
         if !self.psbt.is_empty() {
             os.write_bytes(5, &self.psbt)?;
         }
+        for v in &self.chunks {
+            os.write_tag(6, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if self.chunk_max_inputs != 0 {
+            os.write_uint32(7, self.chunk_max_inputs)?;
+        }
+        if self.chunk_max_weight != 0 {
+            os.write_uint32(8, self.chunk_max_weight)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -622,6 +1104,21 @@ impl ::protobuf::Message for Proof {
                     |m: &Proof| { &m.psbt },
                     |m: &mut Proof| { &mut m.psbt },
                 ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<ProofChunk>>(
+                    "chunks",
+                    |m: &Proof| { &m.chunks },
+                    |m: &mut Proof| { &mut m.chunks },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "chunk_max_inputs",
+                    |m: &Proof| { &m.chunk_max_inputs },
+                    |m: &mut Proof| { &mut m.chunk_max_inputs },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "chunk_max_weight",
+                    |m: &Proof| { &m.chunk_max_weight },
+                    |m: &mut Proof| { &mut m.chunk_max_weight },
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<Proof>(
                     "Proof",
                     fields,
@@ -649,6 +1146,9 @@ impl ::protobuf::Clear for Proof {
         self.clear_proof_tx();
         self.clear_utxos();
         self.clear_psbt();
+        self.clear_chunks();
+        self.clear_chunk_max_inputs();
+        self.clear_chunk_max_weight();
         self.unknown_fields.clear();
     }
 }
@@ -727,127 +1227,75 @@ impl ::protobuf::reflect::ProtobufValue for Proof_Status {
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct ProofOfReserves {
+pub struct ProofChunk {
     // message fields
-    pub version: u32,
-    pub network: Network,
-    pub challenge: ::std::string::String,
-    pub block_number: u32,
-    pub proofs: ::protobuf::RepeatedField<Proof>,
+    pub proof_tx: ::std::vec::Vec<u8>,
+    pub psbt: ::std::vec::Vec<u8>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
 }
 
-impl ProofOfReserves {
-    pub fn new() -> ProofOfReserves {
+impl ProofChunk {
+    pub fn new() -> ProofChunk {
         ::std::default::Default::default()
     }
 
-    // uint32 version = 1;
-
-    pub fn clear_version(&mut self) {
-        self.version = 0;
-    }
-
-    // Param is passed by value, moved
-    pub fn set_version(&mut self, v: u32) {
-        self.version = v;
-    }
-
-    pub fn get_version(&self) -> u32 {
-        self.version
-    }
-
-    // .Network network = 2;
-
-    pub fn clear_network(&mut self) {
-        self.network = Network::BITCOIN_MAINNET;
-    }
-
-    // Param is passed by value, moved
-    pub fn set_network(&mut self, v: Network) {
-        self.network = v;
-    }
-
-    pub fn get_network(&self) -> Network {
-        self.network
-    }
-
-    // string challenge = 3;
+    // bytes proof_tx = 1;
 
-    pub fn clear_challenge(&mut self) {
-        self.challenge.clear();
+    pub fn clear_proof_tx(&mut self) {
+        self.proof_tx.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_challenge(&mut self, v: ::std::string::String) {
-        self.challenge = v;
+    pub fn set_proof_tx(&mut self, v: ::std::vec::Vec<u8>) {
+        self.proof_tx = v;
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_challenge(&mut self) -> &mut ::std::string::String {
-        &mut self.challenge
+    pub fn mut_proof_tx(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.proof_tx
     }
 
     // Take field
-    pub fn take_challenge(&mut self) -> ::std::string::String {
-        ::std::mem::replace(&mut self.challenge, ::std::string::String::new())
-    }
-
-    pub fn get_challenge(&self) -> &str {
-        &self.challenge
-    }
-
-    // uint32 block_number = 4;
-
-    pub fn clear_block_number(&mut self) {
-        self.block_number = 0;
-    }
-
-    // Param is passed by value, moved
-    pub fn set_block_number(&mut self, v: u32) {
-        self.block_number = v;
+    pub fn take_proof_tx(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.proof_tx, ::std::vec::Vec::new())
     }
 
-    pub fn get_block_number(&self) -> u32 {
-        self.block_number
+    pub fn get_proof_tx(&self) -> &[u8] {
+        &self.proof_tx
     }
 
-    // repeated .Proof proofs = 5;
+    // bytes psbt = 2;
 
-    pub fn clear_proofs(&mut self) {
-        self.proofs.clear();
+    pub fn clear_psbt(&mut self) {
+        self.psbt.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_proofs(&mut self, v: ::protobuf::RepeatedField<Proof>) {
-        self.proofs = v;
+    pub fn set_psbt(&mut self, v: ::std::vec::Vec<u8>) {
+        self.psbt = v;
     }
 
     // Mutable pointer to the field.
-    pub fn mut_proofs(&mut self) -> &mut ::protobuf::RepeatedField<Proof> {
-        &mut self.proofs
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_psbt(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.psbt
     }
 
     // Take field
-    pub fn take_proofs(&mut self) -> ::protobuf::RepeatedField<Proof> {
-        ::std::mem::replace(&mut self.proofs, ::protobuf::RepeatedField::new())
+    pub fn take_psbt(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.psbt, ::std::vec::Vec::new())
     }
 
-    pub fn get_proofs(&self) -> &[Proof] {
-        &self.proofs
+    pub fn get_psbt(&self) -> &[u8] {
+        &self.psbt
     }
 }
 
-impl ::protobuf::Message for ProofOfReserves {
+impl ::protobuf::Message for ProofChunk {
     fn is_initialized(&self) -> bool {
-        for v in &self.proofs {
-            if !v.is_initialized() {
-                return false;
-            }
-        };
         true
     }
 
@@ -856,27 +1304,10 @@ impl ::protobuf::Message for ProofOfReserves {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_uint32()?;
-                    self.version = tmp;
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.proof_tx)?;
                 },
                 2 => {
-                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.network, 2, &mut self.unknown_fields)?
-                },
-                3 => {
-                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.challenge)?;
-                },
-                4 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_uint32()?;
-                    self.block_number = tmp;
-                },
-                5 => {
-                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.proofs)?;
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.psbt)?;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -890,45 +1321,24 @@ impl ::protobuf::Message for ProofOfReserves {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if self.version != 0 {
-            my_size += ::protobuf::rt::value_size(1, self.version, ::protobuf::wire_format::WireTypeVarint);
-        }
-        if self.network != Network::BITCOIN_MAINNET {
-            my_size += ::protobuf::rt::enum_size(2, self.network);
-        }
-        if !self.challenge.is_empty() {
-            my_size += ::protobuf::rt::string_size(3, &self.challenge);
+        if !self.proof_tx.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.proof_tx);
         }
-        if self.block_number != 0 {
-            my_size += ::protobuf::rt::value_size(4, self.block_number, ::protobuf::wire_format::WireTypeVarint);
+        if !self.psbt.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(2, &self.psbt);
         }
-        for value in &self.proofs {
-            let len = value.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
-        };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if self.version != 0 {
-            os.write_uint32(1, self.version)?;
-        }
-        if self.network != Network::BITCOIN_MAINNET {
-            os.write_enum(2, self.network.value())?;
-        }
-        if !self.challenge.is_empty() {
-            os.write_string(3, &self.challenge)?;
+        if !self.proof_tx.is_empty() {
+            os.write_bytes(1, &self.proof_tx)?;
         }
-        if self.block_number != 0 {
-            os.write_uint32(4, self.block_number)?;
+        if !self.psbt.is_empty() {
+            os.write_bytes(2, &self.psbt)?;
         }
-        for v in &self.proofs {
-            os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-            os.write_raw_varint32(v.get_cached_size())?;
-            v.write_to_with_cached_sizes(os)?;
-        };
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -959,8 +1369,8 @@ impl ::protobuf::Message for ProofOfReserves {
         Self::descriptor_static()
     }
 
-    fn new() -> ProofOfReserves {
-        ProofOfReserves::new()
+    fn new() -> ProofChunk {
+        ProofChunk::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -971,33 +1381,2888 @@ impl ::protobuf::Message for ProofOfReserves {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
-                    "version",
-                    |m: &ProofOfReserves| { &m.version },
-                    |m: &mut ProofOfReserves| { &mut m.version },
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "proof_tx",
+                    |m: &ProofChunk| { &m.proof_tx },
+                    |m: &mut ProofChunk| { &mut m.proof_tx },
                 ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<Network>>(
-                    "network",
-                    |m: &ProofOfReserves| { &m.network },
-                    |m: &mut ProofOfReserves| { &mut m.network },
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "psbt",
+                    |m: &ProofChunk| { &m.psbt },
+                    |m: &mut ProofChunk| { &mut m.psbt },
                 ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "challenge",
-                    |m: &ProofOfReserves| { &m.challenge },
-                    |m: &mut ProofOfReserves| { &mut m.challenge },
+                ::protobuf::reflect::MessageDescriptor::new::<ProofChunk>(
+                    "ProofChunk",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static ProofChunk {
+        static mut instance: ::protobuf::lazy::Lazy<ProofChunk> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ProofChunk,
+        };
+        unsafe {
+            instance.get(ProofChunk::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for ProofChunk {
+    fn clear(&mut self) {
+        self.clear_proof_tx();
+        self.clear_psbt();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ProofChunk {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ProofChunk {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ProvisionsKey {
+    // message fields
+    pub pubkey: ::std::vec::Vec<u8>,
+    pub balance: u64,
+    pub balance_commitment: ::std::vec::Vec<u8>,
+    pub key_commitment: ::std::vec::Vec<u8>,
+    pub bit_commitment: ::std::vec::Vec<u8>,
+    pub proof: ::std::vec::Vec<u8>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+impl ProvisionsKey {
+    pub fn new() -> ProvisionsKey {
+        ::std::default::Default::default()
+    }
+
+    // bytes pubkey = 1;
+
+    pub fn clear_pubkey(&mut self) {
+        self.pubkey.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_pubkey(&mut self, v: ::std::vec::Vec<u8>) {
+        self.pubkey = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_pubkey(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.pubkey
+    }
+
+    // Take field
+    pub fn take_pubkey(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.pubkey, ::std::vec::Vec::new())
+    }
+
+    pub fn get_pubkey(&self) -> &[u8] {
+        &self.pubkey
+    }
+
+    // uint64 balance = 2;
+
+    pub fn clear_balance(&mut self) {
+        self.balance = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_balance(&mut self, v: u64) {
+        self.balance = v;
+    }
+
+    pub fn get_balance(&self) -> u64 {
+        self.balance
+    }
+
+    // bytes balance_commitment = 3;
+
+    pub fn clear_balance_commitment(&mut self) {
+        self.balance_commitment.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_balance_commitment(&mut self, v: ::std::vec::Vec<u8>) {
+        self.balance_commitment = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_balance_commitment(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.balance_commitment
+    }
+
+    // Take field
+    pub fn take_balance_commitment(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.balance_commitment, ::std::vec::Vec::new())
+    }
+
+    pub fn get_balance_commitment(&self) -> &[u8] {
+        &self.balance_commitment
+    }
+
+    // bytes key_commitment = 4;
+
+    pub fn clear_key_commitment(&mut self) {
+        self.key_commitment.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_key_commitment(&mut self, v: ::std::vec::Vec<u8>) {
+        self.key_commitment = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_key_commitment(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.key_commitment
+    }
+
+    // Take field
+    pub fn take_key_commitment(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.key_commitment, ::std::vec::Vec::new())
+    }
+
+    pub fn get_key_commitment(&self) -> &[u8] {
+        &self.key_commitment
+    }
+
+    // bytes bit_commitment = 5;
+
+    pub fn clear_bit_commitment(&mut self) {
+        self.bit_commitment.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_bit_commitment(&mut self, v: ::std::vec::Vec<u8>) {
+        self.bit_commitment = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_bit_commitment(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.bit_commitment
+    }
+
+    // Take field
+    pub fn take_bit_commitment(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.bit_commitment, ::std::vec::Vec::new())
+    }
+
+    pub fn get_bit_commitment(&self) -> &[u8] {
+        &self.bit_commitment
+    }
+
+    // bytes proof = 6;
+
+    pub fn clear_proof(&mut self) {
+        self.proof.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_proof(&mut self, v: ::std::vec::Vec<u8>) {
+        self.proof = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_proof(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.proof
+    }
+
+    // Take field
+    pub fn take_proof(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.proof, ::std::vec::Vec::new())
+    }
+
+    pub fn get_proof(&self) -> &[u8] {
+        &self.proof
+    }
+}
+
+impl ::protobuf::Message for ProvisionsKey {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.pubkey)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.balance = tmp;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.balance_commitment)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.key_commitment)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.bit_commitment)?;
+                },
+                6 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.proof)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.pubkey.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.pubkey);
+        }
+        if self.balance != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.balance, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.balance_commitment.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(3, &self.balance_commitment);
+        }
+        if !self.key_commitment.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(4, &self.key_commitment);
+        }
+        if !self.bit_commitment.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(5, &self.bit_commitment);
+        }
+        if !self.proof.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(6, &self.proof);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if !self.pubkey.is_empty() {
+            os.write_bytes(1, &self.pubkey)?;
+        }
+        if self.balance != 0 {
+            os.write_uint64(2, self.balance)?;
+        }
+        if !self.balance_commitment.is_empty() {
+            os.write_bytes(3, &self.balance_commitment)?;
+        }
+        if !self.key_commitment.is_empty() {
+            os.write_bytes(4, &self.key_commitment)?;
+        }
+        if !self.bit_commitment.is_empty() {
+            os.write_bytes(5, &self.bit_commitment)?;
+        }
+        if !self.proof.is_empty() {
+            os.write_bytes(6, &self.proof)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ProvisionsKey {
+        ProvisionsKey::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "pubkey",
+                    |m: &ProvisionsKey| { &m.pubkey },
+                    |m: &mut ProvisionsKey| { &mut m.pubkey },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "balance",
+                    |m: &ProvisionsKey| { &m.balance },
+                    |m: &mut ProvisionsKey| { &mut m.balance },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "balance_commitment",
+                    |m: &ProvisionsKey| { &m.balance_commitment },
+                    |m: &mut ProvisionsKey| { &mut m.balance_commitment },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "key_commitment",
+                    |m: &ProvisionsKey| { &m.key_commitment },
+                    |m: &mut ProvisionsKey| { &mut m.key_commitment },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "bit_commitment",
+                    |m: &ProvisionsKey| { &m.bit_commitment },
+                    |m: &mut ProvisionsKey| { &mut m.bit_commitment },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "proof",
+                    |m: &ProvisionsKey| { &m.proof },
+                    |m: &mut ProvisionsKey| { &mut m.proof },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<ProvisionsKey>(
+                    "ProvisionsKey",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static ProvisionsKey {
+        static mut instance: ::protobuf::lazy::Lazy<ProvisionsKey> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ProvisionsKey,
+        };
+        unsafe {
+            instance.get(ProvisionsKey::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for ProvisionsKey {
+    fn clear(&mut self) {
+        self.clear_pubkey();
+        self.clear_balance();
+        self.clear_balance_commitment();
+        self.clear_key_commitment();
+        self.clear_bit_commitment();
+        self.clear_proof();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ProvisionsKey {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ProvisionsKey {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ProvisionsProof {
+    // message fields
+    pub keys: ::protobuf::RepeatedField<ProvisionsKey>,
+    pub threshold: u64,
+    pub range_commitments: ::protobuf::RepeatedField<::std::vec::Vec<u8>>,
+    pub range_proofs: ::protobuf::RepeatedField<::std::vec::Vec<u8>>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+impl ProvisionsProof {
+    pub fn new() -> ProvisionsProof {
+        ::std::default::Default::default()
+    }
+
+    // repeated .ProvisionsKey keys = 1;
+
+    pub fn clear_keys(&mut self) {
+        self.keys.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_keys(&mut self, v: ::protobuf::RepeatedField<ProvisionsKey>) {
+        self.keys = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_keys(&mut self) -> &mut ::protobuf::RepeatedField<ProvisionsKey> {
+        &mut self.keys
+    }
+
+    // Take field
+    pub fn take_keys(&mut self) -> ::protobuf::RepeatedField<ProvisionsKey> {
+        ::std::mem::replace(&mut self.keys, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_keys(&self) -> &[ProvisionsKey] {
+        &self.keys
+    }
+
+    // uint64 threshold = 2;
+
+    pub fn clear_threshold(&mut self) {
+        self.threshold = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_threshold(&mut self, v: u64) {
+        self.threshold = v;
+    }
+
+    pub fn get_threshold(&self) -> u64 {
+        self.threshold
+    }
+
+    // repeated bytes range_commitments = 3;
+
+    pub fn clear_range_commitments(&mut self) {
+        self.range_commitments.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_range_commitments(&mut self, v: ::protobuf::RepeatedField<::std::vec::Vec<u8>>) {
+        self.range_commitments = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_range_commitments(&mut self) -> &mut ::protobuf::RepeatedField<::std::vec::Vec<u8>> {
+        &mut self.range_commitments
+    }
+
+    // Take field
+    pub fn take_range_commitments(&mut self) -> ::protobuf::RepeatedField<::std::vec::Vec<u8>> {
+        ::std::mem::replace(&mut self.range_commitments, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_range_commitments(&self) -> &[::std::vec::Vec<u8>] {
+        &self.range_commitments
+    }
+
+    // repeated bytes range_proofs = 4;
+
+    pub fn clear_range_proofs(&mut self) {
+        self.range_proofs.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_range_proofs(&mut self, v: ::protobuf::RepeatedField<::std::vec::Vec<u8>>) {
+        self.range_proofs = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_range_proofs(&mut self) -> &mut ::protobuf::RepeatedField<::std::vec::Vec<u8>> {
+        &mut self.range_proofs
+    }
+
+    // Take field
+    pub fn take_range_proofs(&mut self) -> ::protobuf::RepeatedField<::std::vec::Vec<u8>> {
+        ::std::mem::replace(&mut self.range_proofs, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_range_proofs(&self) -> &[::std::vec::Vec<u8>] {
+        &self.range_proofs
+    }
+}
+
+impl ::protobuf::Message for ProvisionsProof {
+    fn is_initialized(&self) -> bool {
+        for v in &self.keys {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.keys)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.threshold = tmp;
+                },
+                3 => {
+                    ::protobuf::rt::read_repeated_bytes_into(wire_type, is, &mut self.range_commitments)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_repeated_bytes_into(wire_type, is, &mut self.range_proofs)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.keys {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if self.threshold != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.threshold, ::protobuf::wire_format::WireTypeVarint);
+        }
+        for value in &self.range_commitments {
+            my_size += ::protobuf::rt::bytes_size(3, &value);
+        };
+        for value in &self.range_proofs {
+            my_size += ::protobuf::rt::bytes_size(4, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.keys {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if self.threshold != 0 {
+            os.write_uint64(2, self.threshold)?;
+        }
+        for v in &self.range_commitments {
+            os.write_bytes(3, &v)?;
+        };
+        for v in &self.range_proofs {
+            os.write_bytes(4, &v)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ProvisionsProof {
+        ProvisionsProof::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<ProvisionsKey>>(
+                    "keys",
+                    |m: &ProvisionsProof| { &m.keys },
+                    |m: &mut ProvisionsProof| { &mut m.keys },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "threshold",
+                    |m: &ProvisionsProof| { &m.threshold },
+                    |m: &mut ProvisionsProof| { &mut m.threshold },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "range_commitments",
+                    |m: &ProvisionsProof| { &m.range_commitments },
+                    |m: &mut ProvisionsProof| { &mut m.range_commitments },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "range_proofs",
+                    |m: &ProvisionsProof| { &m.range_proofs },
+                    |m: &mut ProvisionsProof| { &mut m.range_proofs },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<ProvisionsProof>(
+                    "ProvisionsProof",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static ProvisionsProof {
+        static mut instance: ::protobuf::lazy::Lazy<ProvisionsProof> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ProvisionsProof,
+        };
+        unsafe {
+            instance.get(ProvisionsProof::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for ProvisionsProof {
+    fn clear(&mut self) {
+        self.clear_keys();
+        self.clear_threshold();
+        self.clear_range_commitments();
+        self.clear_range_proofs();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ProvisionsProof {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ProvisionsProof {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Liabilities {
+    // message fields
+    pub root: ::std::vec::Vec<u8>,
+    pub total: u64,
+    pub block_number: u32,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+impl Liabilities {
+    pub fn new() -> Liabilities {
+        ::std::default::Default::default()
+    }
+
+    // bytes root = 1;
+
+    pub fn clear_root(&mut self) {
+        self.root.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_root(&mut self, v: ::std::vec::Vec<u8>) {
+        self.root = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_root(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.root
+    }
+
+    // Take field
+    pub fn take_root(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.root, ::std::vec::Vec::new())
+    }
+
+    pub fn get_root(&self) -> &[u8] {
+        &self.root
+    }
+
+    // uint64 total = 2;
+
+    pub fn clear_total(&mut self) {
+        self.total = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_total(&mut self, v: u64) {
+        self.total = v;
+    }
+
+    pub fn get_total(&self) -> u64 {
+        self.total
+    }
+
+    // uint32 block_number = 3;
+
+    pub fn clear_block_number(&mut self) {
+        self.block_number = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_block_number(&mut self, v: u32) {
+        self.block_number = v;
+    }
+
+    pub fn get_block_number(&self) -> u32 {
+        self.block_number
+    }
+}
+
+impl ::protobuf::Message for Liabilities {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.root)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.total = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.block_number = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.root.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.root);
+        }
+        if self.total != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.total, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.block_number != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.block_number, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if !self.root.is_empty() {
+            os.write_bytes(1, &self.root)?;
+        }
+        if self.total != 0 {
+            os.write_uint64(2, self.total)?;
+        }
+        if self.block_number != 0 {
+            os.write_uint32(3, self.block_number)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Liabilities {
+        Liabilities::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "root",
+                    |m: &Liabilities| { &m.root },
+                    |m: &mut Liabilities| { &mut m.root },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "total",
+                    |m: &Liabilities| { &m.total },
+                    |m: &mut Liabilities| { &mut m.total },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "block_number",
+                    |m: &Liabilities| { &m.block_number },
+                    |m: &mut Liabilities| { &mut m.block_number },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<Liabilities>(
+                    "Liabilities",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static Liabilities {
+        static mut instance: ::protobuf::lazy::Lazy<Liabilities> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const Liabilities,
+        };
+        unsafe {
+            instance.get(Liabilities::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for Liabilities {
+    fn clear(&mut self) {
+        self.clear_root();
+        self.clear_total();
+        self.clear_block_number();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Liabilities {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Liabilities {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Challenge {
+    // message fields
+    pub message: ::std::string::String,
+    pub liabilities_root: ::std::vec::Vec<u8>,
+    pub block_hash: ::std::vec::Vec<u8>,
+    pub nonce: ::std::vec::Vec<u8>,
+    pub auditor: ::std::string::String,
+    pub date: ::std::string::String,
+    pub target_block: u32,
+    pub end_block: u32,
+    pub auditor_signature: ::std::vec::Vec<u8>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+impl Challenge {
+    pub fn new() -> Challenge {
+        ::std::default::Default::default()
+    }
+
+    // string message = 1;
+
+    pub fn clear_message(&mut self) {
+        self.message.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_message(&mut self, v: ::std::string::String) {
+        self.message = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_message(&mut self) -> &mut ::std::string::String {
+        &mut self.message
+    }
+
+    // Take field
+    pub fn take_message(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.message, ::std::string::String::new())
+    }
+
+    pub fn get_message(&self) -> &str {
+        &self.message
+    }
+
+    // bytes liabilities_root = 2;
+
+    pub fn clear_liabilities_root(&mut self) {
+        self.liabilities_root.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_liabilities_root(&mut self, v: ::std::vec::Vec<u8>) {
+        self.liabilities_root = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_liabilities_root(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.liabilities_root
+    }
+
+    // Take field
+    pub fn take_liabilities_root(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.liabilities_root, ::std::vec::Vec::new())
+    }
+
+    pub fn get_liabilities_root(&self) -> &[u8] {
+        &self.liabilities_root
+    }
+
+    // bytes block_hash = 3;
+
+    pub fn clear_block_hash(&mut self) {
+        self.block_hash.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_block_hash(&mut self, v: ::std::vec::Vec<u8>) {
+        self.block_hash = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_block_hash(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.block_hash
+    }
+
+    // Take field
+    pub fn take_block_hash(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.block_hash, ::std::vec::Vec::new())
+    }
+
+    pub fn get_block_hash(&self) -> &[u8] {
+        &self.block_hash
+    }
+
+    // bytes nonce = 4;
+
+    pub fn clear_nonce(&mut self) {
+        self.nonce.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_nonce(&mut self, v: ::std::vec::Vec<u8>) {
+        self.nonce = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_nonce(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.nonce
+    }
+
+    // Take field
+    pub fn take_nonce(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.nonce, ::std::vec::Vec::new())
+    }
+
+    pub fn get_nonce(&self) -> &[u8] {
+        &self.nonce
+    }
+
+    // string auditor = 5;
+
+    pub fn clear_auditor(&mut self) {
+        self.auditor.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_auditor(&mut self, v: ::std::string::String) {
+        self.auditor = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_auditor(&mut self) -> &mut ::std::string::String {
+        &mut self.auditor
+    }
+
+    // Take field
+    pub fn take_auditor(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.auditor, ::std::string::String::new())
+    }
+
+    pub fn get_auditor(&self) -> &str {
+        &self.auditor
+    }
+
+    // string date = 6;
+
+    pub fn clear_date(&mut self) {
+        self.date.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_date(&mut self, v: ::std::string::String) {
+        self.date = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_date(&mut self) -> &mut ::std::string::String {
+        &mut self.date
+    }
+
+    // Take field
+    pub fn take_date(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.date, ::std::string::String::new())
+    }
+
+    pub fn get_date(&self) -> &str {
+        &self.date
+    }
+
+    // uint32 target_block = 7;
+
+    pub fn clear_target_block(&mut self) {
+        self.target_block = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_target_block(&mut self, v: u32) {
+        self.target_block = v;
+    }
+
+    pub fn get_target_block(&self) -> u32 {
+        self.target_block
+    }
+
+    // uint32 end_block = 9;
+
+    pub fn clear_end_block(&mut self) {
+        self.end_block = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_end_block(&mut self, v: u32) {
+        self.end_block = v;
+    }
+
+    pub fn get_end_block(&self) -> u32 {
+        self.end_block
+    }
+
+    // bytes auditor_signature = 8;
+
+    pub fn clear_auditor_signature(&mut self) {
+        self.auditor_signature.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_auditor_signature(&mut self, v: ::std::vec::Vec<u8>) {
+        self.auditor_signature = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_auditor_signature(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.auditor_signature
+    }
+
+    // Take field
+    pub fn take_auditor_signature(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.auditor_signature, ::std::vec::Vec::new())
+    }
+
+    pub fn get_auditor_signature(&self) -> &[u8] {
+        &self.auditor_signature
+    }
+}
+
+impl ::protobuf::Message for Challenge {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.message)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.liabilities_root)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.block_hash)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.nonce)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.auditor)?;
+                },
+                6 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.date)?;
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.target_block = tmp;
+                },
+                9 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.end_block = tmp;
+                },
+                8 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.auditor_signature)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.message.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.message);
+        }
+        if !self.liabilities_root.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(2, &self.liabilities_root);
+        }
+        if !self.block_hash.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(3, &self.block_hash);
+        }
+        if !self.nonce.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(4, &self.nonce);
+        }
+        if !self.auditor.is_empty() {
+            my_size += ::protobuf::rt::string_size(5, &self.auditor);
+        }
+        if !self.date.is_empty() {
+            my_size += ::protobuf::rt::string_size(6, &self.date);
+        }
+        if self.target_block != 0 {
+            my_size += ::protobuf::rt::value_size(7, self.target_block, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.end_block != 0 {
+            my_size += ::protobuf::rt::value_size(9, self.end_block, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.auditor_signature.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(8, &self.auditor_signature);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if !self.message.is_empty() {
+            os.write_string(1, &self.message)?;
+        }
+        if !self.liabilities_root.is_empty() {
+            os.write_bytes(2, &self.liabilities_root)?;
+        }
+        if !self.block_hash.is_empty() {
+            os.write_bytes(3, &self.block_hash)?;
+        }
+        if !self.nonce.is_empty() {
+            os.write_bytes(4, &self.nonce)?;
+        }
+        if !self.auditor.is_empty() {
+            os.write_string(5, &self.auditor)?;
+        }
+        if !self.date.is_empty() {
+            os.write_string(6, &self.date)?;
+        }
+        if self.target_block != 0 {
+            os.write_uint32(7, self.target_block)?;
+        }
+        if self.end_block != 0 {
+            os.write_uint32(9, self.end_block)?;
+        }
+        if !self.auditor_signature.is_empty() {
+            os.write_bytes(8, &self.auditor_signature)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Challenge {
+        Challenge::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "message",
+                    |m: &Challenge| { &m.message },
+                    |m: &mut Challenge| { &mut m.message },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "liabilities_root",
+                    |m: &Challenge| { &m.liabilities_root },
+                    |m: &mut Challenge| { &mut m.liabilities_root },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "block_hash",
+                    |m: &Challenge| { &m.block_hash },
+                    |m: &mut Challenge| { &mut m.block_hash },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "nonce",
+                    |m: &Challenge| { &m.nonce },
+                    |m: &mut Challenge| { &mut m.nonce },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "auditor",
+                    |m: &Challenge| { &m.auditor },
+                    |m: &mut Challenge| { &mut m.auditor },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "date",
+                    |m: &Challenge| { &m.date },
+                    |m: &mut Challenge| { &mut m.date },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "target_block",
+                    |m: &Challenge| { &m.target_block },
+                    |m: &mut Challenge| { &mut m.target_block },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "end_block",
+                    |m: &Challenge| { &m.end_block },
+                    |m: &mut Challenge| { &mut m.end_block },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "auditor_signature",
+                    |m: &Challenge| { &m.auditor_signature },
+                    |m: &mut Challenge| { &mut m.auditor_signature },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<Challenge>(
+                    "Challenge",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static Challenge {
+        static mut instance: ::protobuf::lazy::Lazy<Challenge> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const Challenge,
+        };
+        unsafe {
+            instance.get(Challenge::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for Challenge {
+    fn clear(&mut self) {
+        self.clear_message();
+        self.clear_liabilities_root();
+        self.clear_block_hash();
+        self.clear_nonce();
+        self.clear_auditor();
+        self.clear_date();
+        self.clear_target_block();
+        self.clear_end_block();
+        self.clear_auditor_signature();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Challenge {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Challenge {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ProofOfReserves {
+    // message fields
+    pub version: u32,
+    pub network: Network,
+    pub challenge: ::std::string::String,
+    pub block_number: u32,
+    pub block_hash: ::std::vec::Vec<u8>,
+    pub end_block_number: u32,
+    pub proofs: ::protobuf::RepeatedField<Proof>,
+    pub provisions: ::protobuf::SingularPtrField<ProvisionsProof>,
+    pub liabilities: ::protobuf::SingularPtrField<Liabilities>,
+    pub structured_challenge: ::protobuf::SingularPtrField<Challenge>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+impl ProofOfReserves {
+    pub fn new() -> ProofOfReserves {
+        ::std::default::Default::default()
+    }
+
+    // uint32 version = 1;
+
+    pub fn clear_version(&mut self) {
+        self.version = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_version(&mut self, v: u32) {
+        self.version = v;
+    }
+
+    pub fn get_version(&self) -> u32 {
+        self.version
+    }
+
+    // .Network network = 2;
+
+    pub fn clear_network(&mut self) {
+        self.network = Network::BITCOIN_MAINNET;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_network(&mut self, v: Network) {
+        self.network = v;
+    }
+
+    pub fn get_network(&self) -> Network {
+        self.network
+    }
+
+    // string challenge = 3;
+
+    pub fn clear_challenge(&mut self) {
+        self.challenge.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_challenge(&mut self, v: ::std::string::String) {
+        self.challenge = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_challenge(&mut self) -> &mut ::std::string::String {
+        &mut self.challenge
+    }
+
+    // Take field
+    pub fn take_challenge(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.challenge, ::std::string::String::new())
+    }
+
+    pub fn get_challenge(&self) -> &str {
+        &self.challenge
+    }
+
+    // uint32 block_number = 4;
+
+    pub fn clear_block_number(&mut self) {
+        self.block_number = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_block_number(&mut self, v: u32) {
+        self.block_number = v;
+    }
+
+    pub fn get_block_number(&self) -> u32 {
+        self.block_number
+    }
+
+    // bytes block_hash = 9;
+
+    pub fn clear_block_hash(&mut self) {
+        self.block_hash.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_block_hash(&mut self, v: ::std::vec::Vec<u8>) {
+        self.block_hash = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_block_hash(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.block_hash
+    }
+
+    // Take field
+    pub fn take_block_hash(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.block_hash, ::std::vec::Vec::new())
+    }
+
+    pub fn get_block_hash(&self) -> &[u8] {
+        &self.block_hash
+    }
+
+    // uint32 end_block_number = 10;
+
+    pub fn clear_end_block_number(&mut self) {
+        self.end_block_number = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_end_block_number(&mut self, v: u32) {
+        self.end_block_number = v;
+    }
+
+    pub fn get_end_block_number(&self) -> u32 {
+        self.end_block_number
+    }
+
+    // repeated .Proof proofs = 5;
+
+    pub fn clear_proofs(&mut self) {
+        self.proofs.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_proofs(&mut self, v: ::protobuf::RepeatedField<Proof>) {
+        self.proofs = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_proofs(&mut self) -> &mut ::protobuf::RepeatedField<Proof> {
+        &mut self.proofs
+    }
+
+    // Take field
+    pub fn take_proofs(&mut self) -> ::protobuf::RepeatedField<Proof> {
+        ::std::mem::replace(&mut self.proofs, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_proofs(&self) -> &[Proof] {
+        &self.proofs
+    }
+
+    // .ProvisionsProof provisions = 6;
+
+    pub fn clear_provisions(&mut self) {
+        self.provisions.clear();
+    }
+
+    pub fn has_provisions(&self) -> bool {
+        self.provisions.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_provisions(&mut self, v: ProvisionsProof) {
+        self.provisions = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_provisions(&mut self) -> &mut ProvisionsProof {
+        if self.provisions.is_none() {
+            self.provisions.set_default();
+        }
+        self.provisions.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_provisions(&mut self) -> ProvisionsProof {
+        self.provisions.take().unwrap_or_else(|| ProvisionsProof::new())
+    }
+
+    pub fn get_provisions(&self) -> &ProvisionsProof {
+        self.provisions.as_ref().unwrap_or_else(|| ProvisionsProof::default_instance())
+    }
+
+    // .Liabilities liabilities = 7;
+
+    pub fn clear_liabilities(&mut self) {
+        self.liabilities.clear();
+    }
+
+    pub fn has_liabilities(&self) -> bool {
+        self.liabilities.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_liabilities(&mut self, v: Liabilities) {
+        self.liabilities = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_liabilities(&mut self) -> &mut Liabilities {
+        if self.liabilities.is_none() {
+            self.liabilities.set_default();
+        }
+        self.liabilities.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_liabilities(&mut self) -> Liabilities {
+        self.liabilities.take().unwrap_or_else(|| Liabilities::new())
+    }
+
+    pub fn get_liabilities(&self) -> &Liabilities {
+        self.liabilities.as_ref().unwrap_or_else(|| Liabilities::default_instance())
+    }
+
+    // .Challenge structured_challenge = 8;
+
+    pub fn clear_structured_challenge(&mut self) {
+        self.structured_challenge.clear();
+    }
+
+    pub fn has_structured_challenge(&self) -> bool {
+        self.structured_challenge.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_structured_challenge(&mut self, v: Challenge) {
+        self.structured_challenge = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_structured_challenge(&mut self) -> &mut Challenge {
+        if self.structured_challenge.is_none() {
+            self.structured_challenge.set_default();
+        }
+        self.structured_challenge.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_structured_challenge(&mut self) -> Challenge {
+        self.structured_challenge.take().unwrap_or_else(|| Challenge::new())
+    }
+
+    pub fn get_structured_challenge(&self) -> &Challenge {
+        self.structured_challenge.as_ref().unwrap_or_else(|| Challenge::default_instance())
+    }
+}
+
+impl ::protobuf::Message for ProofOfReserves {
+    fn is_initialized(&self) -> bool {
+        for v in &self.proofs {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.provisions {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.liabilities {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.structured_challenge {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.version = tmp;
+                },
+                2 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.network, 2, &mut self.unknown_fields)?
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.challenge)?;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.block_number = tmp;
+                },
+                9 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.block_hash)?;
+                },
+                10 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.end_block_number = tmp;
+                },
+                5 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.proofs)?;
+                },
+                6 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.provisions)?;
+                },
+                7 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.liabilities)?;
+                },
+                8 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.structured_challenge)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.version != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.version, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.network != Network::BITCOIN_MAINNET {
+            my_size += ::protobuf::rt::enum_size(2, self.network);
+        }
+        if !self.challenge.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.challenge);
+        }
+        if self.block_number != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.block_number, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.block_hash.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(9, &self.block_hash);
+        }
+        if self.end_block_number != 0 {
+            my_size += ::protobuf::rt::value_size(10, self.end_block_number, ::protobuf::wire_format::WireTypeVarint);
+        }
+        for value in &self.proofs {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if let Some(ref v) = self.provisions.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.liabilities.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.structured_challenge.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if self.version != 0 {
+            os.write_uint32(1, self.version)?;
+        }
+        if self.network != Network::BITCOIN_MAINNET {
+            os.write_enum(2, self.network.value())?;
+        }
+        if !self.challenge.is_empty() {
+            os.write_string(3, &self.challenge)?;
+        }
+        if self.block_number != 0 {
+            os.write_uint32(4, self.block_number)?;
+        }
+        if !self.block_hash.is_empty() {
+            os.write_bytes(9, &self.block_hash)?;
+        }
+        if self.end_block_number != 0 {
+            os.write_uint32(10, self.end_block_number)?;
+        }
+        for v in &self.proofs {
+            os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if let Some(ref v) = self.provisions.as_ref() {
+            os.write_tag(6, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.liabilities.as_ref() {
+            os.write_tag(7, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.structured_challenge.as_ref() {
+            os.write_tag(8, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ProofOfReserves {
+        ProofOfReserves::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "version",
+                    |m: &ProofOfReserves| { &m.version },
+                    |m: &mut ProofOfReserves| { &mut m.version },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<Network>>(
+                    "network",
+                    |m: &ProofOfReserves| { &m.network },
+                    |m: &mut ProofOfReserves| { &mut m.network },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "challenge",
+                    |m: &ProofOfReserves| { &m.challenge },
+                    |m: &mut ProofOfReserves| { &mut m.challenge },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "block_number",
+                    |m: &ProofOfReserves| { &m.block_number },
+                    |m: &mut ProofOfReserves| { &mut m.block_number },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "block_hash",
+                    |m: &ProofOfReserves| { &m.block_hash },
+                    |m: &mut ProofOfReserves| { &mut m.block_hash },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "end_block_number",
+                    |m: &ProofOfReserves| { &m.end_block_number },
+                    |m: &mut ProofOfReserves| { &mut m.end_block_number },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Proof>>(
+                    "proofs",
+                    |m: &ProofOfReserves| { &m.proofs },
+                    |m: &mut ProofOfReserves| { &mut m.proofs },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<ProvisionsProof>>(
+                    "provisions",
+                    |m: &ProofOfReserves| { &m.provisions },
+                    |m: &mut ProofOfReserves| { &mut m.provisions },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Liabilities>>(
+                    "liabilities",
+                    |m: &ProofOfReserves| { &m.liabilities },
+                    |m: &mut ProofOfReserves| { &mut m.liabilities },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Challenge>>(
+                    "structured_challenge",
+                    |m: &ProofOfReserves| { &m.structured_challenge },
+                    |m: &mut ProofOfReserves| { &mut m.structured_challenge },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<ProofOfReserves>(
+                    "ProofOfReserves",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static ProofOfReserves {
+        static mut instance: ::protobuf::lazy::Lazy<ProofOfReserves> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ProofOfReserves,
+        };
+        unsafe {
+            instance.get(ProofOfReserves::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for ProofOfReserves {
+    fn clear(&mut self) {
+        self.clear_version();
+        self.clear_network();
+        self.clear_challenge();
+        self.clear_block_number();
+        self.clear_block_hash();
+        self.clear_end_block_number();
+        self.clear_proofs();
+        self.clear_provisions();
+        self.clear_liabilities();
+        self.clear_structured_challenge();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ProofOfReserves {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ProofOfReserves {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct LiabilitiesEntry {
+    // message fields
+    pub customer_id: ::std::string::String,
+    pub balance: u64,
+    pub nonce: ::std::vec::Vec<u8>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+impl LiabilitiesEntry {
+    pub fn new() -> LiabilitiesEntry {
+        ::std::default::Default::default()
+    }
+
+    // string customer_id = 1;
+
+    pub fn clear_customer_id(&mut self) {
+        self.customer_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_customer_id(&mut self, v: ::std::string::String) {
+        self.customer_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_customer_id(&mut self) -> &mut ::std::string::String {
+        &mut self.customer_id
+    }
+
+    // Take field
+    pub fn take_customer_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.customer_id, ::std::string::String::new())
+    }
+
+    pub fn get_customer_id(&self) -> &str {
+        &self.customer_id
+    }
+
+    // uint64 balance = 2;
+
+    pub fn clear_balance(&mut self) {
+        self.balance = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_balance(&mut self, v: u64) {
+        self.balance = v;
+    }
+
+    pub fn get_balance(&self) -> u64 {
+        self.balance
+    }
+
+    // bytes nonce = 3;
+
+    pub fn clear_nonce(&mut self) {
+        self.nonce.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_nonce(&mut self, v: ::std::vec::Vec<u8>) {
+        self.nonce = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_nonce(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.nonce
+    }
+
+    // Take field
+    pub fn take_nonce(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.nonce, ::std::vec::Vec::new())
+    }
+
+    pub fn get_nonce(&self) -> &[u8] {
+        &self.nonce
+    }
+}
+
+impl ::protobuf::Message for LiabilitiesEntry {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.customer_id)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.balance = tmp;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.nonce)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.customer_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.customer_id);
+        }
+        if self.balance != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.balance, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.nonce.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(3, &self.nonce);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if !self.customer_id.is_empty() {
+            os.write_string(1, &self.customer_id)?;
+        }
+        if self.balance != 0 {
+            os.write_uint64(2, self.balance)?;
+        }
+        if !self.nonce.is_empty() {
+            os.write_bytes(3, &self.nonce)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> LiabilitiesEntry {
+        LiabilitiesEntry::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "customer_id",
+                    |m: &LiabilitiesEntry| { &m.customer_id },
+                    |m: &mut LiabilitiesEntry| { &mut m.customer_id },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "balance",
+                    |m: &LiabilitiesEntry| { &m.balance },
+                    |m: &mut LiabilitiesEntry| { &mut m.balance },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "nonce",
+                    |m: &LiabilitiesEntry| { &m.nonce },
+                    |m: &mut LiabilitiesEntry| { &mut m.nonce },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<LiabilitiesEntry>(
+                    "LiabilitiesEntry",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static LiabilitiesEntry {
+        static mut instance: ::protobuf::lazy::Lazy<LiabilitiesEntry> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const LiabilitiesEntry,
+        };
+        unsafe {
+            instance.get(LiabilitiesEntry::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for LiabilitiesEntry {
+    fn clear(&mut self) {
+        self.clear_customer_id();
+        self.clear_balance();
+        self.clear_nonce();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for LiabilitiesEntry {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for LiabilitiesEntry {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct LiabilitiesTree {
+    // message fields
+    pub block_number: u32,
+    pub entries: ::protobuf::RepeatedField<LiabilitiesEntry>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+impl LiabilitiesTree {
+    pub fn new() -> LiabilitiesTree {
+        ::std::default::Default::default()
+    }
+
+    // uint32 block_number = 1;
+
+    pub fn clear_block_number(&mut self) {
+        self.block_number = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_block_number(&mut self, v: u32) {
+        self.block_number = v;
+    }
+
+    pub fn get_block_number(&self) -> u32 {
+        self.block_number
+    }
+
+    // repeated .LiabilitiesEntry entries = 2;
+
+    pub fn clear_entries(&mut self) {
+        self.entries.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_entries(&mut self, v: ::protobuf::RepeatedField<LiabilitiesEntry>) {
+        self.entries = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_entries(&mut self) -> &mut ::protobuf::RepeatedField<LiabilitiesEntry> {
+        &mut self.entries
+    }
+
+    // Take field
+    pub fn take_entries(&mut self) -> ::protobuf::RepeatedField<LiabilitiesEntry> {
+        ::std::mem::replace(&mut self.entries, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_entries(&self) -> &[LiabilitiesEntry] {
+        &self.entries
+    }
+}
+
+impl ::protobuf::Message for LiabilitiesTree {
+    fn is_initialized(&self) -> bool {
+        for v in &self.entries {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.block_number = tmp;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.entries)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.block_number != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.block_number, ::protobuf::wire_format::WireTypeVarint);
+        }
+        for value in &self.entries {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if self.block_number != 0 {
+            os.write_uint32(1, self.block_number)?;
+        }
+        for v in &self.entries {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> LiabilitiesTree {
+        LiabilitiesTree::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "block_number",
+                    |m: &LiabilitiesTree| { &m.block_number },
+                    |m: &mut LiabilitiesTree| { &mut m.block_number },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<LiabilitiesEntry>>(
+                    "entries",
+                    |m: &LiabilitiesTree| { &m.entries },
+                    |m: &mut LiabilitiesTree| { &mut m.entries },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<LiabilitiesTree>(
+                    "LiabilitiesTree",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static LiabilitiesTree {
+        static mut instance: ::protobuf::lazy::Lazy<LiabilitiesTree> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const LiabilitiesTree,
+        };
+        unsafe {
+            instance.get(LiabilitiesTree::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for LiabilitiesTree {
+    fn clear(&mut self) {
+        self.clear_block_number();
+        self.clear_entries();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for LiabilitiesTree {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for LiabilitiesTree {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct SumTreeNode {
+    // message fields
+    pub hash: ::std::vec::Vec<u8>,
+    pub sum: u64,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+impl SumTreeNode {
+    pub fn new() -> SumTreeNode {
+        ::std::default::Default::default()
+    }
+
+    // bytes hash = 1;
+
+    pub fn clear_hash(&mut self) {
+        self.hash.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_hash(&mut self, v: ::std::vec::Vec<u8>) {
+        self.hash = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_hash(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.hash
+    }
+
+    // Take field
+    pub fn take_hash(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.hash, ::std::vec::Vec::new())
+    }
+
+    pub fn get_hash(&self) -> &[u8] {
+        &self.hash
+    }
+
+    // uint64 sum = 2;
+
+    pub fn clear_sum(&mut self) {
+        self.sum = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_sum(&mut self, v: u64) {
+        self.sum = v;
+    }
+
+    pub fn get_sum(&self) -> u64 {
+        self.sum
+    }
+}
+
+impl ::protobuf::Message for SumTreeNode {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.hash)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.sum = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.hash.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.hash);
+        }
+        if self.sum != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.sum, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if !self.hash.is_empty() {
+            os.write_bytes(1, &self.hash)?;
+        }
+        if self.sum != 0 {
+            os.write_uint64(2, self.sum)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> SumTreeNode {
+        SumTreeNode::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "hash",
+                    |m: &SumTreeNode| { &m.hash },
+                    |m: &mut SumTreeNode| { &mut m.hash },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "sum",
+                    |m: &SumTreeNode| { &m.sum },
+                    |m: &mut SumTreeNode| { &mut m.sum },
                 ));
+                ::protobuf::reflect::MessageDescriptor::new::<SumTreeNode>(
+                    "SumTreeNode",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static SumTreeNode {
+        static mut instance: ::protobuf::lazy::Lazy<SumTreeNode> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const SumTreeNode,
+        };
+        unsafe {
+            instance.get(SumTreeNode::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for SumTreeNode {
+    fn clear(&mut self) {
+        self.clear_hash();
+        self.clear_sum();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for SumTreeNode {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SumTreeNode {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct InclusionProof {
+    // message fields
+    pub block_number: u32,
+    pub entry: ::protobuf::SingularPtrField<LiabilitiesEntry>,
+    pub index: u32,
+    pub path: ::protobuf::RepeatedField<SumTreeNode>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+impl InclusionProof {
+    pub fn new() -> InclusionProof {
+        ::std::default::Default::default()
+    }
+
+    // uint32 block_number = 1;
+
+    pub fn clear_block_number(&mut self) {
+        self.block_number = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_block_number(&mut self, v: u32) {
+        self.block_number = v;
+    }
+
+    pub fn get_block_number(&self) -> u32 {
+        self.block_number
+    }
+
+    // .LiabilitiesEntry entry = 2;
+
+    pub fn clear_entry(&mut self) {
+        self.entry.clear();
+    }
+
+    pub fn has_entry(&self) -> bool {
+        self.entry.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_entry(&mut self, v: LiabilitiesEntry) {
+        self.entry = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_entry(&mut self) -> &mut LiabilitiesEntry {
+        if self.entry.is_none() {
+            self.entry.set_default();
+        }
+        self.entry.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_entry(&mut self) -> LiabilitiesEntry {
+        self.entry.take().unwrap_or_else(|| LiabilitiesEntry::new())
+    }
+
+    pub fn get_entry(&self) -> &LiabilitiesEntry {
+        self.entry.as_ref().unwrap_or_else(|| LiabilitiesEntry::default_instance())
+    }
+
+    // uint32 index = 3;
+
+    pub fn clear_index(&mut self) {
+        self.index = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_index(&mut self, v: u32) {
+        self.index = v;
+    }
+
+    pub fn get_index(&self) -> u32 {
+        self.index
+    }
+
+    // repeated .SumTreeNode path = 4;
+
+    pub fn clear_path(&mut self) {
+        self.path.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_path(&mut self, v: ::protobuf::RepeatedField<SumTreeNode>) {
+        self.path = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_path(&mut self) -> &mut ::protobuf::RepeatedField<SumTreeNode> {
+        &mut self.path
+    }
+
+    // Take field
+    pub fn take_path(&mut self) -> ::protobuf::RepeatedField<SumTreeNode> {
+        ::std::mem::replace(&mut self.path, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_path(&self) -> &[SumTreeNode] {
+        &self.path
+    }
+}
+
+impl ::protobuf::Message for InclusionProof {
+    fn is_initialized(&self) -> bool {
+        for v in &self.entry {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.path {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.block_number = tmp;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.entry)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.index = tmp;
+                },
+                4 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.path)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.block_number != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.block_number, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.entry.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if self.index != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.index, ::protobuf::wire_format::WireTypeVarint);
+        }
+        for value in &self.path {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if self.block_number != 0 {
+            os.write_uint32(1, self.block_number)?;
+        }
+        if let Some(ref v) = self.entry.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if self.index != 0 {
+            os.write_uint32(3, self.index)?;
+        }
+        for v in &self.path {
+            os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> InclusionProof {
+        InclusionProof::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
                 fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
                     "block_number",
-                    |m: &ProofOfReserves| { &m.block_number },
-                    |m: &mut ProofOfReserves| { &mut m.block_number },
+                    |m: &InclusionProof| { &m.block_number },
+                    |m: &mut InclusionProof| { &mut m.block_number },
                 ));
-                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Proof>>(
-                    "proofs",
-                    |m: &ProofOfReserves| { &m.proofs },
-                    |m: &mut ProofOfReserves| { &mut m.proofs },
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<LiabilitiesEntry>>(
+                    "entry",
+                    |m: &InclusionProof| { &m.entry },
+                    |m: &mut InclusionProof| { &mut m.entry },
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<ProofOfReserves>(
-                    "ProofOfReserves",
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "index",
+                    |m: &InclusionProof| { &m.index },
+                    |m: &mut InclusionProof| { &mut m.index },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<SumTreeNode>>(
+                    "path",
+                    |m: &InclusionProof| { &m.path },
+                    |m: &mut InclusionProof| { &mut m.path },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<InclusionProof>(
+                    "InclusionProof",
                     fields,
                     file_descriptor_proto()
                 )
@@ -1005,35 +4270,34 @@ impl ::protobuf::Message for ProofOfReserves {
         }
     }
 
-    fn default_instance() -> &'static ProofOfReserves {
-        static mut instance: ::protobuf::lazy::Lazy<ProofOfReserves> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static InclusionProof {
+        static mut instance: ::protobuf::lazy::Lazy<InclusionProof> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const ProofOfReserves,
+            ptr: 0 as *const InclusionProof,
         };
         unsafe {
-            instance.get(ProofOfReserves::new)
+            instance.get(InclusionProof::new)
         }
     }
 }
 
-impl ::protobuf::Clear for ProofOfReserves {
+impl ::protobuf::Clear for InclusionProof {
     fn clear(&mut self) {
-        self.clear_version();
-        self.clear_network();
-        self.clear_challenge();
         self.clear_block_number();
-        self.clear_proofs();
+        self.clear_entry();
+        self.clear_index();
+        self.clear_path();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for ProofOfReserves {
+impl ::std::fmt::Debug for InclusionProof {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for ProofOfReserves {
+impl ::protobuf::reflect::ProtobufValue for InclusionProof {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
@@ -1098,113 +4362,416 @@ impl ::protobuf::reflect::ProtobufValue for Network {
 }
 
 static file_descriptor_proto_data: &'static [u8] = b"\
-    \n\x15protos/reserves.proto\"\x8f\x01\n\x04UTXO\x12\x12\n\x04txid\x18\
-    \x01\x20\x01(\x0cR\x04txid\x12\x12\n\x04vout\x18\x02\x20\x01(\rR\x04vout\
-    \x12\x1d\n\npsbt_input\x18\x03\x20\x01(\x0cR\tpsbtInput\x12!\n\x0cblock_\
-    number\x18\x04\x20\x01(\rR\x0bblockNumber\x12\x1d\n\nblock_hash\x18\x05\
-    \x20\x01(\x0cR\tblockHash\"\xd0\x01\n\x05Proof\x12\x0e\n\x02id\x18\x01\
-    \x20\x01(\tR\x02id\x12%\n\x06status\x18\x02\x20\x01(\x0e2\r.Proof.Status\
-    R\x06status\x12\x19\n\x08proof_tx\x18\x03\x20\x01(\x0cR\x07proofTx\x12\
-    \x1b\n\x05utxos\x18\x04\x20\x03(\x0b2\x05.UTXOR\x05utxos\x12\x12\n\x04ps\
-    bt\x18\x05\x20\x01(\x0cR\x04psbt\"D\n\x06Status\x12\r\n\tUNDEFINED\x10\0\
-    \x12\t\n\x05FINAL\x10\x01\x12\x13\n\x0fGATHERING_UTXOS\x10\x02\x12\x0b\n\
-    \x07SIGNING\x10\x03\"\xb0\x01\n\x0fProofOfReserves\x12\x18\n\x07version\
-    \x18\x01\x20\x01(\rR\x07version\x12\"\n\x07network\x18\x02\x20\x01(\x0e2\
-    \x08.NetworkR\x07network\x12\x1c\n\tchallenge\x18\x03\x20\x01(\tR\tchall\
-    enge\x12!\n\x0cblock_number\x18\x04\x20\x01(\rR\x0bblockNumber\x12\x1e\n\
-    \x06proofs\x18\x05\x20\x03(\x0b2\x06.ProofR\x06proofs*?\n\x07Network\x12\
-    \x13\n\x0fBITCOIN_MAINNET\x10\0\x12\x13\n\x0fBITCOIN_TESTNET\x10\x01\x12\
-    \n\n\x06LIQUID\x10\x02J\xef\x11\n\x06\x12\x04\0\0=\x01\n\x08\n\x01\x0c\
-    \x12\x03\0\0\x12\n\n\n\x02\x05\0\x12\x04\x02\0\x06\x01\n\n\n\x03\x05\0\
-    \x01\x12\x03\x02\x05\x0c\n\x0b\n\x04\x05\0\x02\0\x12\x03\x03\x08\x1c\n\
-    \x0c\n\x05\x05\0\x02\0\x01\x12\x03\x03\x08\x17\n\x0c\n\x05\x05\0\x02\0\
-    \x02\x12\x03\x03\x1a\x1b\n\x0b\n\x04\x05\0\x02\x01\x12\x03\x04\x08\x1c\n\
-    \x0c\n\x05\x05\0\x02\x01\x01\x12\x03\x04\x08\x17\n\x0c\n\x05\x05\0\x02\
-    \x01\x02\x12\x03\x04\x1a\x1b\n\x0b\n\x04\x05\0\x02\x02\x12\x03\x05\x08\
-    \x13\n\x0c\n\x05\x05\0\x02\x02\x01\x12\x03\x05\x08\x0e\n\x0c\n\x05\x05\0\
-    \x02\x02\x02\x12\x03\x05\x11\x12\n\n\n\x02\x04\0\x12\x04\x08\0\x17\x01\n\
-    \n\n\x03\x04\0\x01\x12\x03\x08\x08\x0c\n\x0b\n\x04\x04\0\x02\0\x12\x03\t\
-    \x08\x17\n\r\n\x05\x04\0\x02\0\x04\x12\x04\t\x08\x08\x0e\n\x0c\n\x05\x04\
-    \0\x02\0\x05\x12\x03\t\x08\r\n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\t\x0e\
-    \x12\n\x0c\n\x05\x04\0\x02\0\x03\x12\x03\t\x15\x16\n\x0b\n\x04\x04\0\x02\
-    \x01\x12\x03\n\x08\x18\n\r\n\x05\x04\0\x02\x01\x04\x12\x04\n\x08\t\x17\n\
-    \x0c\n\x05\x04\0\x02\x01\x05\x12\x03\n\x08\x0e\n\x0c\n\x05\x04\0\x02\x01\
-    \x01\x12\x03\n\x0f\x13\n\x0c\n\x05\x04\0\x02\x01\x03\x12\x03\n\x16\x17\n\
-    \xc2\x01\n\x04\x04\0\x02\x02\x12\x03\x0f\x08\x1d\x1a\xb4\x01\x20A\x20ser\
-    ialized\x20BIP-174\x20INPUT\x20(not\x20a\x20full\x20PSBT).\n\x20To\x20ma\
-    ke\x20use\x20of\x20the\x20existing\x20PSBT\x20infrastructure\x20this\x20\
-    field\x20should\x20be\n\x20filled\x20with\x20all\x20the\x20information\
-    \x20needed\x20to\x20sign\x20for\x20this\x20UTXO.\n\n\r\n\x05\x04\0\x02\
-    \x02\x04\x12\x04\x0f\x08\n\x18\n\x0c\n\x05\x04\0\x02\x02\x05\x12\x03\x0f\
-    \x08\r\n\x0c\n\x05\x04\0\x02\x02\x01\x12\x03\x0f\x0e\x18\n\x0c\n\x05\x04\
-    \0\x02\x02\x03\x12\x03\x0f\x1b\x1c\nt\n\x04\x04\0\x02\x03\x12\x03\x15\
-    \x08\x20\x1aS\x20Block\x20information\x20to\x20speed\x20up\x20verificati\
-    on\x20for\x20nodes\x20that\x20don't\x20have\x20a\x20\n\x20txindex.\n2\
-    \x12\x20Extra\x20meta-data.\n\n\r\n\x05\x04\0\x02\x03\x04\x12\x04\x15\
-    \x08\x0f\x1d\n\x0c\n\x05\x04\0\x02\x03\x05\x12\x03\x15\x08\x0e\n\x0c\n\
-    \x05\x04\0\x02\x03\x01\x12\x03\x15\x0f\x1b\n\x0c\n\x05\x04\0\x02\x03\x03\
-    \x12\x03\x15\x1e\x1f\n\x0b\n\x04\x04\0\x02\x04\x12\x03\x16\x08\x1d\n\r\n\
-    \x05\x04\0\x02\x04\x04\x12\x04\x16\x08\x15\x20\n\x0c\n\x05\x04\0\x02\x04\
-    \x05\x12\x03\x16\x08\r\n\x0c\n\x05\x04\0\x02\x04\x01\x12\x03\x16\x0e\x18\
-    \n\x0c\n\x05\x04\0\x02\x04\x03\x12\x03\x16\x1b\x1c\n\n\n\x02\x04\x01\x12\
-    \x04\x19\02\x01\n\n\n\x03\x04\x01\x01\x12\x03\x19\x08\r\n\x0c\n\x04\x04\
-    \x01\x04\0\x12\x04\x1a\x08\x1f\t\n\x0c\n\x05\x04\x01\x04\0\x01\x12\x03\
-    \x1a\r\x13\n\r\n\x06\x04\x01\x04\0\x02\0\x12\x03\x1b\x10\x1e\n\x0e\n\x07\
-    \x04\x01\x04\0\x02\0\x01\x12\x03\x1b\x10\x19\n\x0e\n\x07\x04\x01\x04\0\
-    \x02\0\x02\x12\x03\x1b\x1c\x1d\n\r\n\x06\x04\x01\x04\0\x02\x01\x12\x03\
-    \x1c\x10\x1a\n\x0e\n\x07\x04\x01\x04\0\x02\x01\x01\x12\x03\x1c\x10\x15\n\
-    \x0e\n\x07\x04\x01\x04\0\x02\x01\x02\x12\x03\x1c\x18\x19\n\r\n\x06\x04\
-    \x01\x04\0\x02\x02\x12\x03\x1d\x10$\n\x0e\n\x07\x04\x01\x04\0\x02\x02\
-    \x01\x12\x03\x1d\x10\x1f\n\x0e\n\x07\x04\x01\x04\0\x02\x02\x02\x12\x03\
-    \x1d\"#\n\r\n\x06\x04\x01\x04\0\x02\x03\x12\x03\x1e\x10\x1c\n\x0e\n\x07\
-    \x04\x01\x04\0\x02\x03\x01\x12\x03\x1e\x10\x17\n\x0e\n\x07\x04\x01\x04\0\
-    \x02\x03\x02\x12\x03\x1e\x1a\x1b\n\x0b\n\x04\x04\x01\x02\0\x12\x03!\x08\
-    \x16\n\r\n\x05\x04\x01\x02\0\x04\x12\x04!\x08\x1f\t\n\x0c\n\x05\x04\x01\
-    \x02\0\x05\x12\x03!\x08\x0e\n\x0c\n\x05\x04\x01\x02\0\x01\x12\x03!\x0f\
-    \x11\n\x0c\n\x05\x04\x01\x02\0\x03\x12\x03!\x14\x15\n\x0b\n\x04\x04\x01\
-    \x02\x01\x12\x03\"\x08\x1a\n\r\n\x05\x04\x01\x02\x01\x04\x12\x04\"\x08!\
-    \x16\n\x0c\n\x05\x04\x01\x02\x01\x06\x12\x03\"\x08\x0e\n\x0c\n\x05\x04\
-    \x01\x02\x01\x01\x12\x03\"\x0f\x15\n\x0c\n\x05\x04\x01\x02\x01\x03\x12\
-    \x03\"\x18\x19\nQ\n\x04\x04\x01\x02\x02\x12\x03&\x08\x1b\x1aD\x20Proof\
-    \x20tx\x20of\x20a\x20final\x20proof.\n\x20Should\x20only\x20be\x20set\
-    \x20in\x20the\x20FINAL\x20state.\n\n\r\n\x05\x04\x01\x02\x02\x04\x12\x04\
-    &\x08\"\x1a\n\x0c\n\x05\x04\x01\x02\x02\x05\x12\x03&\x08\r\n\x0c\n\x05\
-    \x04\x01\x02\x02\x01\x12\x03&\x0e\x16\n\x0c\n\x05\x04\x01\x02\x02\x03\
-    \x12\x03&\x19\x1a\n\x9f\x01\n\x04\x04\x01\x02\x03\x12\x03+\x08\x20\x1a\
-    \x91\x01\x20UTXOs\x20used\x20in\x20this\x20proof.\x20\x20These\x20are\
-    \x20filled\x20when\x20gathering\x20UTXOs,\x20\n\x20used\x20to\x20help\
-    \x20signing\x20and\x20can\x20be\x20kept\x20afterwards\x20to\x20speed\x20\
-    up\x20\n\x20verification.\n\n\x0c\n\x05\x04\x01\x02\x03\x04\x12\x03+\x08\
-    \x10\n\x0c\n\x05\x04\x01\x02\x03\x06\x12\x03+\x11\x15\n\x0c\n\x05\x04\
-    \x01\x02\x03\x01\x12\x03+\x16\x1b\n\x0c\n\x05\x04\x01\x02\x03\x03\x12\
-    \x03+\x1e\x1f\n\xd6\x01\n\x04\x04\x01\x02\x04\x12\x031\x08\x17\x1a\xc8\
-    \x01\x20When\x20gathering\x20signatures,\x20use\x20the\x20BIP-174\x20PSB\
-    T\x20format\x20to\x20incrementally\n\x20construct\x20the\x20fully\x20sig\
-    ned\x20transaction.\n\x20Once\x20this\x20variable\x20is\x20no\x20longer\
-    \x20empty,\x20no\x20new\x20outputs\x20should\x20be\x20added\n\x20to\x20t\
-    he\x20proof.\n\n\r\n\x05\x04\x01\x02\x04\x04\x12\x041\x08+\x20\n\x0c\n\
-    \x05\x04\x01\x02\x04\x05\x12\x031\x08\r\n\x0c\n\x05\x04\x01\x02\x04\x01\
-    \x12\x031\x0e\x12\n\x0c\n\x05\x04\x01\x02\x04\x03\x12\x031\x15\x16\n\n\n\
-    \x02\x04\x02\x12\x044\0=\x01\n\n\n\x03\x04\x02\x01\x12\x034\x08\x17\n\
-    \x0b\n\x04\x04\x02\x02\0\x12\x035\x08\x1b\n\r\n\x05\x04\x02\x02\0\x04\
-    \x12\x045\x084\x19\n\x0c\n\x05\x04\x02\x02\0\x05\x12\x035\x08\x0e\n\x0c\
-    \n\x05\x04\x02\x02\0\x01\x12\x035\x0f\x16\n\x0c\n\x05\x04\x02\x02\0\x03\
-    \x12\x035\x19\x1a\n\x0b\n\x04\x04\x02\x02\x01\x12\x036\x08\x1c\n\r\n\x05\
-    \x04\x02\x02\x01\x04\x12\x046\x085\x1b\n\x0c\n\x05\x04\x02\x02\x01\x06\
-    \x12\x036\x08\x0f\n\x0c\n\x05\x04\x02\x02\x01\x01\x12\x036\x10\x17\n\x0c\
-    \n\x05\x04\x02\x02\x01\x03\x12\x036\x1a\x1b\nU\n\x04\x04\x02\x02\x02\x12\
-    \x039\x08\x1d\x1aH\x20Both\x20the\x20challenge\x20and\x20the\x20block\
-    \x20number\x20are\x20global\x20for\x20all\x20the\x20proofs.\n\n\r\n\x05\
-    \x04\x02\x02\x02\x04\x12\x049\x086\x1c\n\x0c\n\x05\x04\x02\x02\x02\x05\
-    \x12\x039\x08\x0e\n\x0c\n\x05\x04\x02\x02\x02\x01\x12\x039\x0f\x18\n\x0c\
-    \n\x05\x04\x02\x02\x02\x03\x12\x039\x1b\x1c\n\x0b\n\x04\x04\x02\x02\x03\
-    \x12\x03:\x08\x20\n\r\n\x05\x04\x02\x02\x03\x04\x12\x04:\x089\x1d\n\x0c\
-    \n\x05\x04\x02\x02\x03\x05\x12\x03:\x08\x0e\n\x0c\n\x05\x04\x02\x02\x03\
-    \x01\x12\x03:\x0f\x1b\n\x0c\n\x05\x04\x02\x02\x03\x03\x12\x03:\x1e\x1f\n\
-    \x0b\n\x04\x04\x02\x02\x04\x12\x03<\x08#\n\x0c\n\x05\x04\x02\x02\x04\x04\
-    \x12\x03<\x08\x10\n\x0c\n\x05\x04\x02\x02\x04\x06\x12\x03<\x11\x16\n\x0c\
-    \n\x05\x04\x02\x02\x04\x01\x12\x03<\x17\x1d\n\x0c\n\x05\x04\x02\x02\x04\
-    \x03\x12\x03<!\"b\x06proto3\
+    \n\x15protos/reserves.proto\"\xa0\x01\n\nUnblinding\x12\x14\n\x05asset\
+    \x18\x01\x20\x01(\x0cR\x05asset\x12\x14\n\x05value\x18\x02\x20\x01(\x04R\
+    \x05value\x122\n\x15asset_blinding_factor\x18\x03\x20\x01(\x0cR\x13asset\
+    BlindingFactor\x122\n\x15value_blinding_factor\x18\x04\x20\x01(\x0cR\x13\
+    valueBlindingFactor\"\xd4\x01\n\x04UTXO\x12\x12\n\x04txid\x18\x01\x20\
+    \x01(\x0cR\x04txid\x12\x12\n\x04vout\x18\x02\x20\x01(\rR\x04vout\x12\x1d\
+    \n\npsbt_input\x18\x03\x20\x01(\x0cR\tpsbtInput\x12!\n\x0cblock_number\
+    \x18\x04\x20\x01(\rR\x0bblockNumber\x12\x1d\n\nblock_hash\x18\x05\x20\
+    \x01(\x0cR\tblockHash\x12+\n\nunblinding\x18\x06\x20\x01(\x0b2\x0b.Unbli\
+    ndingR\nunblinding\x12\x16\n\x06wallet\x18\x07\x20\x01(\tR\x06wallet\"\
+    \xc9\x02\n\x05Proof\x12\x0e\n\x02id\x18\x01\x20\x01(\tR\x02id\x12%\n\x06\
+    status\x18\x02\x20\x01(\x0e2\r.Proof.StatusR\x06status\x12\x19\n\x08proo\
+    f_tx\x18\x03\x20\x01(\x0cR\x07proofTx\x12\x1b\n\x05utxos\x18\x04\x20\x03\
+    (\x0b2\x05.UTXOR\x05utxos\x12\x12\n\x04psbt\x18\x05\x20\x01(\x0cR\x04psb\
+    t\x12#\n\x06chunks\x18\x06\x20\x03(\x0b2\x0b.ProofChunkR\x06chunks\x12(\
+    \n\x10chunk_max_inputs\x18\x07\x20\x01(\rR\x0echunkMaxInputs\x12(\n\x10c\
+    hunk_max_weight\x18\x08\x20\x01(\rR\x0echunkMaxWeight\"D\n\x06Status\x12\
+    \r\n\tUNDEFINED\x10\0\x12\t\n\x05FINAL\x10\x01\x12\x13\n\x0fGATHERING_UT\
+    XOS\x10\x02\x12\x0b\n\x07SIGNING\x10\x03\";\n\nProofChunk\x12\x19\n\x08p\
+    roof_tx\x18\x01\x20\x01(\x0cR\x07proofTx\x12\x12\n\x04psbt\x18\x02\x20\
+    \x01(\x0cR\x04psbt\"\xd4\x01\n\rProvisionsKey\x12\x16\n\x06pubkey\x18\
+    \x01\x20\x01(\x0cR\x06pubkey\x12\x18\n\x07balance\x18\x02\x20\x01(\x04R\
+    \x07balance\x12-\n\x12balance_commitment\x18\x03\x20\x01(\x0cR\x11balanc\
+    eCommitment\x12%\n\x0ekey_commitment\x18\x04\x20\x01(\x0cR\rkeyCommitmen\
+    t\x12%\n\x0ebit_commitment\x18\x05\x20\x01(\x0cR\rbitCommitment\x12\x14\
+    \n\x05proof\x18\x06\x20\x01(\x0cR\x05proof\"\xa3\x01\n\x0fProvisionsProo\
+    f\x12\"\n\x04keys\x18\x01\x20\x03(\x0b2\x0e.ProvisionsKeyR\x04keys\x12\
+    \x1c\n\tthreshold\x18\x02\x20\x01(\x04R\tthreshold\x12+\n\x11range_commi\
+    tments\x18\x03\x20\x03(\x0cR\x10rangeCommitments\x12!\n\x0crange_proofs\
+    \x18\x04\x20\x03(\x0cR\x0brangeProofs\"Z\n\x0bLiabilities\x12\x12\n\x04r\
+    oot\x18\x01\x20\x01(\x0cR\x04root\x12\x14\n\x05total\x18\x02\x20\x01(\
+    \x04R\x05total\x12!\n\x0cblock_number\x18\x03\x20\x01(\rR\x0bblockNumber\
+    \"\xa0\x02\n\tChallenge\x12\x18\n\x07message\x18\x01\x20\x01(\tR\x07mess\
+    age\x12)\n\x10liabilities_root\x18\x02\x20\x01(\x0cR\x0fliabilitiesRoot\
+    \x12\x1d\n\nblock_hash\x18\x03\x20\x01(\x0cR\tblockHash\x12\x14\n\x05non\
+    ce\x18\x04\x20\x01(\x0cR\x05nonce\x12\x18\n\x07auditor\x18\x05\x20\x01(\
+    \tR\x07auditor\x12\x12\n\x04date\x18\x06\x20\x01(\tR\x04date\x12!\n\x0ct\
+    arget_block\x18\x07\x20\x01(\rR\x0btargetBlock\x12\x1b\n\tend_block\x18\
+    \t\x20\x01(\rR\x08endBlock\x12+\n\x11auditor_signature\x18\x08\x20\x01(\
+    \x0cR\x10auditorSignature\"\x9a\x03\n\x0fProofOfReserves\x12\x18\n\x07ve\
+    rsion\x18\x01\x20\x01(\rR\x07version\x12\"\n\x07network\x18\x02\x20\x01(\
+    \x0e2\x08.NetworkR\x07network\x12\x1c\n\tchallenge\x18\x03\x20\x01(\tR\t\
+    challenge\x12!\n\x0cblock_number\x18\x04\x20\x01(\rR\x0bblockNumber\x12\
+    \x1d\n\nblock_hash\x18\t\x20\x01(\x0cR\tblockHash\x12(\n\x10end_block_nu\
+    mber\x18\n\x20\x01(\rR\x0eendBlockNumber\x12\x1e\n\x06proofs\x18\x05\x20\
+    \x03(\x0b2\x06.ProofR\x06proofs\x120\n\nprovisions\x18\x06\x20\x01(\x0b2\
+    \x10.ProvisionsProofR\nprovisions\x12.\n\x0bliabilities\x18\x07\x20\x01(\
+    \x0b2\x0c.LiabilitiesR\x0bliabilities\x12=\n\x14structured_challenge\x18\
+    \x08\x20\x01(\x0b2\n.ChallengeR\x13structuredChallenge\"c\n\x10Liabiliti\
+    esEntry\x12\x1f\n\x0bcustomer_id\x18\x01\x20\x01(\tR\ncustomerId\x12\x18\
+    \n\x07balance\x18\x02\x20\x01(\x04R\x07balance\x12\x14\n\x05nonce\x18\
+    \x03\x20\x01(\x0cR\x05nonce\"a\n\x0fLiabilitiesTree\x12!\n\x0cblock_numb\
+    er\x18\x01\x20\x01(\rR\x0bblockNumber\x12+\n\x07entries\x18\x02\x20\x03(\
+    \x0b2\x11.LiabilitiesEntryR\x07entries\"3\n\x0bSumTreeNode\x12\x12\n\x04\
+    hash\x18\x01\x20\x01(\x0cR\x04hash\x12\x10\n\x03sum\x18\x02\x20\x01(\x04\
+    R\x03sum\"\x94\x01\n\x0eInclusionProof\x12!\n\x0cblock_number\x18\x01\
+    \x20\x01(\rR\x0bblockNumber\x12'\n\x05entry\x18\x02\x20\x01(\x0b2\x11.Li\
+    abilitiesEntryR\x05entry\x12\x14\n\x05index\x18\x03\x20\x01(\rR\x05index\
+    \x12\x20\n\x04path\x18\x04\x20\x03(\x0b2\x0c.SumTreeNodeR\x04path*?\n\
+    \x07Network\x12\x13\n\x0fBITCOIN_MAINNET\x10\0\x12\x13\n\x0fBITCOIN_TEST\
+    NET\x10\x01\x12\n\n\x06LIQUID\x10\x02J\xf7H\n\x07\x12\x05\0\0\xce\x01\
+    \x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\n\n\x02\x05\0\x12\x04\x02\0\x06\
+    \x01\n\n\n\x03\x05\0\x01\x12\x03\x02\x05\x0c\n\x0b\n\x04\x05\0\x02\0\x12\
+    \x03\x03\x08\x1c\n\x0c\n\x05\x05\0\x02\0\x01\x12\x03\x03\x08\x17\n\x0c\n\
+    \x05\x05\0\x02\0\x02\x12\x03\x03\x1a\x1b\n\x0b\n\x04\x05\0\x02\x01\x12\
+    \x03\x04\x08\x1c\n\x0c\n\x05\x05\0\x02\x01\x01\x12\x03\x04\x08\x17\n\x0c\
+    \n\x05\x05\0\x02\x01\x02\x12\x03\x04\x1a\x1b\n\x0b\n\x04\x05\0\x02\x02\
+    \x12\x03\x05\x08\x13\n\x0c\n\x05\x05\0\x02\x02\x01\x12\x03\x05\x08\x0e\n\
+    \x0c\n\x05\x05\0\x02\x02\x02\x12\x03\x05\x11\x12\nV\n\x02\x04\0\x12\x04\
+    \t\0\x0e\x01\x1aJ\x20The\x20data\x20needed\x20to\x20open\x20the\x20commi\
+    tments\x20of\x20a\x20confidential\x20Liquid\x20output.\n\n\n\n\x03\x04\0\
+    \x01\x12\x03\t\x08\x12\n\x0b\n\x04\x04\0\x02\0\x12\x03\n\x08\x18\n\r\n\
+    \x05\x04\0\x02\0\x04\x12\x04\n\x08\t\x14\n\x0c\n\x05\x04\0\x02\0\x05\x12\
+    \x03\n\x08\r\n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\n\x0e\x13\n\x0c\n\x05\
+    \x04\0\x02\0\x03\x12\x03\n\x16\x17\n\x0b\n\x04\x04\0\x02\x01\x12\x03\x0b\
+    \x08\x19\n\r\n\x05\x04\0\x02\x01\x04\x12\x04\x0b\x08\n\x18\n\x0c\n\x05\
+    \x04\0\x02\x01\x05\x12\x03\x0b\x08\x0e\n\x0c\n\x05\x04\0\x02\x01\x01\x12\
+    \x03\x0b\x0f\x14\n\x0c\n\x05\x04\0\x02\x01\x03\x12\x03\x0b\x17\x18\n\x0b\
+    \n\x04\x04\0\x02\x02\x12\x03\x0c\x08(\n\r\n\x05\x04\0\x02\x02\x04\x12\
+    \x04\x0c\x08\x0b\x19\n\x0c\n\x05\x04\0\x02\x02\x05\x12\x03\x0c\x08\r\n\
+    \x0c\n\x05\x04\0\x02\x02\x01\x12\x03\x0c\x0e#\n\x0c\n\x05\x04\0\x02\x02\
+    \x03\x12\x03\x0c&'\n\x0b\n\x04\x04\0\x02\x03\x12\x03\r\x08(\n\r\n\x05\
+    \x04\0\x02\x03\x04\x12\x04\r\x08\x0c(\n\x0c\n\x05\x04\0\x02\x03\x05\x12\
+    \x03\r\x08\r\n\x0c\n\x05\x04\0\x02\x03\x01\x12\x03\r\x0e#\n\x0c\n\x05\
+    \x04\0\x02\x03\x03\x12\x03\r&'\n\n\n\x02\x04\x01\x12\x04\x10\0'\x01\n\n\
+    \n\x03\x04\x01\x01\x12\x03\x10\x08\x0c\n\x0b\n\x04\x04\x01\x02\0\x12\x03\
+    \x11\x08\x17\n\r\n\x05\x04\x01\x02\0\x04\x12\x04\x11\x08\x10\x0e\n\x0c\n\
+    \x05\x04\x01\x02\0\x05\x12\x03\x11\x08\r\n\x0c\n\x05\x04\x01\x02\0\x01\
+    \x12\x03\x11\x0e\x12\n\x0c\n\x05\x04\x01\x02\0\x03\x12\x03\x11\x15\x16\n\
+    \x0b\n\x04\x04\x01\x02\x01\x12\x03\x12\x08\x18\n\r\n\x05\x04\x01\x02\x01\
+    \x04\x12\x04\x12\x08\x11\x17\n\x0c\n\x05\x04\x01\x02\x01\x05\x12\x03\x12\
+    \x08\x0e\n\x0c\n\x05\x04\x01\x02\x01\x01\x12\x03\x12\x0f\x13\n\x0c\n\x05\
+    \x04\x01\x02\x01\x03\x12\x03\x12\x16\x17\n\xf7\x01\n\x04\x04\x01\x02\x02\
+    \x12\x03\x18\x08\x1d\x1a\xe9\x01\x20A\x20serialized\x20BIP-174\x20INPUT\
+    \x20(not\x20a\x20full\x20PSBT).\n\x20To\x20make\x20use\x20of\x20the\x20e\
+    xisting\x20PSBT\x20infrastructure\x20this\x20field\x20should\x20be\n\x20\
+    filled\x20with\x20all\x20the\x20information\x20needed\x20to\x20sign\x20f\
+    or\x20this\x20UTXO.\n\x20For\x20Liquid\x20proofs,\x20this\x20is\x20a\x20\
+    serialized\x20PSET\x20input.\n\n\r\n\x05\x04\x01\x02\x02\x04\x12\x04\x18\
+    \x08\x12\x18\n\x0c\n\x05\x04\x01\x02\x02\x05\x12\x03\x18\x08\r\n\x0c\n\
+    \x05\x04\x01\x02\x02\x01\x12\x03\x18\x0e\x18\n\x0c\n\x05\x04\x01\x02\x02\
+    \x03\x12\x03\x18\x1b\x1c\nt\n\x04\x04\x01\x02\x03\x12\x03\x1e\x08\x20\
+    \x1aS\x20Block\x20information\x20to\x20speed\x20up\x20verification\x20fo\
+    r\x20nodes\x20that\x20don't\x20have\x20a\x20\n\x20txindex.\n2\x12\x20Ext\
+    ra\x20meta-data.\n\n\r\n\x05\x04\x01\x02\x03\x04\x12\x04\x1e\x08\x18\x1d\
+    \n\x0c\n\x05\x04\x01\x02\x03\x05\x12\x03\x1e\x08\x0e\n\x0c\n\x05\x04\x01\
+    \x02\x03\x01\x12\x03\x1e\x0f\x1b\n\x0c\n\x05\x04\x01\x02\x03\x03\x12\x03\
+    \x1e\x1e\x1f\n\x0b\n\x04\x04\x01\x02\x04\x12\x03\x1f\x08\x1d\n\r\n\x05\
+    \x04\x01\x02\x04\x04\x12\x04\x1f\x08\x1e\x20\n\x0c\n\x05\x04\x01\x02\x04\
+    \x05\x12\x03\x1f\x08\r\n\x0c\n\x05\x04\x01\x02\x04\x01\x12\x03\x1f\x0e\
+    \x18\n\x0c\n\x05\x04\x01\x02\x04\x03\x12\x03\x1f\x1b\x1c\n\x9f\x01\n\x04\
+    \x04\x01\x02\x05\x12\x03#\x08\"\x1a\x91\x01\x20For\x20confidential\x20Li\
+    quid\x20UTXOs,\x20the\x20data\x20to\x20reveal\x20the\x20asset\x20and\x20\
+    value.\n\x20This\x20is\x20needed\x20to\x20verify\x20the\x20proof,\x20so\
+    \x20it\x20should\x20be\x20kept\x20after\x20signing.\n\n\r\n\x05\x04\x01\
+    \x02\x05\x04\x12\x04#\x08\x1f\x1d\n\x0c\n\x05\x04\x01\x02\x05\x06\x12\
+    \x03#\x08\x12\n\x0c\n\x05\x04\x01\x02\x05\x01\x12\x03#\x13\x1d\n\x0c\n\
+    \x05\x04\x01\x02\x05\x03\x12\x03#\x20!\nH\n\x04\x04\x01\x02\x06\x12\x03&\
+    \x08\x1a\x1a;\x20The\x20name\x20of\x20the\x20wallet\x20the\x20UTXO\x20wa\
+    s\x20fetched\x20from,\x20if\x20any.\n\n\r\n\x05\x04\x01\x02\x06\x04\x12\
+    \x04&\x08#\"\n\x0c\n\x05\x04\x01\x02\x06\x05\x12\x03&\x08\x0e\n\x0c\n\
+    \x05\x04\x01\x02\x06\x01\x12\x03&\x0f\x15\n\x0c\n\x05\x04\x01\x02\x06\
+    \x03\x12\x03&\x18\x19\n\n\n\x02\x04\x02\x12\x04)\0P\x01\n\n\n\x03\x04\
+    \x02\x01\x12\x03)\x08\r\n\x0c\n\x04\x04\x02\x04\0\x12\x04*\x08/\t\n\x0c\
+    \n\x05\x04\x02\x04\0\x01\x12\x03*\r\x13\n\r\n\x06\x04\x02\x04\0\x02\0\
+    \x12\x03+\x10\x1e\n\x0e\n\x07\x04\x02\x04\0\x02\0\x01\x12\x03+\x10\x19\n\
+    \x0e\n\x07\x04\x02\x04\0\x02\0\x02\x12\x03+\x1c\x1d\n\r\n\x06\x04\x02\
+    \x04\0\x02\x01\x12\x03,\x10\x1a\n\x0e\n\x07\x04\x02\x04\0\x02\x01\x01\
+    \x12\x03,\x10\x15\n\x0e\n\x07\x04\x02\x04\0\x02\x01\x02\x12\x03,\x18\x19\
+    \n\r\n\x06\x04\x02\x04\0\x02\x02\x12\x03-\x10$\n\x0e\n\x07\x04\x02\x04\0\
+    \x02\x02\x01\x12\x03-\x10\x1f\n\x0e\n\x07\x04\x02\x04\0\x02\x02\x02\x12\
+    \x03-\"#\n\r\n\x06\x04\x02\x04\0\x02\x03\x12\x03.\x10\x1c\n\x0e\n\x07\
+    \x04\x02\x04\0\x02\x03\x01\x12\x03.\x10\x17\n\x0e\n\x07\x04\x02\x04\0\
+    \x02\x03\x02\x12\x03.\x1a\x1b\n\x0b\n\x04\x04\x02\x02\0\x12\x031\x08\x16\
+    \n\r\n\x05\x04\x02\x02\0\x04\x12\x041\x08/\t\n\x0c\n\x05\x04\x02\x02\0\
+    \x05\x12\x031\x08\x0e\n\x0c\n\x05\x04\x02\x02\0\x01\x12\x031\x0f\x11\n\
+    \x0c\n\x05\x04\x02\x02\0\x03\x12\x031\x14\x15\n\x0b\n\x04\x04\x02\x02\
+    \x01\x12\x032\x08\x1a\n\r\n\x05\x04\x02\x02\x01\x04\x12\x042\x081\x16\n\
+    \x0c\n\x05\x04\x02\x02\x01\x06\x12\x032\x08\x0e\n\x0c\n\x05\x04\x02\x02\
+    \x01\x01\x12\x032\x0f\x15\n\x0c\n\x05\x04\x02\x02\x01\x03\x12\x032\x18\
+    \x19\nQ\n\x04\x04\x02\x02\x02\x12\x036\x08\x1b\x1aD\x20Proof\x20tx\x20of\
+    \x20a\x20final\x20proof.\n\x20Should\x20only\x20be\x20set\x20in\x20the\
+    \x20FINAL\x20state.\n\n\r\n\x05\x04\x02\x02\x02\x04\x12\x046\x082\x1a\n\
+    \x0c\n\x05\x04\x02\x02\x02\x05\x12\x036\x08\r\n\x0c\n\x05\x04\x02\x02\
+    \x02\x01\x12\x036\x0e\x16\n\x0c\n\x05\x04\x02\x02\x02\x03\x12\x036\x19\
+    \x1a\n\x9f\x01\n\x04\x04\x02\x02\x03\x12\x03;\x08\x20\x1a\x91\x01\x20UTX\
+    Os\x20used\x20in\x20this\x20proof.\x20\x20These\x20are\x20filled\x20when\
+    \x20gathering\x20UTXOs,\x20\n\x20used\x20to\x20help\x20signing\x20and\
+    \x20can\x20be\x20kept\x20afterwards\x20to\x20speed\x20up\x20\n\x20verifi\
+    cation.\n\n\x0c\n\x05\x04\x02\x02\x03\x04\x12\x03;\x08\x10\n\x0c\n\x05\
+    \x04\x02\x02\x03\x06\x12\x03;\x11\x15\n\x0c\n\x05\x04\x02\x02\x03\x01\
+    \x12\x03;\x16\x1b\n\x0c\n\x05\x04\x02\x02\x03\x03\x12\x03;\x1e\x1f\n\xfa\
+    \x01\n\x04\x04\x02\x02\x04\x12\x03B\x08\x17\x1a\xec\x01\x20When\x20gathe\
+    ring\x20signatures,\x20use\x20the\x20BIP-174\x20PSBT\x20format\x20to\x20\
+    incrementally\n\x20construct\x20the\x20fully\x20signed\x20transaction.\n\
+    \x20Once\x20this\x20variable\x20is\x20no\x20longer\x20empty,\x20no\x20ne\
+    w\x20outputs\x20should\x20be\x20added\n\x20to\x20the\x20proof.\n\x20For\
+    \x20Liquid\x20proofs,\x20this\x20is\x20a\x20PSET.\n\n\r\n\x05\x04\x02\
+    \x02\x04\x04\x12\x04B\x08;\x20\n\x0c\n\x05\x04\x02\x02\x04\x05\x12\x03B\
+    \x08\r\n\x0c\n\x05\x04\x02\x02\x04\x01\x12\x03B\x0e\x12\n\x0c\n\x05\x04\
+    \x02\x02\x04\x03\x12\x03B\x15\x16\n\xc3\x03\n\x04\x04\x02\x02\x05\x12\
+    \x03J\x08'\x1a\xb5\x03\x20When\x20a\x20proof\x20is\x20too\x20large\x20fo\
+    r\x20a\x20single\x20transaction,\x20it\x20is\x20split\x20into\n\x20multi\
+    ple\x20chunks\x20that\x20each\x20have\x20their\x20own\x20proof\x20tx\x20\
+    and\x20PSBT.\x20\x20The\x20proof_tx\n\x20and\x20psbt\x20fields\x20above\
+    \x20are\x20then\x20left\x20empty.\x20\x20The\x20challenge\x20input\x20of\
+    \x20the\n\x20proof\x20tx\x20of\x20a\x20chunk\x20uses\x20the\x20index\x20\
+    of\x20the\x20chunk\x20as\x20the\x20prevout\x20index.\n\x20The\x20indices\
+    \x20are\x20distinct,\x20but\x20can\x20have\x20gaps:\x20a\x20chunk\x20kee\
+    ps\x20its\x20index\x20when\n\x20an\x20earlier\x20chunk\x20loses\x20all\
+    \x20its\x20UTXOs,\x20so\x20that\x20its\x20signatures\x20stay\x20valid.\n\
+    \n\x0c\n\x05\x04\x02\x02\x05\x04\x12\x03J\x08\x10\n\x0c\n\x05\x04\x02\
+    \x02\x05\x06\x12\x03J\x11\x1b\n\x0c\n\x05\x04\x02\x02\x05\x01\x12\x03J\
+    \x1c\"\n\x0c\n\x05\x04\x02\x02\x05\x03\x12\x03J%&\n\xa0\x01\n\x04\x04\
+    \x02\x02\x06\x12\x03N\x08$\x1a\x92\x01\x20The\x20limits\x20the\x20chunks\
+    \x20are\x20built\x20with,\x20so\x20that\x20they\x20can\x20be\x20rebuilt\
+    \x20with\n\x20the\x20same\x20limits.\x20\x20Zero\x20means\x20no\x20input\
+    \x20limit\x20or\x20the\x20default\x20weight\x20limit.\n\n\r\n\x05\x04\
+    \x02\x02\x06\x04\x12\x04N\x08J'\n\x0c\n\x05\x04\x02\x02\x06\x05\x12\x03N\
+    \x08\x0e\n\x0c\n\x05\x04\x02\x02\x06\x01\x12\x03N\x0f\x1f\n\x0c\n\x05\
+    \x04\x02\x02\x06\x03\x12\x03N\"#\n\x0b\n\x04\x04\x02\x02\x07\x12\x03O\
+    \x08$\n\r\n\x05\x04\x02\x02\x07\x04\x12\x04O\x08N$\n\x0c\n\x05\x04\x02\
+    \x02\x07\x05\x12\x03O\x08\x0e\n\x0c\n\x05\x04\x02\x02\x07\x01\x12\x03O\
+    \x0f\x1f\n\x0c\n\x05\x04\x02\x02\x07\x03\x12\x03O\"#\n\n\n\x02\x04\x03\
+    \x12\x04R\0X\x01\n\n\n\x03\x04\x03\x01\x12\x03R\x08\x12\n*\n\x04\x04\x03\
+    \x02\0\x12\x03T\x08\x1b\x1a\x1d\x20Proof\x20tx\x20of\x20a\x20signed\x20c\
+    hunk.\n\n\r\n\x05\x04\x03\x02\0\x04\x12\x04T\x08R\x14\n\x0c\n\x05\x04\
+    \x03\x02\0\x05\x12\x03T\x08\r\n\x0c\n\x05\x04\x03\x02\0\x01\x12\x03T\x0e\
+    \x16\n\x0c\n\x05\x04\x03\x02\0\x03\x12\x03T\x19\x1a\n3\n\x04\x04\x03\x02\
+    \x01\x12\x03W\x08\x17\x1a&\x20The\x20PSBT\x20of\x20the\x20chunk\x20while\
+    \x20signing.\n\n\r\n\x05\x04\x03\x02\x01\x04\x12\x04W\x08T\x1b\n\x0c\n\
+    \x05\x04\x03\x02\x01\x05\x12\x03W\x08\r\n\x0c\n\x05\x04\x03\x02\x01\x01\
+    \x12\x03W\x0e\x12\n\x0c\n\x05\x04\x03\x02\x01\x03\x12\x03W\x15\x16\n\x83\
+    \x01\n\x02\x04\x04\x12\x04\\\0g\x01\x1aw\x20A\x20key\x20in\x20the\x20ano\
+    nymity\x20set\x20of\x20a\x20Provisions\x20proof,\x20together\x20with\x20\
+    the\n\x20commitments\x20and\x20the\x20zero-knowledge\x20proof\x20for\x20\
+    it.\n\n\n\n\x03\x04\x04\x01\x12\x03\\\x08\x15\n=\n\x04\x04\x04\x02\0\x12\
+    \x03^\x08\x19\x1a0\x20The\x20public\x20key,\x20as\x20used\x20in\x20the\
+    \x20output\x20scripts.\n\n\r\n\x05\x04\x04\x02\0\x04\x12\x04^\x08\\\x17\
+    \n\x0c\n\x05\x04\x04\x02\0\x05\x12\x03^\x08\r\n\x0c\n\x05\x04\x04\x02\0\
+    \x01\x12\x03^\x0e\x14\n\x0c\n\x05\x04\x04\x02\0\x03\x12\x03^\x17\x18\nF\
+    \n\x04\x04\x04\x02\x01\x12\x03`\x08\x1b\x1a9\x20The\x20balance\x20of\x20\
+    the\x20key\x20at\x20the\x20proof\x20block,\x20in\x20satoshis.\n\n\r\n\
+    \x05\x04\x04\x02\x01\x04\x12\x04`\x08^\x19\n\x0c\n\x05\x04\x04\x02\x01\
+    \x05\x12\x03`\x08\x0e\n\x0c\n\x05\x04\x04\x02\x01\x01\x12\x03`\x0f\x16\n\
+    \x0c\n\x05\x04\x04\x02\x01\x03\x12\x03`\x19\x1a\n\x0b\n\x04\x04\x04\x02\
+    \x02\x12\x03b\x08%\n\r\n\x05\x04\x04\x02\x02\x04\x12\x04b\x08`\x1b\n\x0c\
+    \n\x05\x04\x04\x02\x02\x05\x12\x03b\x08\r\n\x0c\n\x05\x04\x04\x02\x02\
+    \x01\x12\x03b\x0e\x20\n\x0c\n\x05\x04\x04\x02\x02\x03\x12\x03b#$\n\x0b\n\
+    \x04\x04\x04\x02\x03\x12\x03c\x08!\n\r\n\x05\x04\x04\x02\x03\x04\x12\x04\
+    c\x08b%\n\x0c\n\x05\x04\x04\x02\x03\x05\x12\x03c\x08\r\n\x0c\n\x05\x04\
+    \x04\x02\x03\x01\x12\x03c\x0e\x1c\n\x0c\n\x05\x04\x04\x02\x03\x03\x12\
+    \x03c\x1f\x20\n\x0b\n\x04\x04\x04\x02\x04\x12\x03d\x08!\n\r\n\x05\x04\
+    \x04\x02\x04\x04\x12\x04d\x08c!\n\x0c\n\x05\x04\x04\x02\x04\x05\x12\x03d\
+    \x08\r\n\x0c\n\x05\x04\x04\x02\x04\x01\x12\x03d\x0e\x1c\n\x0c\n\x05\x04\
+    \x04\x02\x04\x03\x12\x03d\x1f\x20\nE\n\x04\x04\x04\x02\x05\x12\x03f\x08\
+    \x18\x1a8\x20The\x20scalars\x20of\x20the\x20proof,\x20each\x20serialized\
+    \x20as\x2032\x20bytes.\n\n\r\n\x05\x04\x04\x02\x05\x04\x12\x04f\x08d!\n\
+    \x0c\n\x05\x04\x04\x02\x05\x05\x12\x03f\x08\r\n\x0c\n\x05\x04\x04\x02\
+    \x05\x01\x12\x03f\x0e\x13\n\x0c\n\x05\x04\x04\x02\x05\x03\x12\x03f\x16\
+    \x17\n\x87\x01\n\x02\x04\x05\x12\x04k\0s\x01\x1a{\x20A\x20privacy-preser\
+    ving\x20proof\x20of\x20reserves\x20using\x20the\x20Provisions\x20scheme.\
+    \n\x20Only\x20the\x20commitment\x20to\x20the\x20total\x20amount\x20is\
+    \x20revealed.\n\n\n\n\x03\x04\x05\x01\x12\x03k\x08\x17\n\x0b\n\x04\x04\
+    \x05\x02\0\x12\x03l\x08(\n\x0c\n\x05\x04\x05\x02\0\x04\x12\x03l\x08\x10\
+    \n\x0c\n\x05\x04\x05\x02\0\x06\x12\x03l\x11\x1e\n\x0c\n\x05\x04\x05\x02\
+    \0\x01\x12\x03l\x1f#\n\x0c\n\x05\x04\x05\x02\0\x03\x12\x03l&'\n}\n\x04\
+    \x04\x05\x02\x01\x12\x03p\x08\x1d\x1ap\x20The\x20optional\x20range\x20pr\
+    oof\x20that\x20the\x20committed\x20total\x20is\x20at\x20least\x20the\n\
+    \x20threshold.\x20Empty\x20if\x20no\x20threshold\x20is\x20proven.\n\n\r\
+    \n\x05\x04\x05\x02\x01\x04\x12\x04p\x08l(\n\x0c\n\x05\x04\x05\x02\x01\
+    \x05\x12\x03p\x08\x0e\n\x0c\n\x05\x04\x05\x02\x01\x01\x12\x03p\x0f\x18\n\
+    \x0c\n\x05\x04\x05\x02\x01\x03\x12\x03p\x1b\x1c\n\x0b\n\x04\x04\x05\x02\
+    \x02\x12\x03q\x08-\n\x0c\n\x05\x04\x05\x02\x02\x04\x12\x03q\x08\x10\n\
+    \x0c\n\x05\x04\x05\x02\x02\x05\x12\x03q\x11\x16\n\x0c\n\x05\x04\x05\x02\
+    \x02\x01\x12\x03q\x17(\n\x0c\n\x05\x04\x05\x02\x02\x03\x12\x03q+,\n\x0b\
+    \n\x04\x04\x05\x02\x03\x12\x03r\x08(\n\x0c\n\x05\x04\x05\x02\x03\x04\x12\
+    \x03r\x08\x10\n\x0c\n\x05\x04\x05\x02\x03\x05\x12\x03r\x11\x16\n\x0c\n\
+    \x05\x04\x05\x02\x03\x01\x12\x03r\x17#\n\x0c\n\x05\x04\x05\x02\x03\x03\
+    \x12\x03r&'\nu\n\x02\x04\x06\x12\x04w\0}\x01\x1ai\x20The\x20published\
+    \x20commitment\x20to\x20the\x20liabilities:\x20the\x20root\x20of\x20a\
+    \x20Merkle\x20sum\x20tree\n\x20over\x20all\x20customer\x20balances.\n\n\
+    \n\n\x03\x04\x06\x01\x12\x03w\x08\x13\n\x0b\n\x04\x04\x06\x02\0\x12\x03x\
+    \x08\x17\n\r\n\x05\x04\x06\x02\0\x04\x12\x04x\x08w\x15\n\x0c\n\x05\x04\
+    \x06\x02\0\x05\x12\x03x\x08\r\n\x0c\n\x05\x04\x06\x02\0\x01\x12\x03x\x0e\
+    \x12\n\x0c\n\x05\x04\x06\x02\0\x03\x12\x03x\x15\x16\n0\n\x04\x04\x06\x02\
+    \x01\x12\x03z\x08\x19\x1a#\x20The\x20sum\x20of\x20all\x20customer\x20bal\
+    ances.\n\n\r\n\x05\x04\x06\x02\x01\x04\x12\x04z\x08x\x17\n\x0c\n\x05\x04\
+    \x06\x02\x01\x05\x12\x03z\x08\x0e\n\x0c\n\x05\x04\x06\x02\x01\x01\x12\
+    \x03z\x0f\x14\n\x0c\n\x05\x04\x06\x02\x01\x03\x12\x03z\x17\x18\n:\n\x04\
+    \x04\x06\x02\x02\x12\x03|\x08\x20\x1a-\x20The\x20block\x20at\x20which\
+    \x20the\x20balances\x20were\x20taken.\n\n\r\n\x05\x04\x06\x02\x02\x04\
+    \x12\x04|\x08z\x19\n\x0c\n\x05\x04\x06\x02\x02\x05\x12\x03|\x08\x0e\n\
+    \x0c\n\x05\x04\x06\x02\x02\x01\x12\x03|\x0f\x1b\n\x0c\n\x05\x04\x06\x02\
+    \x02\x03\x12\x03|\x1e\x1f\n\x9e\x01\n\x02\x04\x07\x12\x06\x81\x01\0\x95\
+    \x01\x01\x1a\x8f\x01\x20A\x20structured\x20challenge\x20that\x20commits\
+    \x20to\x20external\x20data.\x20\x20The\x20challenge\x20hash\n\x20is\x20a\
+    \x20tagged\x20hash\x20over\x20a\x20canonical\x20serialization\x20of\x20a\
+    ll\x20the\x20fields.\n\n\x0b\n\x03\x04\x07\x01\x12\x04\x81\x01\x08\x11\n\
+    \x0c\n\x04\x04\x07\x02\0\x12\x04\x82\x01\x08\x1b\n\x0f\n\x05\x04\x07\x02\
+    \0\x04\x12\x06\x82\x01\x08\x81\x01\x13\n\r\n\x05\x04\x07\x02\0\x05\x12\
+    \x04\x82\x01\x08\x0e\n\r\n\x05\x04\x07\x02\0\x01\x12\x04\x82\x01\x0f\x16\
+    \n\r\n\x05\x04\x07\x02\0\x03\x12\x04\x82\x01\x19\x1a\n<\n\x04\x04\x07\
+    \x02\x01\x12\x04\x84\x01\x08#\x1a.\x20The\x20root\x20of\x20the\x20liabil\
+    ities\x20Merkle\x20sum\x20tree.\n\n\x0f\n\x05\x04\x07\x02\x01\x04\x12\
+    \x06\x84\x01\x08\x82\x01\x1b\n\r\n\x05\x04\x07\x02\x01\x05\x12\x04\x84\
+    \x01\x08\r\n\r\n\x05\x04\x07\x02\x01\x01\x12\x04\x84\x01\x0e\x1e\n\r\n\
+    \x05\x04\x07\x02\x01\x03\x12\x04\x84\x01!\"\n7\n\x04\x04\x07\x02\x02\x12\
+    \x04\x86\x01\x08\x1d\x1a)\x20A\x20recent\x20block\x20hash\x20to\x20prove\
+    \x20freshness.\n\n\x0f\n\x05\x04\x07\x02\x02\x04\x12\x06\x86\x01\x08\x84\
+    \x01#\n\r\n\x05\x04\x07\x02\x02\x05\x12\x04\x86\x01\x08\r\n\r\n\x05\x04\
+    \x07\x02\x02\x01\x12\x04\x86\x01\x0e\x18\n\r\n\x05\x04\x07\x02\x02\x03\
+    \x12\x04\x86\x01\x1b\x1c\n0\n\x04\x04\x07\x02\x03\x12\x04\x88\x01\x08\
+    \x18\x1a\"\x20A\x20nonce\x20provided\x20by\x20the\x20auditor.\n\n\x0f\n\
+    \x05\x04\x07\x02\x03\x04\x12\x06\x88\x01\x08\x86\x01\x1d\n\r\n\x05\x04\
+    \x07\x02\x03\x05\x12\x04\x88\x01\x08\r\n\r\n\x05\x04\x07\x02\x03\x01\x12\
+    \x04\x88\x01\x0e\x13\n\r\n\x05\x04\x07\x02\x03\x03\x12\x04\x88\x01\x16\
+    \x17\nF\n\x04\x04\x07\x02\x04\x12\x04\x8a\x01\x08\x1b\x1a8\x20The\x20ide\
+    ntity\x20of\x20the\x20auditor\x20that\x20issued\x20the\x20challenge.\n\n\
+    \x0f\n\x05\x04\x07\x02\x04\x04\x12\x06\x8a\x01\x08\x88\x01\x18\n\r\n\x05\
+    \x04\x07\x02\x04\x05\x12\x04\x8a\x01\x08\x0e\n\r\n\x05\x04\x07\x02\x04\
+    \x01\x12\x04\x8a\x01\x0f\x16\n\r\n\x05\x04\x07\x02\x04\x03\x12\x04\x8a\
+    \x01\x19\x1a\n2\n\x04\x04\x07\x02\x05\x12\x04\x8c\x01\x08\x18\x1a$\x20Th\
+    e\x20date\x20the\x20challenge\x20was\x20issued.\n\n\x0f\n\x05\x04\x07\
+    \x02\x05\x04\x12\x06\x8c\x01\x08\x8a\x01\x1b\n\r\n\x05\x04\x07\x02\x05\
+    \x05\x12\x04\x8c\x01\x08\x0e\n\r\n\x05\x04\x07\x02\x05\x01\x12\x04\x8c\
+    \x01\x0f\x13\n\r\n\x05\x04\x07\x02\x05\x03\x12\x04\x8c\x01\x16\x17\nM\n\
+    \x04\x04\x07\x02\x06\x12\x04\x8e\x01\x08\x20\x1a?\x20The\x20block\x20num\
+    ber\x20the\x20auditor\x20wants\x20the\x20proofs\x20to\x20be\x20valid\x20\
+    at.\n\n\x0f\n\x05\x04\x07\x02\x06\x04\x12\x06\x8e\x01\x08\x8c\x01\x18\n\
+    \r\n\x05\x04\x07\x02\x06\x05\x12\x04\x8e\x01\x08\x0e\n\r\n\x05\x04\x07\
+    \x02\x06\x01\x12\x04\x8e\x01\x0f\x1b\n\r\n\x05\x04\x07\x02\x06\x03\x12\
+    \x04\x8e\x01\x1e\x1f\nY\n\x04\x04\x07\x02\x07\x12\x04\x90\x01\x08\x1d\
+    \x1aK\x20The\x20last\x20block\x20of\x20the\x20claimed\x20block\x20range\
+    \x20that\x20starts\x20at\x20the\x20proof\x20block.\n\n\x0f\n\x05\x04\x07\
+    \x02\x07\x04\x12\x06\x90\x01\x08\x8e\x01\x20\n\r\n\x05\x04\x07\x02\x07\
+    \x05\x12\x04\x90\x01\x08\x0e\n\r\n\x05\x04\x07\x02\x07\x01\x12\x04\x90\
+    \x01\x0f\x18\n\r\n\x05\x04\x07\x02\x07\x03\x12\x04\x90\x01\x1b\x1c\n\x92\
+    \x01\n\x04\x04\x07\x02\x08\x12\x04\x94\x01\x08$\x1a\x83\x01\x20The\x20au\
+    ditor's\x20signature\x20over\x20the\x20challenge\x20hash\x20using\x20Bit\
+    coin's\x20legacy\n\x20message\x20signing.\x20\x20This\x20is\x20not\x20pa\
+    rt\x20of\x20the\x20challenge\x20hash.\n\n\x0f\n\x05\x04\x07\x02\x08\x04\
+    \x12\x06\x94\x01\x08\x90\x01\x1d\n\r\n\x05\x04\x07\x02\x08\x05\x12\x04\
+    \x94\x01\x08\r\n\r\n\x05\x04\x07\x02\x08\x01\x12\x04\x94\x01\x0e\x1f\n\r\
+    \n\x05\x04\x07\x02\x08\x03\x12\x04\x94\x01\"#\n\x0c\n\x02\x04\x08\x12\
+    \x06\x97\x01\0\xaf\x01\x01\n\x0b\n\x03\x04\x08\x01\x12\x04\x97\x01\x08\
+    \x17\n\x0c\n\x04\x04\x08\x02\0\x12\x04\x98\x01\x08\x1b\n\x0f\n\x05\x04\
+    \x08\x02\0\x04\x12\x06\x98\x01\x08\x97\x01\x19\n\r\n\x05\x04\x08\x02\0\
+    \x05\x12\x04\x98\x01\x08\x0e\n\r\n\x05\x04\x08\x02\0\x01\x12\x04\x98\x01\
+    \x0f\x16\n\r\n\x05\x04\x08\x02\0\x03\x12\x04\x98\x01\x19\x1a\n\x0c\n\x04\
+    \x04\x08\x02\x01\x12\x04\x99\x01\x08\x1c\n\x0f\n\x05\x04\x08\x02\x01\x04\
+    \x12\x06\x99\x01\x08\x98\x01\x1b\n\r\n\x05\x04\x08\x02\x01\x06\x12\x04\
+    \x99\x01\x08\x0f\n\r\n\x05\x04\x08\x02\x01\x01\x12\x04\x99\x01\x10\x17\n\
+    \r\n\x05\x04\x08\x02\x01\x03\x12\x04\x99\x01\x1a\x1b\nV\n\x04\x04\x08\
+    \x02\x02\x12\x04\x9c\x01\x08\x1d\x1aH\x20Both\x20the\x20challenge\x20and\
+    \x20the\x20block\x20number\x20are\x20global\x20for\x20all\x20the\x20proo\
+    fs.\n\n\x0f\n\x05\x04\x08\x02\x02\x04\x12\x06\x9c\x01\x08\x99\x01\x1c\n\
+    \r\n\x05\x04\x08\x02\x02\x05\x12\x04\x9c\x01\x08\x0e\n\r\n\x05\x04\x08\
+    \x02\x02\x01\x12\x04\x9c\x01\x0f\x18\n\r\n\x05\x04\x08\x02\x02\x03\x12\
+    \x04\x9c\x01\x1b\x1c\n\x0c\n\x04\x04\x08\x02\x03\x12\x04\x9d\x01\x08\x20\
+    \n\x0f\n\x05\x04\x08\x02\x03\x04\x12\x06\x9d\x01\x08\x9c\x01\x1d\n\r\n\
+    \x05\x04\x08\x02\x03\x05\x12\x04\x9d\x01\x08\x0e\n\r\n\x05\x04\x08\x02\
+    \x03\x01\x12\x04\x9d\x01\x0f\x1b\n\r\n\x05\x04\x08\x02\x03\x03\x12\x04\
+    \x9d\x01\x1e\x1f\nO\n\x04\x04\x08\x02\x04\x12\x04\x9f\x01\x08\x1d\x1aA\
+    \x20The\x20hash\x20of\x20the\x20block\x20at\x20block_number,\x20pinning\
+    \x20the\x20chain\x20state.\n\n\x0f\n\x05\x04\x08\x02\x04\x04\x12\x06\x9f\
+    \x01\x08\x9d\x01\x20\n\r\n\x05\x04\x08\x02\x04\x05\x12\x04\x9f\x01\x08\r\
+    \n\r\n\x05\x04\x08\x02\x04\x01\x12\x04\x9f\x01\x0e\x18\n\r\n\x05\x04\x08\
+    \x02\x04\x03\x12\x04\x9f\x01\x1b\x1c\ny\n\x04\x04\x08\x02\x05\x12\x04\
+    \xa2\x01\x08%\x1ak\x20If\x20set,\x20the\x20proofs\x20claim\x20that\x20th\
+    e\x20UTXOs\x20stayed\x20unspent\x20from\x20block_number\n\x20up\x20to\
+    \x20and\x20including\x20this\x20block.\n\n\x0f\n\x05\x04\x08\x02\x05\x04\
+    \x12\x06\xa2\x01\x08\x9f\x01\x1d\n\r\n\x05\x04\x08\x02\x05\x05\x12\x04\
+    \xa2\x01\x08\x0e\n\r\n\x05\x04\x08\x02\x05\x01\x12\x04\xa2\x01\x0f\x1f\n\
+    \r\n\x05\x04\x08\x02\x05\x03\x12\x04\xa2\x01\"$\n\x0c\n\x04\x04\x08\x02\
+    \x06\x12\x04\xa4\x01\x08#\n\r\n\x05\x04\x08\x02\x06\x04\x12\x04\xa4\x01\
+    \x08\x10\n\r\n\x05\x04\x08\x02\x06\x06\x12\x04\xa4\x01\x11\x16\n\r\n\x05\
+    \x04\x08\x02\x06\x01\x12\x04\xa4\x01\x17\x1d\n\r\n\x05\x04\x08\x02\x06\
+    \x03\x12\x04\xa4\x01!\"\n<\n\x04\x04\x08\x02\x07\x12\x04\xa7\x01\x08'\
+    \x1a.\x20Only\x20present\x20if\x20a\x20Provisions\x20proof\x20was\x20mad\
+    e.\n\n\x0f\n\x05\x04\x08\x02\x07\x04\x12\x06\xa7\x01\x08\xa4\x01#\n\r\n\
+    \x05\x04\x08\x02\x07\x06\x12\x04\xa7\x01\x08\x17\n\r\n\x05\x04\x08\x02\
+    \x07\x01\x12\x04\xa7\x01\x18\"\n\r\n\x05\x04\x08\x02\x07\x03\x12\x04\xa7\
+    \x01%&\n>\n\x04\x04\x08\x02\x08\x12\x04\xaa\x01\x08$\x1a0\x20Only\x20pre\
+    sent\x20if\x20liabilities\x20were\x20committed\x20to.\n\n\x0f\n\x05\x04\
+    \x08\x02\x08\x04\x12\x06\xaa\x01\x08\xa7\x01'\n\r\n\x05\x04\x08\x02\x08\
+    \x06\x12\x04\xaa\x01\x08\x13\n\r\n\x05\x04\x08\x02\x08\x01\x12\x04\xaa\
+    \x01\x14\x1f\n\r\n\x05\x04\x08\x02\x08\x03\x12\x04\xaa\x01\"#\n\x81\x01\
+    \n\x04\x04\x08\x02\t\x12\x04\xae\x01\x08+\x1as\x20Only\x20present\x20for\
+    \x20structured\x20challenges.\x20\x20The\x20challenge\x20field\x20then\
+    \x20holds\n\x20the\x20message\x20of\x20the\x20structured\x20challenge.\n\
+    \n\x0f\n\x05\x04\x08\x02\t\x04\x12\x06\xae\x01\x08\xaa\x01$\n\r\n\x05\
+    \x04\x08\x02\t\x06\x12\x04\xae\x01\x08\x11\n\r\n\x05\x04\x08\x02\t\x01\
+    \x12\x04\xae\x01\x12&\n\r\n\x05\x04\x08\x02\t\x03\x12\x04\xae\x01)*\n;\n\
+    \x02\x04\t\x12\x06\xb2\x01\0\xb7\x01\x01\x1a-\x20A\x20customer\x20balanc\
+    e\x20in\x20the\x20liabilities\x20tree.\n\n\x0b\n\x03\x04\t\x01\x12\x04\
+    \xb2\x01\x08\x18\n\x0c\n\x04\x04\t\x02\0\x12\x04\xb3\x01\x08\x1f\n\x0f\n\
+    \x05\x04\t\x02\0\x04\x12\x06\xb3\x01\x08\xb2\x01\x1a\n\r\n\x05\x04\t\x02\
+    \0\x05\x12\x04\xb3\x01\x08\x0e\n\r\n\x05\x04\t\x02\0\x01\x12\x04\xb3\x01\
+    \x0f\x1a\n\r\n\x05\x04\t\x02\0\x03\x12\x04\xb3\x01\x1d\x1e\n\x0c\n\x04\
+    \x04\t\x02\x01\x12\x04\xb4\x01\x08\x1b\n\x0f\n\x05\x04\t\x02\x01\x04\x12\
+    \x06\xb4\x01\x08\xb3\x01\x1f\n\r\n\x05\x04\t\x02\x01\x05\x12\x04\xb4\x01\
+    \x08\x0e\n\r\n\x05\x04\t\x02\x01\x01\x12\x04\xb4\x01\x0f\x16\n\r\n\x05\
+    \x04\t\x02\x01\x03\x12\x04\xb4\x01\x19\x1a\nB\n\x04\x04\t\x02\x02\x12\
+    \x04\xb6\x01\x08\x18\x1a4\x20Random\x20data\x20to\x20hide\x20the\x20cust\
+    omer\x20data\x20in\x20the\x20tree.\n\n\x0f\n\x05\x04\t\x02\x02\x04\x12\
+    \x06\xb6\x01\x08\xb4\x01\x1b\n\r\n\x05\x04\t\x02\x02\x05\x12\x04\xb6\x01\
+    \x08\r\n\r\n\x05\x04\t\x02\x02\x01\x12\x04\xb6\x01\x0e\x13\n\r\n\x05\x04\
+    \t\x02\x02\x03\x12\x04\xb6\x01\x16\x17\nn\n\x02\x04\n\x12\x06\xbb\x01\0\
+    \xbe\x01\x01\x1a`\x20The\x20full\x20liabilities\x20tree.\x20\x20This\x20\
+    file\x20contains\x20all\x20customer\x20data\x20and\x20should\n\x20not\
+    \x20be\x20published.\n\n\x0b\n\x03\x04\n\x01\x12\x04\xbb\x01\x08\x17\n\
+    \x0c\n\x04\x04\n\x02\0\x12\x04\xbc\x01\x08\x20\n\x0f\n\x05\x04\n\x02\0\
+    \x04\x12\x06\xbc\x01\x08\xbb\x01\x19\n\r\n\x05\x04\n\x02\0\x05\x12\x04\
+    \xbc\x01\x08\x0e\n\r\n\x05\x04\n\x02\0\x01\x12\x04\xbc\x01\x0f\x1b\n\r\n\
+    \x05\x04\n\x02\0\x03\x12\x04\xbc\x01\x1e\x1f\n\x0c\n\x04\x04\n\x02\x01\
+    \x12\x04\xbd\x01\x08.\n\r\n\x05\x04\n\x02\x01\x04\x12\x04\xbd\x01\x08\
+    \x10\n\r\n\x05\x04\n\x02\x01\x06\x12\x04\xbd\x01\x11!\n\r\n\x05\x04\n\
+    \x02\x01\x01\x12\x04\xbd\x01\")\n\r\n\x05\x04\n\x02\x01\x03\x12\x04\xbd\
+    \x01,-\n,\n\x02\x04\x0b\x12\x06\xc1\x01\0\xc4\x01\x01\x1a\x1e\x20A\x20no\
+    de\x20in\x20a\x20Merkle\x20sum\x20tree.\n\n\x0b\n\x03\x04\x0b\x01\x12\
+    \x04\xc1\x01\x08\x13\n\x0c\n\x04\x04\x0b\x02\0\x12\x04\xc2\x01\x08\x17\n\
+    \x0f\n\x05\x04\x0b\x02\0\x04\x12\x06\xc2\x01\x08\xc1\x01\x15\n\r\n\x05\
+    \x04\x0b\x02\0\x05\x12\x04\xc2\x01\x08\r\n\r\n\x05\x04\x0b\x02\0\x01\x12\
+    \x04\xc2\x01\x0e\x12\n\r\n\x05\x04\x0b\x02\0\x03\x12\x04\xc2\x01\x15\x16\
+    \n\x0c\n\x04\x04\x0b\x02\x01\x12\x04\xc3\x01\x08\x17\n\x0f\n\x05\x04\x0b\
+    \x02\x01\x04\x12\x06\xc3\x01\x08\xc2\x01\x17\n\r\n\x05\x04\x0b\x02\x01\
+    \x05\x12\x04\xc3\x01\x08\x0e\n\r\n\x05\x04\x0b\x02\x01\x01\x12\x04\xc3\
+    \x01\x0f\x12\n\r\n\x05\x04\x0b\x02\x01\x03\x12\x04\xc3\x01\x15\x16\nS\n\
+    \x02\x04\x0c\x12\x06\xc7\x01\0\xce\x01\x01\x1aE\x20The\x20proof\x20that\
+    \x20a\x20customer's\x20balance\x20is\x20included\x20in\x20the\x20liabili\
+    ties.\n\n\x0b\n\x03\x04\x0c\x01\x12\x04\xc7\x01\x08\x16\n\x0c\n\x04\x04\
+    \x0c\x02\0\x12\x04\xc8\x01\x08\x20\n\x0f\n\x05\x04\x0c\x02\0\x04\x12\x06\
+    \xc8\x01\x08\xc7\x01\x18\n\r\n\x05\x04\x0c\x02\0\x05\x12\x04\xc8\x01\x08\
+    \x0e\n\r\n\x05\x04\x0c\x02\0\x01\x12\x04\xc8\x01\x0f\x1b\n\r\n\x05\x04\
+    \x0c\x02\0\x03\x12\x04\xc8\x01\x1e\x1f\n\x0c\n\x04\x04\x0c\x02\x01\x12\
+    \x04\xc9\x01\x08#\n\x0f\n\x05\x04\x0c\x02\x01\x04\x12\x06\xc9\x01\x08\
+    \xc8\x01\x20\n\r\n\x05\x04\x0c\x02\x01\x06\x12\x04\xc9\x01\x08\x18\n\r\n\
+    \x05\x04\x0c\x02\x01\x01\x12\x04\xc9\x01\x19\x1e\n\r\n\x05\x04\x0c\x02\
+    \x01\x03\x12\x04\xc9\x01!\"\n3\n\x04\x04\x0c\x02\x02\x12\x04\xcb\x01\x08\
+    \x19\x1a%\x20The\x20index\x20of\x20the\x20entry\x20in\x20the\x20tree.\n\
+    \n\x0f\n\x05\x04\x0c\x02\x02\x04\x12\x06\xcb\x01\x08\xc9\x01#\n\r\n\x05\
+    \x04\x0c\x02\x02\x05\x12\x04\xcb\x01\x08\x0e\n\r\n\x05\x04\x0c\x02\x02\
+    \x01\x12\x04\xcb\x01\x0f\x14\n\r\n\x05\x04\x0c\x02\x02\x03\x12\x04\xcb\
+    \x01\x17\x18\n?\n\x04\x04\x0c\x02\x03\x12\x04\xcd\x01\x08&\x1a1\x20The\
+    \x20sibling\x20nodes\x20from\x20the\x20leaf\x20up\x20to\x20the\x20root.\
+    \n\n\r\n\x05\x04\x0c\x02\x03\x04\x12\x04\xcd\x01\x08\x10\n\r\n\x05\x04\
+    \x0c\x02\x03\x06\x12\x04\xcd\x01\x11\x1c\n\r\n\x05\x04\x0c\x02\x03\x01\
+    \x12\x04\xcd\x01\x1d!\n\r\n\x05\x04\x0c\x02\x03\x03\x12\x04\xcd\x01$%b\
+    \x06proto3\
 ";
 
 static mut file_descriptor_proto_lazy: ::protobuf::lazy::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::lazy::Lazy {
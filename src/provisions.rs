@@ -0,0 +1,651 @@
+//! Privacy-preserving proofs of reserves following the Provisions scheme by Dagher, Bünz et al.
+//!
+//! For every public key `y_i` in an anonymity set with a balance `bal_i`, the prover publishes
+//! a Pedersen commitment `p_i = g^(bal_i * s_i) h^(v_i)` where `s_i` is 1 if the prover owns the
+//! key and 0 otherwise.  A zero-knowledge proof shows that each commitment is well-formed
+//! without revealing `s_i`.  The product of all `p_i` is a commitment to the total reserves.
+//! Optionally, a range proof shows that this total is at least a public threshold.
+
+use std::collections::HashMap;
+
+use k256::elliptic_curve::ops::Reduce;
+use k256::elliptic_curve::sec1::{FromEncodedPoint, ToEncodedPoint};
+use k256::elliptic_curve::{Field, PrimeField};
+use k256::{AffinePoint, EncodedPoint, FieldBytes, ProjectivePoint as Point, U256};
+pub use k256::Scalar;
+use rand::rngs::OsRng;
+
+use challenge::Challenge;
+use protos;
+use utils;
+
+/// The number of bits used in the threshold range proof.
+/// 2^51 satoshis is more than the total Bitcoin supply.
+pub const RANGE_BITS: usize = 51;
+
+pub fn encode_point(p: &Point) -> Vec<u8> {
+	p.to_affine().to_encoded_point(true).as_bytes().to_vec()
+}
+
+pub fn decode_point(b: &[u8]) -> Option<Point> {
+	let ep = EncodedPoint::from_bytes(b).ok()?;
+	Option::<AffinePoint>::from(AffinePoint::from_encoded_point(&ep)).map(Point::from)
+}
+
+fn decode_scalar(b: &[u8]) -> Option<Scalar> {
+	if b.len() != 32 {
+		return None;
+	}
+	let mut bytes = FieldBytes::default();
+	bytes.copy_from_slice(b);
+	Option::from(Scalar::from_repr(bytes))
+}
+
+/// Parse a private key from its 32 raw bytes.
+pub fn secret_from_slice(b: &[u8]) -> Option<Scalar> {
+	decode_scalar(b).and_then(|s| if s.is_zero().into() { None } else { Some(s) })
+}
+
+/// The serialized public key for the given private key.
+pub fn pubkey_from_secret(secret: &Scalar, compressed: bool) -> Vec<u8> {
+	let point = Point::GENERATOR * secret;
+	point.to_affine().to_encoded_point(compressed).as_bytes().to_vec()
+}
+
+/// Deserialize a fixed number of concatenated 32-byte scalars.
+fn decode_scalars(b: &[u8], n: usize) -> Option<Vec<Scalar>> {
+	if b.len() != n * 32 {
+		return None;
+	}
+	b.chunks(32).map(decode_scalar).collect()
+}
+
+fn encode_scalars(scalars: &[&Scalar]) -> Vec<u8> {
+	let mut ret = Vec::with_capacity(scalars.len() * 32);
+	for s in scalars {
+		ret.extend_from_slice(&s.to_bytes());
+	}
+	ret
+}
+
+/// The second generator `h`, for which nobody knows the discrete logarithm with respect to `g`.
+/// It is derived from a hash by trying x coordinates until a valid point is found.
+pub fn generator_h() -> Point {
+	let mut counter: u32 = 0;
+	loop {
+		let mut data = b"reserves/provisions/h".to_vec();
+		data.extend_from_slice(&counter.to_le_bytes());
+		let mut encoded = vec![0x02];
+		encoded.extend_from_slice(&utils::sha256(&data));
+		if let Some(h) = decode_point(&encoded) {
+			return h;
+		}
+		counter += 1;
+	}
+}
+
+/// A Fiat-Shamir transcript.  Every item is length-prefixed.
+struct Transcript(Vec<u8>);
+
+impl Transcript {
+	fn new(label: &str) -> Transcript {
+		let mut t = Transcript(Vec::new());
+		t.append(label.as_bytes());
+		t
+	}
+
+	fn append(&mut self, data: &[u8]) {
+		self.0.extend_from_slice(&(data.len() as u64).to_le_bytes());
+		self.0.extend_from_slice(data);
+	}
+
+	fn append_point(&mut self, p: &Point) {
+		self.append(&encode_point(p));
+	}
+
+	fn challenge(&self) -> Scalar {
+		<Scalar as Reduce<U256>>::reduce_bytes(&utils::sha256(&self.0).into())
+	}
+}
+
+/// A proof that a commitment `C = g^b h^r` commits to a bit `b`.
+///
+/// This is an OR-composition of two Schnorr proofs: one for `C = h^r` and one for `C/g = h^r`.
+#[derive(Debug)]
+pub struct BitProof {
+	e0: Scalar,
+	e1: Scalar,
+	z0: Scalar,
+	z1: Scalar,
+}
+
+impl BitProof {
+	fn statements(c: &Point) -> [Point; 2] {
+		[*c, *c - Point::GENERATOR]
+	}
+
+	fn challenge(ctx: &[u8], c: &Point, a0: &Point, a1: &Point) -> Scalar {
+		let mut t = Transcript::new("reserves/provisions/bit");
+		t.append(ctx);
+		t.append_point(c);
+		t.append_point(a0);
+		t.append_point(a1);
+		t.challenge()
+	}
+
+	pub fn prove(ctx: &[u8], h: &Point, c: &Point, bit: bool, r: &Scalar) -> BitProof {
+		let x = BitProof::statements(c);
+		let (k, o) = if bit { (1, 0) } else { (0, 1) };
+
+		// Simulate the proof for the false statement.
+		let mut e = [Scalar::ZERO; 2];
+		let mut z = [Scalar::ZERO; 2];
+		let mut a = [Point::IDENTITY; 2];
+		e[o] = Scalar::random(&mut OsRng);
+		z[o] = Scalar::random(&mut OsRng);
+		a[o] = *h * z[o] - x[o] * e[o];
+
+		// And make a real one for the true one.
+		let nonce = Scalar::random(&mut OsRng);
+		a[k] = *h * nonce;
+		e[k] = BitProof::challenge(ctx, c, &a[0], &a[1]) - e[o];
+		z[k] = nonce + e[k] * r;
+
+		BitProof {
+			e0: e[0],
+			e1: e[1],
+			z0: z[0],
+			z1: z[1],
+		}
+	}
+
+	pub fn verify(&self, ctx: &[u8], h: &Point, c: &Point) -> bool {
+		let x = BitProof::statements(c);
+		let a0 = *h * self.z0 - x[0] * self.e0;
+		let a1 = *h * self.z1 - x[1] * self.e1;
+		self.e0 + self.e1 == BitProof::challenge(ctx, c, &a0, &a1)
+	}
+
+	pub fn serialize(&self) -> Vec<u8> {
+		encode_scalars(&[&self.e0, &self.e1, &self.z0, &self.z1])
+	}
+
+	pub fn deserialize(b: &[u8]) -> Option<BitProof> {
+		decode_scalars(b, 4).map(|s| BitProof {
+			e0: s[0],
+			e1: s[1],
+			z0: s[2],
+			z1: s[3],
+		})
+	}
+}
+
+/// The commitments and the proof for a single key of the anonymity set.
+///
+/// With `b = g^bal` and `y` the public key, the commitments are
+///   p = b^s h^v   (the balance commitment)
+///   l = y^s h^t = g^x h^t   (with x the private key times s)
+///   q = g^s h^w   (a commitment to s, used to prove s is a bit)
+/// and the sigma protocol proves knowledge of s, v, t, x and w.
+#[derive(Debug)]
+pub struct KeyProof {
+	pub balance_commitment: Point,
+	pub key_commitment: Point,
+	pub bit_commitment: Point,
+	c: Scalar,
+	r_s: Scalar,
+	r_v: Scalar,
+	r_t: Scalar,
+	r_x: Scalar,
+	r_w: Scalar,
+	bit_proof: BitProof,
+}
+
+impl KeyProof {
+	fn challenge(ctx: &[u8], p: &Point, l: &Point, q: &Point, a: &[Point; 4]) -> Scalar {
+		let mut t = Transcript::new("reserves/provisions/key");
+		t.append(ctx);
+		t.append_point(p);
+		t.append_point(l);
+		t.append_point(q);
+		for a in a.iter() {
+			t.append_point(a);
+		}
+		t.challenge()
+	}
+
+	/// Create the proof for a key.  When the secret key is given, the balance is included.
+	/// Returns the proof and the blinding factor of the balance commitment.
+	pub fn prove(
+		ctx: &[u8],
+		h: &Point,
+		pubkey: &Point,
+		balance: u64,
+		secret: Option<&Scalar>,
+	) -> (KeyProof, Scalar) {
+		let g = Point::GENERATOR;
+		let b = g * Scalar::from(balance);
+		let s = if secret.is_some() { Scalar::ONE } else { Scalar::ZERO };
+		let x = secret.cloned().unwrap_or(Scalar::ZERO);
+
+		let v = Scalar::random(&mut OsRng);
+		let t = Scalar::random(&mut OsRng);
+		let w = Scalar::random(&mut OsRng);
+		let p = b * s + *h * v;
+		let l = *pubkey * s + *h * t;
+		let q = g * s + *h * w;
+
+		let u: Vec<Scalar> = (0..5).map(|_| Scalar::random(&mut OsRng)).collect();
+		let a = [
+			b * u[0] + *h * u[1],
+			*pubkey * u[0] + *h * u[2],
+			g * u[3] + *h * u[2],
+			g * u[0] + *h * u[4],
+		];
+		let c = KeyProof::challenge(ctx, &p, &l, &q, &a);
+
+		let proof = KeyProof {
+			balance_commitment: p,
+			key_commitment: l,
+			bit_commitment: q,
+			c: c,
+			r_s: u[0] + c * s,
+			r_v: u[1] + c * v,
+			r_t: u[2] + c * t,
+			r_x: u[3] + c * x,
+			r_w: u[4] + c * w,
+			bit_proof: BitProof::prove(ctx, h, &q, secret.is_some(), &w),
+		};
+		(proof, v)
+	}
+
+	pub fn verify(&self, ctx: &[u8], h: &Point, pubkey: &Point, balance: u64) -> bool {
+		let g = Point::GENERATOR;
+		let b = g * Scalar::from(balance);
+		let (p, l, q) = (&self.balance_commitment, &self.key_commitment, &self.bit_commitment);
+		let c = self.c;
+		let a = [
+			b * self.r_s + *h * self.r_v - *p * c,
+			*pubkey * self.r_s + *h * self.r_t - *l * c,
+			g * self.r_x + *h * self.r_t - *l * c,
+			g * self.r_s + *h * self.r_w - *q * c,
+		];
+		c == KeyProof::challenge(ctx, p, l, q, &a) && self.bit_proof.verify(ctx, h, q)
+	}
+
+	fn serialize_proof(&self) -> Vec<u8> {
+		let mut ret =
+			encode_scalars(&[&self.c, &self.r_s, &self.r_v, &self.r_t, &self.r_x, &self.r_w]);
+		ret.extend(self.bit_proof.serialize());
+		ret
+	}
+}
+
+/// A proof that a commitment `D = g^d h^v` commits to a value `d` in `[0, 2^RANGE_BITS)`.
+/// `D` is split into commitments to the bits of `d`, each with a proof that it commits to a bit.
+#[derive(Debug)]
+pub struct RangeProof {
+	pub commitments: Vec<Point>,
+	proofs: Vec<BitProof>,
+}
+
+impl RangeProof {
+	fn bit_ctx(ctx: &[u8], idx: usize) -> Vec<u8> {
+		let mut t = Transcript::new("reserves/provisions/range");
+		t.append(ctx);
+		t.append(&(idx as u64).to_le_bytes());
+		t.0
+	}
+
+	pub fn prove(ctx: &[u8], h: &Point, value: u64, blinding: &Scalar) -> RangeProof {
+		let g = Point::GENERATOR;
+		let last = RANGE_BITS - 1;
+
+		// Pick the blinding factors so that they add up to the one of the total.
+		let mut blindings = Vec::with_capacity(RANGE_BITS);
+		let mut sum = Scalar::ZERO;
+		for i in 0..last {
+			let r = Scalar::random(&mut OsRng);
+			sum += r * Scalar::from(1u64 << i);
+			blindings.push(r);
+		}
+		let last_weight = Scalar::from(1u64 << last).invert().unwrap();
+		blindings.push((*blinding - sum) * last_weight);
+
+		let mut commitments = Vec::with_capacity(RANGE_BITS);
+		let mut proofs = Vec::with_capacity(RANGE_BITS);
+		for (i, r) in blindings.iter().enumerate() {
+			let bit = (value >> i) & 1 == 1;
+			let c = g * Scalar::from(bit as u64) + *h * r;
+			proofs.push(BitProof::prove(&RangeProof::bit_ctx(ctx, i), h, &c, bit, r));
+			commitments.push(c);
+		}
+		RangeProof {
+			commitments: commitments,
+			proofs: proofs,
+		}
+	}
+
+	pub fn verify(&self, ctx: &[u8], h: &Point, target: &Point) -> bool {
+		if self.commitments.len() != RANGE_BITS || self.proofs.len() != RANGE_BITS {
+			return false;
+		}
+		let mut sum = Point::IDENTITY;
+		for (i, (c, proof)) in self.commitments.iter().zip(self.proofs.iter()).enumerate() {
+			if !proof.verify(&RangeProof::bit_ctx(ctx, i), h, c) {
+				return false;
+			}
+			sum += *c * Scalar::from(1u64 << i);
+		}
+		sum == *target
+	}
+}
+
+/// A key of the anonymity set.
+#[derive(Debug)]
+pub struct Key {
+	/// The public key as it was provided.
+	pub pubkey: Vec<u8>,
+	pub point: Point,
+	/// The balance of the key at the proof block, in satoshis.
+	pub balance: u64,
+}
+
+/// A full Provisions proof.
+#[derive(Debug)]
+pub struct ProvisionsProof {
+	pub keys: Vec<Key>,
+	pub key_proofs: Vec<KeyProof>,
+	/// The threshold proven by the range proof, if any.
+	pub threshold: u64,
+	pub range_proof: Option<RangeProof>,
+}
+
+/// The context every proof commits to: the challenge, the block and the anonymity set.
+fn proof_ctx(challenge: &Challenge, block_number: u32, keys: &[Key]) -> Vec<u8> {
+	let mut t = Transcript::new("reserves/provisions/v1");
+	t.append(&challenge.hash());
+	t.append(&block_number.to_le_bytes());
+	for key in keys {
+		t.append(&key.pubkey);
+		t.append(&key.balance.to_le_bytes());
+	}
+	utils::sha256(&t.0).to_vec()
+}
+
+fn key_ctx(ctx: &[u8], idx: usize) -> Vec<u8> {
+	let mut ret = ctx.to_vec();
+	ret.extend_from_slice(&(idx as u64).to_le_bytes());
+	ret
+}
+
+impl ProvisionsProof {
+	/// Create a proof over the given anonymity set.  The secrets map the index of the keys we own
+	/// to their private keys.
+	pub fn prove(
+		challenge: &Challenge,
+		block_number: u32,
+		keys: Vec<Key>,
+		secrets: &HashMap<usize, Scalar>,
+		threshold: Option<u64>,
+	) -> Result<ProvisionsProof, String> {
+		let h = generator_h();
+		let ctx = proof_ctx(challenge, block_number, &keys);
+
+		let mut total = 0;
+		let mut total_blinding = Scalar::ZERO;
+		let mut key_proofs = Vec::with_capacity(keys.len());
+		for (idx, key) in keys.iter().enumerate() {
+			let secret = secrets.get(&idx);
+			if let Some(secret) = secret {
+				if Point::GENERATOR * secret != key.point {
+					return Err(format!("private key doesn't match key #{}", idx));
+				}
+				total += key.balance;
+			}
+			let (proof, v) =
+				KeyProof::prove(&key_ctx(&ctx, idx), &h, &key.point, key.balance, secret);
+			total_blinding += v;
+			key_proofs.push(proof);
+		}
+
+		let range_proof = match threshold {
+			Some(threshold) => {
+				if total < threshold {
+					return Err(format!(
+						"total reserves of {} satoshis are lower than the threshold of {}",
+						total, threshold
+					));
+				}
+				Some(RangeProof::prove(&ctx, &h, total - threshold, &total_blinding))
+			}
+			None => None,
+		};
+
+		Ok(ProvisionsProof {
+			keys: keys,
+			key_proofs: key_proofs,
+			threshold: threshold.unwrap_or(0),
+			range_proof: range_proof,
+		})
+	}
+
+	/// The commitment to the total reserves.
+	pub fn total_commitment(&self) -> Point {
+		self.key_proofs.iter().fold(Point::IDENTITY, |sum, p| sum + p.balance_commitment)
+	}
+
+	/// Verify all the proofs.  The balances of the keys have to be checked by the caller.
+	pub fn verify(&self, challenge: &Challenge, block_number: u32) -> Result<(), String> {
+		if self.keys.len() != self.key_proofs.len() {
+			return Err("number of keys doesn't match the number of key proofs".into());
+		}
+		let h = generator_h();
+		let ctx = proof_ctx(challenge, block_number, &self.keys);
+
+		for (idx, (key, proof)) in self.keys.iter().zip(self.key_proofs.iter()).enumerate() {
+			if !proof.verify(&key_ctx(&ctx, idx), &h, &key.point, key.balance) {
+				return Err(format!("invalid proof for key #{}", idx));
+			}
+		}
+
+		if let Some(ref range_proof) = self.range_proof {
+			let target = self.total_commitment() - Point::GENERATOR * Scalar::from(self.threshold);
+			if !range_proof.verify(&ctx, &h, &target) {
+				return Err("invalid threshold range proof".into());
+			}
+		}
+		Ok(())
+	}
+}
+
+impl From<protos::ProvisionsProof> for ProvisionsProof {
+	fn from(mut p: protos::ProvisionsProof) -> Self {
+		let mut keys = Vec::new();
+		let mut key_proofs = Vec::new();
+		for k in p.take_keys().into_iter() {
+			keys.push(Key {
+				point: decode_point(k.get_pubkey()).expect("corrupt Provisions pubkey"),
+				pubkey: k.get_pubkey().to_vec(),
+				balance: k.get_balance(),
+			});
+			if k.get_proof().len() != 10 * 32 {
+				panic!("corrupt Provisions proof");
+			}
+			let s = decode_scalars(&k.get_proof()[..6 * 32], 6).expect("corrupt Provisions proof");
+			key_proofs.push(KeyProof {
+				balance_commitment: decode_point(k.get_balance_commitment())
+					.expect("corrupt Provisions commitment"),
+				key_commitment: decode_point(k.get_key_commitment())
+					.expect("corrupt Provisions commitment"),
+				bit_commitment: decode_point(k.get_bit_commitment())
+					.expect("corrupt Provisions commitment"),
+				c: s[0],
+				r_s: s[1],
+				r_v: s[2],
+				r_t: s[3],
+				r_x: s[4],
+				r_w: s[5],
+				bit_proof: BitProof::deserialize(&k.get_proof()[6 * 32..])
+					.expect("corrupt Provisions proof"),
+			});
+		}
+
+		let range_proof = if p.get_range_commitments().len() > 0 {
+			Some(RangeProof {
+				commitments: p
+					.get_range_commitments()
+					.iter()
+					.map(|c| decode_point(c).expect("corrupt Provisions range proof"))
+					.collect(),
+				proofs: p
+					.get_range_proofs()
+					.iter()
+					.map(|c| BitProof::deserialize(c).expect("corrupt Provisions range proof"))
+					.collect(),
+			})
+		} else {
+			None
+		};
+
+		ProvisionsProof {
+			keys: keys,
+			key_proofs: key_proofs,
+			threshold: p.get_threshold(),
+			range_proof: range_proof,
+		}
+	}
+}
+
+impl Into<protos::ProvisionsProof> for ProvisionsProof {
+	fn into(self) -> protos::ProvisionsProof {
+		let mut p = protos::ProvisionsProof::new();
+		for (key, proof) in self.keys.into_iter().zip(self.key_proofs.into_iter()) {
+			let mut k = protos::ProvisionsKey::new();
+			k.set_pubkey(key.pubkey);
+			k.set_balance(key.balance);
+			k.set_balance_commitment(encode_point(&proof.balance_commitment));
+			k.set_key_commitment(encode_point(&proof.key_commitment));
+			k.set_bit_commitment(encode_point(&proof.bit_commitment));
+			k.set_proof(proof.serialize_proof());
+			p.mut_keys().push(k);
+		}
+		p.set_threshold(self.threshold);
+		if let Some(range_proof) = self.range_proof {
+			for c in range_proof.commitments.iter() {
+				p.mut_range_commitments().push(encode_point(c));
+			}
+			for proof in range_proof.proofs.iter() {
+				p.mut_range_proofs().push(proof.serialize());
+			}
+		}
+		p
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn key(secret: &Scalar, balance: u64) -> Key {
+		Key {
+			pubkey: pubkey_from_secret(secret, true),
+			point: Point::GENERATOR * secret,
+			balance: balance,
+		}
+	}
+
+	/// An anonymity set of three keys of which we own the first and the last.
+	fn setup() -> (Challenge, Vec<Key>, HashMap<usize, Scalar>) {
+		let secrets: Vec<Scalar> = (0..3).map(|_| Scalar::random(&mut OsRng)).collect();
+		let keys = vec![key(&secrets[0], 5000), key(&secrets[1], 7000), key(&secrets[2], 3000)];
+		let mut owned = HashMap::new();
+		owned.insert(0, secrets[0]);
+		owned.insert(2, secrets[2]);
+		(Challenge::new("Provisions test".into()), keys, owned)
+	}
+
+	#[test]
+	fn prove_verify() {
+		let (challenge, keys, secrets) = setup();
+		let proof = ProvisionsProof::prove(&challenge, 100, keys, &secrets, Some(8000)).unwrap();
+		assert!(proof.verify(&challenge, 100).is_ok());
+
+		// Without a threshold, there is no range proof.
+		let proof = ProvisionsProof::prove(&challenge, 100, proof.keys, &secrets, None).unwrap();
+		assert!(proof.range_proof.is_none());
+		assert!(proof.verify(&challenge, 100).is_ok());
+	}
+
+	#[test]
+	fn proto_roundtrip() {
+		let (challenge, keys, secrets) = setup();
+		let proof = ProvisionsProof::prove(&challenge, 100, keys, &secrets, Some(8000)).unwrap();
+		let proto: protos::ProvisionsProof = proof.into();
+		let proof: ProvisionsProof = proto.into();
+		assert_eq!(proof.threshold, 8000);
+		assert!(proof.verify(&challenge, 100).is_ok());
+	}
+
+	#[test]
+	fn changed_balance() {
+		let (challenge, keys, secrets) = setup();
+		let mut proof = ProvisionsProof::prove(&challenge, 100, keys, &secrets, None).unwrap();
+		proof.keys[1].balance += 1;
+		assert!(proof.verify(&challenge, 100).is_err());
+	}
+
+	#[test]
+	fn changed_threshold() {
+		let (challenge, keys, secrets) = setup();
+		let mut proof =
+			ProvisionsProof::prove(&challenge, 100, keys, &secrets, Some(8000)).unwrap();
+		proof.threshold = 8001;
+		assert_eq!(proof.verify(&challenge, 100), Err("invalid threshold range proof".into()));
+	}
+
+	#[test]
+	fn changed_context() {
+		let (challenge, keys, secrets) = setup();
+		let proof = ProvisionsProof::prove(&challenge, 100, keys, &secrets, None).unwrap();
+		assert!(proof.verify(&challenge, 101).is_err());
+		assert!(proof.verify(&Challenge::new("other".into()), 100).is_err());
+	}
+
+	#[test]
+	fn changed_commitment() {
+		let (challenge, keys, secrets) = setup();
+		let mut proof = ProvisionsProof::prove(&challenge, 100, keys, &secrets, None).unwrap();
+		proof.key_proofs[0].balance_commitment += Point::GENERATOR;
+		assert!(proof.verify(&challenge, 100).is_err());
+	}
+
+	#[test]
+	fn wrong_key() {
+		let (challenge, keys, mut secrets) = setup();
+		secrets.insert(1, Scalar::random(&mut OsRng));
+		let res = ProvisionsProof::prove(&challenge, 100, keys, &secrets, None);
+		assert_eq!(res.err(), Some("private key doesn't match key #1".into()));
+	}
+
+	#[test]
+	fn threshold_too_high() {
+		let (challenge, keys, secrets) = setup();
+		assert!(ProvisionsProof::prove(&challenge, 100, keys, &secrets, Some(8001)).is_err());
+	}
+
+	#[test]
+	fn bit_proof() {
+		let h = generator_h();
+		let r = Scalar::random(&mut OsRng);
+		let one = Point::GENERATOR + h * r;
+		let proof = BitProof::prove(b"ctx", &h, &one, true, &r);
+		assert!(proof.verify(b"ctx", &h, &one));
+		assert!(!proof.verify(b"other", &h, &one));
+
+		// A commitment to 2 can't be proven to be a bit.
+		let two = Point::GENERATOR * Scalar::from(2u64) + h * r;
+		let proof = BitProof::prove(b"ctx", &h, &two, true, &r);
+		assert!(!proof.verify(b"ctx", &h, &two));
+	}
+}
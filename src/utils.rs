@@ -1,5 +1,11 @@
+use std::fs;
+use std::time::{SystemTime, UNIX_EPOCH};
+
 use crypto::digest::Digest;
 use crypto::sha2::Sha256;
+use hex;
+use protobuf;
+use rbitcoin::util::base58;
 
 use protos;
 
@@ -20,3 +26,83 @@ pub fn network_name(network: protos::Network) -> String {
 		protos::Network::LIQUID => "LIQUID",
 	}.into()
 }
+
+/// Read a protobuf message from the file at the given path.
+pub fn read_proto_file<M: protobuf::Message>(path: &str) -> M {
+	let mut file = fs::File::open(path).expect(&format!("error opening file at '{}'", path));
+	protobuf::parse_from_reader(&mut file).expect(&format!("error parsing file at '{}'", path))
+}
+
+/// Write a protobuf message to the file at the given path.
+pub fn write_proto_file<M: protobuf::Message>(path: &str, msg: &M) {
+	let mut file = fs::File::create(path).expect(&format!("error opening file at '{}'", path));
+	msg.write_to_writer(&mut file).expect(&format!("error writing file at '{}'", path));
+}
+
+/// Parse a WIF private key into the secret key and whether the public key is compressed.
+pub fn parse_wif(wif: &str) -> Result<([u8; 32], bool), String> {
+	let data = base58::from_check(wif).map_err(|e| format!("invalid base58: {:?}", e))?;
+	let compressed = match data.len() {
+		33 => false,
+		34 if data[33] == 1 => true,
+		_ => return Err("invalid WIF private key length".into()),
+	};
+	if data[0] != 0x80 && data[0] != 0xef {
+		return Err(format!("invalid WIF private key version byte: {}", data[0]));
+	}
+	let mut secret = [0; 32];
+	secret.copy_from_slice(&data[1..33]);
+	Ok((secret, compressed))
+}
+
+/// Parse a block hash from its hex representation (in the usual reversed byte order).
+pub fn parse_block_hash(s: &str) -> Result<[u8; 32], String> {
+	let bytes = hex::decode(s).map_err(|e| format!("invalid hex: {}", e))?;
+	if bytes.len() != 32 {
+		return Err("block hash must be 32 bytes".into());
+	}
+	let mut hash = [0; 32];
+	for (i, b) in bytes.into_iter().rev().enumerate() {
+		hash[i] = b;
+	}
+	Ok(hash)
+}
+
+/// Parse a liabilities Merkle root in hex.
+pub fn parse_liabilities_root(s: &str) -> Result<[u8; 32], String> {
+	let bytes = hex::decode(s).map_err(|e| format!("invalid hex: {}", e))?;
+	if bytes.len() != 32 {
+		return Err("the liabilities root must be 32 bytes".into());
+	}
+	let mut root = [0; 32];
+	root.copy_from_slice(&bytes);
+	Ok(root)
+}
+
+/// The current time in seconds since the Unix epoch.
+pub fn now() -> u64 {
+	SystemTime::now().duration_since(UNIX_EPOCH).expect("invalid system time").as_secs()
+}
+
+/// The UTC date of the given time in seconds since the Unix epoch in YYYY-MM-DD format.
+pub fn utc_date(secs: u64) -> String {
+	// Convert days since the epoch to a civil date, see
+	// http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+	let z = secs / 86400 + 719468;
+	let era = z / 146097;
+	let doe = z - era * 146097;
+	let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+	let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+	let mp = (5 * doy + 2) / 153;
+	let day = doy - (153 * mp + 2) / 5 + 1;
+	let month = if mp < 10 { mp + 3 } else { mp - 9 };
+	let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+	format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+/// Format a block hash in the usual reversed byte order.
+pub fn block_hash_hex(hash: &[u8; 32]) -> String {
+	let mut bytes = hash.to_vec();
+	bytes.reverse();
+	hex::encode(&bytes)
+}